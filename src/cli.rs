@@ -13,6 +13,13 @@ const HELP_TEMPLATE: &str = "\
 
 {all-args}{after-help}
 ";
+const EXIT_CODES_HELP: &str = "\
+Exit Codes:
+  0  Success
+  1  A value couldn't be parsed
+  2  `--validate` found at least one error
+  3  A file or stdin couldn't be read
+  4  The arguments or configuration are invalid";
 
 /// Builds the command-line interface.
 pub fn build_cli() -> Command {
@@ -21,6 +28,7 @@ pub fn build_cli() -> Command {
 		.author(env!("CARGO_PKG_AUTHORS"))
 		.about(env!("CARGO_PKG_DESCRIPTION"))
 		.help_template(HELP_TEMPLATE)
+		.after_long_help(EXIT_CODES_HELP)
 		.arg_required_else_help(true)
 		.help_expected(true)
 		.disable_help_flag(true)
@@ -60,6 +68,159 @@ pub fn build_cli() -> Command {
 					 will not be used.",
 				),
 		)
+		.arg(
+			Arg::new("no-severity-colour")
+				.long("no-severity-colour")
+				.alias("no-severity-color")
+				.action(ArgAction::SetTrue)
+				.help(
+					"Don't colour values by their severity, while still colouring headers. Use \
+					 `--legend` to see what each colour means.",
+				),
+		)
+		.arg(
+			Arg::new("indent")
+				.long("indent")
+				.visible_alias("indentation")
+				.num_args(1)
+				.action(ArgAction::Set)
+				.value_name("TAB/SPACES")
+				.value_parser(|value: &str| {
+					if value == "tab" || value.parse::<u8>().is_ok() {
+						Ok(value.to_owned())
+					} else {
+						Err("must be `tab` or a number of spaces")
+					}
+				})
+				.help("The indentation to use for each level of nested output.")
+				.long_help(
+					"The indentation to use for each level of nested output.\nEither `tab` to \
+					 indent with a tab character, or a number of spaces to indent with.",
+				),
+		)
+		.arg(
+			Arg::new("format")
+				.long("format")
+				.visible_alias("output-format")
+				.num_args(1)
+				.default_value("human")
+				.action(ArgAction::Set)
+				.value_name("FORMAT")
+				.value_parser(["human", "json"])
+				.help("The format to write parsed output in.")
+				.long_help(
+					"The format to write parsed output in.\nThe `json` format is intended for \
+					 consumption by other programs, and never uses colour. Errors are still \
+					 written to stderr as plain text.",
+				),
+		)
+		.arg(
+			Arg::new("input-file")
+				.long("input-file")
+				.visible_aliases(["input", "file"])
+				.num_args(1)
+				.action(ArgAction::Set)
+				.value_name("PATH")
+				.value_parser(NonEmptyStringValueParser::new())
+				.requires("operations")
+				.help(
+					"Read the value for the operation from a file instead of the command line. \
+					 Use `-` to read from stdin.",
+				)
+				.long_help(
+					"Read the value for the operation from a file instead of the command line. \
+					 Use `-` to read from stdin.\nThe value is only read from the file if the \
+					 operation is provided without a value, like `--ber-tlv --input-file \
+					 dump.txt`. Leading and trailing whitespace in the file is ignored, and \
+					 unless `--batch` is used, line breaks are ignored as well so that long \
+					 values can be wrapped across several lines.\nIf an operation is provided \
+					 without a value and this option isn't provided, the value is read from stdin.",
+				),
+		)
+		.arg(
+			Arg::new("input-file-binary")
+				.long("input-file-binary")
+				.visible_alias("binary")
+				.num_args(1)
+				.action(ArgAction::Set)
+				.value_name("PATH")
+				.value_parser(NonEmptyStringValueParser::new())
+				.requires("ber-tlv")
+				.conflicts_with_all(["input-file", "batch"])
+				.help("Read raw BER-TLV bytes from a file instead of a hex string.")
+				.long_help(
+					"Read raw BER-TLV bytes from a file instead of a hex string.\nThis is for \
+					 binary captures, like `.bin` files from a sniffer, which would otherwise \
+					 need to be hex-encoded first. It can only be used with `--ber-tlv`, and any \
+					 value given to `--ber-tlv` itself is ignored.",
+				),
+		)
+		.arg(
+			Arg::new("base64")
+				.long("base64")
+				.action(ArgAction::SetTrue)
+				.requires("ber-tlv")
+				.conflicts_with("input-file-binary")
+				.help("Decode the value for `--ber-tlv` as base64 instead of hex.")
+				.long_help(
+					"Decode the value for `--ber-tlv` as base64 instead of hex.\nThis is for data \
+					 from logging systems that store it base64-encoded. Both the standard and \
+					 URL-safe alphabets are accepted, and the padding is optional.",
+				),
+		)
+		.arg(
+			Arg::new("tag-dictionary")
+				.long("tag-dictionary")
+				.visible_alias("tags")
+				.num_args(1)
+				.action(ArgAction::Append)
+				.value_name("PATH")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help(
+					"Load additional tag names from a TOML or JSON file. This argument can be \
+					 provided multiple times to load multiple files.",
+				)
+				.long_help(
+					"Load additional tag names from a TOML or JSON file. This argument can be \
+					 provided multiple times to load multiple files.\nThe file maps tags to their \
+					 definitions, like `DF8101 = { name = \"Acquirer Reference\" }`, and is read \
+					 as JSON if it has a `.json` extension. A tag that `pemv` already knows keeps \
+					 its parser, but its name is overridden. Each definition can also provide \
+					 `parse_as` with a known tag, to parse the value the same way as that \
+					 tag.\nIf a tag is defined in multiple files, the last one is used.",
+				),
+		)
+		.arg(
+			Arg::new("repl")
+				.long("repl")
+				.visible_alias("interactive")
+				.action(ArgAction::SetTrue)
+				.conflicts_with_all(["batch", "input-file", "input-file-binary", "diff"])
+				.help(
+					"Keep reading values from stdin and parsing them, until the end of the input.",
+				)
+				.long_help(
+					"Keep reading values from stdin and parsing them, until the end of the \
+					 input.\nEach line is parsed with the chosen operation, or as TLV data with \
+					 the format found automatically if no operation is provided. Any value given \
+					 to the operation itself is ignored. An error on one line is shown and the \
+					 next line is read as usual.",
+				),
+		)
+		.arg(
+			Arg::new("batch")
+				.long("batch")
+				.action(ArgAction::SetTrue)
+				.requires("operations")
+				.help("Treat each non-empty line of the value as a separate value to parse.")
+				.long_help(
+					"Treat each non-empty line of the value as a separate value to parse.\nThis \
+					 is mostly useful alongside `--input-file`, for parsing a file with many \
+					 values like TLV strings from logs. Each result is shown under a header with \
+					 the line number it came from, and an error on one line doesn't stop the \
+					 remaining lines from being parsed. Any errors are reported at the end.",
+				),
+		)
 		.arg(
 			Arg::new("masking-character")
 				.short('m')
@@ -84,6 +245,67 @@ pub fn build_cli() -> Command {
 					 the masking character.",
 				),
 		)
+		.arg(
+			Arg::new("masking-byte")
+				.long("masking-byte")
+				.num_args(1..)
+				.action(ArgAction::Append)
+				.value_name("HEX BYTE")
+				.value_parser(|value: &str| {
+					u8::from_str_radix(value.trim_start_matches("0x"), 16)
+						.map_err(|_| "must be a single byte in hex, like `FF`")
+				})
+				.requires("tlv-parsers")
+				.help(
+					"A byte value that will be treated as masked data when parsing, like `00` or \
+					 `FF`. This argument can be provided multiple times.",
+				)
+				.long_help(
+					"A byte value that will be treated as masked data when parsing, like `00` or \
+					 `FF`. This argument can be provided multiple times.\nSome systems mask \
+					 binary data with bytes that aren't printable characters, so they can't be \
+					 provided with `--masking-character`. These apply on top of the masking \
+					 characters.",
+				),
+		)
+		.arg(
+			Arg::new("masking-mode")
+				.long("masking-mode")
+				.num_args(1)
+				.action(ArgAction::Set)
+				.value_name("MODE")
+				.value_parser(["all-same", "any-configured-char"])
+				.requires("tlv-parsers")
+				.help(
+					"How strictly data has to match the masking characters to be treated as \
+					 masked.",
+				)
+				.long_help(
+					"How strictly data has to match the masking characters to be treated as \
+					 masked.\n`all-same` requires the entire contents to be the same masking \
+					 character. `any-configured-char` only requires a run of masking characters, \
+					 in any combination, so partially-masked data like `4111****1111` is \
+					 recognised as well.",
+				),
+		)
+		.arg(
+			Arg::new("mask-single-byte")
+				.long("mask-single-byte")
+				.num_args(0..=1)
+				.default_missing_value("true")
+				.action(ArgAction::Set)
+				.value_name("TRUE/FALSE")
+				.value_parser(value_parser!(bool))
+				.requires("tlv-parsers")
+				.help("Whether a single byte of binary data can be treated as masked.")
+				.long_help(
+					"Whether a single byte of binary data can be treated as masked.\nBy default, \
+					 it takes at least 2 bytes, since a single byte like `2A` is just as likely \
+					 to be genuine data as a masked `*`. Enabling this recognises 1-byte masked \
+					 values, but genuine 1-byte values that match a masking character will be \
+					 hidden. Text data, like from `--ingenico-tlv`, isn't affected.",
+				),
+		)
 		.arg(
 			Arg::new("sort-parsed-tags")
 				.long("sort-parsed-tags")
@@ -114,22 +336,287 @@ pub fn build_cli() -> Command {
 					 the inverse to `--sort-parsed-tags`.",
 				),
 		)
+		.arg(
+			Arg::new("no-raw")
+				.long("no-raw")
+				.visible_alias("hide-raw")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.help(
+					"Don't display the raw value of each tag from TLV parsers like `--ber-tlv`, \
+					 only the parsed breakdown.",
+				)
+				.long_help(
+					"Don't display the raw value of each tag from TLV parsers like `--ber-tlv`, \
+					 only the parsed breakdown.\nThe raw value of constructed tags (like the FCI \
+					 Template) is never displayed if their child tags could be parsed, since the \
+					 child tags already show all of it.",
+				),
+		)
+		.arg(
+			Arg::new("offsets")
+				.long("offsets")
+				.visible_alias("show-offsets")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.conflicts_with("no-raw")
+				.help(
+					"Show the byte offset at the start of each line of the raw values from TLV \
+					 parsers like `--ber-tlv`.",
+				)
+				.long_help(
+					"Show the byte offset at the start of each line of the raw values from TLV \
+					 parsers like `--ber-tlv`.\nThe offsets are in hex and relative to the start \
+					 of the tag's value, like in a hex editor. This makes it easier to find a \
+					 position in long values, like certificates.",
+				),
+		)
+		.arg(
+			Arg::new("show-path")
+				.long("show-path")
+				.visible_alias("path")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.help(
+					"Show each tag from TLV parsers like `--ber-tlv` with the path of tags it's \
+					 nested inside, like `6F > A5 > 88`.",
+				)
+				.long_help(
+					"Show each tag from TLV parsers like `--ber-tlv` with the path of tags it's \
+					 nested inside, like `6F > A5 > 88`.\nThis makes it easier to tell which \
+					 constructed tag a tag belongs to in deeply-nested data, and to compare \
+					 captures.",
+				),
+		)
+		.arg(
+			Arg::new("highlight-bytes")
+				.long("highlight-bytes")
+				.visible_alias("highlight")
+				.action(ArgAction::SetTrue)
+				.requires("ber-tlv")
+				.help(
+					"Show the input of `--ber-tlv` before the parsed tags, with the tag, length, \
+					 and value bytes of each tag highlighted.",
+				)
+				.long_help(
+					"Show the input of `--ber-tlv` before the parsed tags, with the tag, length, \
+					 and value bytes of each tag highlighted.\nEach part of a tag is separated by \
+					 a space, and each top-level tag by two. This is useful for learning the \
+					 format, or for finding where a tag's encoding went wrong.",
+				),
+		)
+		.arg(
+			Arg::new("severity-summary")
+				.long("severity-summary")
+				.num_args(0..=1)
+				.default_missing_value("true")
+				.action(ArgAction::Set)
+				.value_name("TRUE/FALSE")
+				.value_parser(value_parser!(bool))
+				.requires("tlv-parsers")
+				.help(
+					"Whether to finish the output of TLV parsers like `--ber-tlv` with a count of \
+					 the warnings and errors in it.",
+				)
+				.long_help(
+					"Whether to finish the output of TLV parsers like `--ber-tlv` with a count of \
+					 the warnings and errors in it.\nOnly bits that are marked as warnings or \
+					 errors, like those in the TVR, are counted. This gives a quick sense of \
+					 whether a transaction looks healthy.",
+				),
+		)
+		.arg(
+			Arg::new("only")
+				.long("only")
+				.visible_alias("only-tags")
+				.num_args(1)
+				.value_delimiter(',')
+				.action(ArgAction::Append)
+				.value_name("TAG")
+				.value_parser(NonEmptyStringValueParser::new())
+				.requires("tlv-parsers")
+				.help(
+					"Only display the provided tags from TLV parsers like `--ber-tlv`, like \
+					 `--only 9F26,95`.",
+				)
+				.long_help(
+					"Only display the provided tags from TLV parsers like `--ber-tlv`, like \
+					 `--only 9F26,95`.\nTags nested inside constructed tags are still found, and \
+					 the constructed tags they're inside of are kept for context. This argument \
+					 can be provided multiple times.",
+				),
+		)
+		.arg(
+			Arg::new("validate")
+				.long("validate")
+				.visible_alias("cross-validate")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.help(
+					"Check the tags from TLV parsers like `--ber-tlv` for consistency with each \
+					 other.",
+				)
+				.long_help(
+					"Check the tags from TLV parsers like `--ber-tlv` for consistency with each \
+					 other.\nThis catches problems that can't be found by looking at each tag on \
+					 its own, like the application expiration date not matching the Track 2 data. \
+					 Any findings are shown after the parsed tags. All tags are checked, even \
+					 when `--only` is used.",
+				),
+		)
+		.arg(
+			Arg::new("strict")
+				.long("strict")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.help(
+					"Reject non-compliant data from TLV parsers like `--ber-tlv` instead of \
+					 tolerating it.",
+				)
+				.long_help(
+					"Reject non-compliant data from TLV parsers like `--ber-tlv` instead of \
+					 tolerating it.\nBy default, some common quirks are allowed, like constructed \
+					 tags whose data isn't valid BER-TLV, which are shown unparsed, and padding \
+					 bytes between tags, which are skipped. With this option they're treated as \
+					 errors instead, which is useful for conformance testing.",
+				),
+		)
+		.arg(
+			Arg::new("quiet")
+				.short('q')
+				.long("quiet")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.help(
+					"Only display the warnings and errors in the tags from TLV parsers like \
+					 `--ber-tlv`.",
+				)
+				.long_help(
+					"Only display the warnings and errors in the tags from TLV parsers like \
+					 `--ber-tlv`.\nThe full block is still parsed, but only tags with bits marked \
+					 as warnings or errors are shown, followed by a count of each. This is useful \
+					 for triage. It has no effect on JSON output.",
+				),
+		)
+		.arg(
+			Arg::new("summary")
+				.long("summary")
+				.visible_alias("channel")
+				.action(ArgAction::SetTrue)
+				.requires("tlv-parsers")
+				.help(
+					"Summarise how the transaction was carried out, from the tags from TLV \
+					 parsers like `--ber-tlv`.",
+				)
+				.long_help(
+					"Summarise how the transaction was carried out, from the tags from TLV \
+					 parsers like `--ber-tlv`.\nThe summary combines the POS Entry Mode (`9F39`), \
+					 the service code (`5F30` or `57`), and the Terminal Type (`9F35`) into one \
+					 line, like \"Contactless chip, chip card, online-capable, \
+					 merchant-attended\". All tags are used, even when `--only` is used.",
+				),
+		)
 		.next_help_heading("EMV Utilities")
 		.arg(
 			Arg::new("identify")
 				.group("operations")
 				.long("identify")
 				.alias("id")
-				.num_args(1)
+				.visible_alias("explain")
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("TAG")
 				.value_parser(NonEmptyStringValueParser::new())
-				.help("Attempt to identify an EMV tag by name.")
+				.help("Attempt to identify an EMV tag by name, and show its reference information.")
+				.long_help(
+					"Attempt to identify an EMV tag by name, and show its reference \
+					 information.\nThe class and data object type are properties of tags that \
+					 indicate what context they're designed to be used in, and what kind of data \
+					 they store. Constructed data objects contain nested EMV TLV data.\nTags with \
+					 a dedicated parser have their values broken down when parsing TLV data, \
+					 instead of only being identified by name.\nFor known EMV tags, the format \
+					 and length of the value are shown as well, along with where the tag is \
+					 documented.",
+				),
+		)
+		.arg(
+			Arg::new("list-tags")
+				.group("operations")
+				.long("list-tags")
+				.visible_alias("tags-list")
+				.action(ArgAction::SetTrue)
+				.help("List every EMV tag that can be identified.")
+				.long_help(
+					"List every EMV tag that can be identified.\nEach tag is marked as either \
+					 parsed, if it has a dedicated parser that breaks down its value, or \
+					 annotated only, if it's only identified by name. Tags loaded with \
+					 `--tag-dictionary` are included.",
+				),
+		)
+		.arg(
+			Arg::new("find-tag")
+				.group("operations")
+				.long("find-tag")
+				.visible_alias("search-tags")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("NAME")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Find EMV tags with names that contain the provided text.")
+				.long_help(
+					"Find EMV tags with names that contain the provided text.\nThe search ignores \
+					 case, so `--find-tag cryptogram` finds both `9F26` (Application Cryptogram) \
+					 and `9F27` (Cryptogram Information Data).",
+				),
+		)
+		.arg(
+			Arg::new("legend")
+				.group("operations")
+				.long("legend")
+				.visible_alias("severity-legend")
+				.action(ArgAction::SetTrue)
+				.help("Show what each severity colour in the output means."),
+		)
+		.arg(
+			Arg::new("encode")
+				.group("operations")
+				.long("encode")
+				.visible_alias("encode-ber-tlv")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("TAGS")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help(
+					"Encode a list of tags and values as BER-TLV, like \
+					 `5A=4761730000000119;5F34=01`.",
+				)
 				.long_help(
-					"Attempt to identify an EMV tag by name.\nThe class and data object type are \
-					 properties of tags that indicate what context they're designed to be used \
-					 in, and what kind of data they store. Constructed data objects contain \
-					 nested EMV TLV data.",
+					"Encode a list of tags and values as BER-TLV, like \
+					 `5A=4761730000000119;5F34=01`.\nThis is the inverse of `--ber-tlv`, and is \
+					 useful for constructing test data. Tags and values are both in hex, and the \
+					 tags are written in the order they're provided. Lengths are encoded \
+					 automatically, including lengths that need multiple bytes.",
+				),
+		)
+		.arg(
+			Arg::new("diff")
+				.group("operations")
+				.group("tlv-parsers")
+				.long("diff")
+				.visible_alias("compare")
+				.num_args(2)
+				.action(ArgAction::Set)
+				.value_names(["OLD", "NEW"])
+				.value_parser(NonEmptyStringValueParser::new())
+				.help(
+					"Compare two blocks of TLV data, showing which tags were added, removed, or \
+					 changed.",
+				)
+				.long_help(
+					"Compare two blocks of TLV data, showing which tags were added, removed, or \
+					 changed.\nThe format of each block is found automatically, like with \
+					 `--auto-tlv`. Tags are matched by their path, including any constructed tags \
+					 they're nested inside, and changed tags show both the old and new values.",
 				),
 		)
 		.arg(
@@ -141,11 +628,16 @@ pub fn build_cli() -> Command {
 				.visible_alias("auto")
 				.visible_alias("parse-tlv")
 				.visible_alias("parse")
-				.num_args(1)
-				.action(ArgAction::Set)
+				.num_args(0..)
+				.action(ArgAction::Append)
 				.value_name("EMV DATA BLOCK")
 				.value_parser(NonEmptyStringValueParser::new())
-				.help("Parse a block of TLV data, attempting to find the format automatically."),
+				.help("Parse a block of TLV data, attempting to find the format automatically.")
+				.long_help(
+					"Parse a block of TLV data, attempting to find the format \
+					 automatically.\nMultiple blocks can be provided, in which case each one is \
+					 parsed separately.",
+				),
 		)
 		.arg(
 			Arg::new("ber-tlv")
@@ -154,8 +646,8 @@ pub fn build_cli() -> Command {
 				.short('b')
 				.long("ber-tlv")
 				.alias("ber")
-				.num_args(1)
-				.action(ArgAction::Set)
+				.num_args(0..)
+				.action(ArgAction::Append)
 				.value_name("EMV DATA BLOCK")
 				.value_parser(NonEmptyStringValueParser::new())
 				.help("Parse a block of BER-TLV encoded data.")
@@ -163,7 +655,26 @@ pub fn build_cli() -> Command {
 					"Parse a block of BER-TLV encoded data.\nThe 'BER' stands for \"Basic \
 					 Encoding Rules\", and BER-TLV is the 'canonical' EMV TLV data format. That \
 					 said, some PIN pad manufacturers have their own variations with slight \
-					 differences.",
+					 differences.\nMultiple blocks can be provided, in which case each one is \
+					 parsed separately.",
+				),
+		)
+		.arg(
+			Arg::new("compact-tlv")
+				.group("operations")
+				.group("tlv-parsers")
+				.long("compact-tlv")
+				.alias("compact")
+				.num_args(0..)
+				.action(ArgAction::Append)
+				.value_name("DATA BLOCK")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse a block of compact-TLV encoded data.")
+				.long_help(
+					"Parse a block of compact-TLV encoded data.\nCompact-TLV packs the tag and \
+					 length into a single byte, and is used in the historical bytes of an ATR \
+					 (Answer to Reset).\nMultiple blocks can be provided, in which case each one \
+					 is parsed separately.",
 				),
 		)
 		.arg(
@@ -173,14 +684,30 @@ pub fn build_cli() -> Command {
 				.short('i')
 				.long("ingenico-tlv")
 				.alias("ingenico")
-				.num_args(1)
-				.action(ArgAction::Set)
+				.num_args(0..)
+				.action(ArgAction::Append)
 				.value_name("EMV DATA BLOCK")
 				.value_parser(NonEmptyStringValueParser::new())
 				.help("Parse a block of TLV data encoded in the proprietary Ingenico format.")
 				.long_help(
 					"Parse a block of TLV data encoded in the proprietary Ingenico format.\nNote \
-					 that this tool ignores non-EMV tags in the input data.",
+					 that this tool ignores non-EMV tags in the input data.\nMultiple blocks can \
+					 be provided, in which case each one is parsed separately.",
+				),
+		)
+		.arg(
+			Arg::new("dgi")
+				.group("operations")
+				.long("dgi")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("HEX DATA")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse a block of card personalisation data in the DGI format.")
+				.long_help(
+					"Parse a block of card personalisation data in the DGI format.\nEach DGI \
+					 (Data Grouping Identifier) is broken down into its data, and if the data is \
+					 BER-TLV encoded, it's parsed as well.",
 				),
 		)
 		.next_help_heading("Individual EMV Tags")
@@ -192,7 +719,7 @@ pub fn build_cli() -> Command {
 				.visible_alias("95")
 				.visible_alias("iac")
 				.visible_alias("tac")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("TVR")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -212,7 +739,7 @@ pub fn build_cli() -> Command {
 				.visible_alias("9F10")
 				.alias("9f10")
 				.visible_alias("iad")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("IAD")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -230,7 +757,7 @@ pub fn build_cli() -> Command {
 				.group("operations")
 				.long("ccd-cvr")
 				.visible_alias("cvr")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("CVR")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -242,13 +769,33 @@ pub fn build_cli() -> Command {
 					 from the card application intended for the card issuer.",
 				),
 		)
+		.arg(
+			Arg::new("ccd-cci")
+				.group("operations")
+				.long("ccd-cci")
+				.visible_alias("cci")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("CCI")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help(
+					"Parse a CCD-compliant Common Core Identifier (the first byte of tag 0x9F10).",
+				)
+				.long_help(
+					"Parse a CCD-compliant Common Core Identifier (the first byte of tag \
+					 0x9F10).\nSee the description of `--ccd-iad` for more information about CCD \
+					 compliance.\nThis value indicates the format of the rest of the issuer \
+					 application data, and whether the application cryptogram was generated with \
+					 Triple DES or AES.",
+				),
+		)
 		.arg(
 			Arg::new("tsi")
 				.group("operations")
 				.long("tsi")
 				.visible_alias("9B")
 				.alias("9b")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("TSI")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -267,7 +814,7 @@ pub fn build_cli() -> Command {
 				.visible_alias("9F34")
 				.alias("9f34")
 				.alias("cvm-result")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("CVM RESULTS")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -283,7 +830,7 @@ pub fn build_cli() -> Command {
 				.long("cvm-list")
 				.visible_alias("8E")
 				.alias("8e")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("CVM LIST")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -297,13 +844,44 @@ pub fn build_cli() -> Command {
 				),
 		)
 		.next_help_heading("Non-EMV")
+		.arg(
+			Arg::new("luhn")
+				.group("operations")
+				.long("luhn")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("NUMBER")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Check whether a number (like a PAN) has a valid Luhn check digit.")
+				.long_help(
+					"Check whether a number (like a PAN) has a valid Luhn check digit.\nThe Luhn \
+					 algorithm is a simple checksum used to catch transcription errors, with the \
+					 last digit of the number being the check digit. If the check digit is \
+					 incorrect, the expected check digit is shown.",
+				),
+		)
+		.arg(
+			Arg::new("luhn-complete")
+				.group("operations")
+				.long("luhn-complete")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("PARTIAL NUMBER")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Compute the Luhn check digit for a number (like a PAN) that's missing one.")
+				.long_help(
+					"Compute the Luhn check digit for a number (like a PAN) that's missing \
+					 one.\nThe number is shown with the check digit appended, which is useful for \
+					 making test PANs.",
+				),
+		)
 		.arg(
 			Arg::new("service-code")
 				.group("operations")
 				.long("service-code")
 				.visible_alias("5F30")
 				.alias("5f30")
-				.num_args(1)
+				.num_args(0..=1)
 				.action(ArgAction::Set)
 				.value_name("SERVICE CODE")
 				.value_parser(NonEmptyStringValueParser::new())
@@ -314,6 +892,106 @@ pub fn build_cli() -> Command {
 					 required to authorise transactions with it.",
 				),
 		)
+		.arg(
+			Arg::new("track1")
+				.group("operations")
+				.long("track1")
+				.alias("track-1")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("TRACK DATA")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse Track 1 data from a magnetic stripe (MSR), in format B.")
+				.long_help(
+					"Parse Track 1 data from a magnetic stripe (MSR), in format B.\nThis looks \
+					 like `%B4111111111111111^DOE/JOHN^2512201000000000?`, and the sentinels are \
+					 optional. If the character after the end sentinel is provided, it's checked \
+					 as the LRC of the track.",
+				),
+		)
+		.arg(
+			Arg::new("track2")
+				.group("operations")
+				.long("track2")
+				.alias("track-2")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("TRACK DATA")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse Track 2 data from a magnetic stripe (MSR).")
+				.long_help(
+					"Parse Track 2 data from a magnetic stripe (MSR).\nThis looks like \
+					 `;4111111111111111=25122010000000?`, and the sentinels are optional. If the \
+					 character after the end sentinel is provided, it's checked as the LRC of the \
+					 track. For the EMV form of this data, use `--ber-tlv` with tag `0x57`.",
+				),
+		)
+		.arg(
+			Arg::new("atr")
+				.group("operations")
+				.long("atr")
+				.alias("answer-to-reset")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("HEX DATA")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse a smartcard Answer To Reset (ATR).")
+				.long_help(
+					"Parse a smartcard Answer To Reset (ATR).\nThis is the first thing a card \
+					 sends after being powered up, and describes the transmission protocols it \
+					 supports (like T=0 and T=1) along with how to communicate with it. If the \
+					 check byte (TCK) is present, it's verified as well.",
+				),
+		)
+		.arg(
+			Arg::new("apdu")
+				.group("operations")
+				.long("apdu")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("HEX DATA")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse a command or response APDU.")
+				.long_help(
+					"Parse a command or response APDU.\nCommands are broken down into their \
+					 header and body, with common EMV instructions (like SELECT and GENERATE AC) \
+					 recognised. Responses have their status word decoded, and if the response \
+					 data is BER-TLV encoded, it's parsed as well.",
+				),
+		)
+		.arg(
+			Arg::new("apdu-response")
+				.group("operations")
+				.long("apdu-response")
+				.visible_alias("response")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("HEX DATA")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Parse a response APDU, with the status word at the end.")
+				.long_help(
+					"Parse a response APDU, with the status word at the end.\nThe last 2 bytes \
+					 are decoded as the status word (SW1 SW2), and the rest is parsed as BER-TLV \
+					 data if possible. Unlike `--apdu`, the value is never treated as a command, \
+					 which is useful when a response happens to look like one.",
+				),
+		)
+		.arg(
+			Arg::new("status-word")
+				.group("operations")
+				.long("status-word")
+				.visible_alias("sw")
+				.num_args(0..=1)
+				.action(ArgAction::Set)
+				.value_name("SW1SW2")
+				.value_parser(NonEmptyStringValueParser::new())
+				.help("Decode an ISO 7816 status word, like `6A82`.")
+				.long_help(
+					"Decode an ISO 7816 status word, like `6A82`.\nThe 2 bytes (SW1 SW2) at the \
+					 end of every response APDU say whether the command succeeded, and if not, \
+					 why. Use `--apdu-response` to parse a whole response instead.",
+				),
+		)
 }
 
 // Unit Tests
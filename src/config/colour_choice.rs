@@ -1,10 +1,9 @@
 // Uses
 use atty::{is as is_tty, Stream};
+use pemv::error::ParseError;
 use serde_derive::{Deserialize, Serialize};
 use termcolor::ColorChoice as TermColorChoice;
 
-use crate::error::ParseError;
-
 /// Wraps [`termcolor`]'s [`ColorChoice`] enum, with support for
 /// serialisation.
 ///
@@ -105,10 +104,10 @@ impl ColourChoice {
 #[cfg(test)]
 mod tests {
 	// Uses
+	use pemv::error::ParseError;
 	use termcolor::ColorChoice as TermColorChoice;
 
 	use super::ColourChoice;
-	use crate::error::ParseError;
 
 	// Tests
 	#[test]
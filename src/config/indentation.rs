@@ -0,0 +1,153 @@
+// Uses
+use pemv::{error::ParseError, util::DEFAULT_INDENTATION_UNIT};
+use serde_derive::{Deserialize, Serialize};
+
+/// The indentation to use for each level of nested output.
+///
+/// It's written the same way everywhere it can be set: either `tab`, or a
+/// number of spaces.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "IndentationValue", into = "IndentationValue")]
+pub enum Indentation {
+	/// A tab character.
+	#[default]
+	Tab,
+	/// A number of spaces.
+	Spaces(u8),
+}
+
+/// The raw forms the indentation can take in the configuration file or
+/// environment variables, since a number of spaces may not be a string.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum IndentationValue {
+	Number(i64),
+	Text(String),
+}
+
+impl Indentation {
+	/// The text printed for each level of indentation.
+	pub fn unit(self) -> String {
+		match self {
+			Self::Tab => DEFAULT_INDENTATION_UNIT.to_owned(),
+			Self::Spaces(spaces) => " ".repeat(spaces.into()),
+		}
+	}
+}
+
+impl TryFrom<&str> for Indentation {
+	type Error = ParseError;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		if value.eq_ignore_ascii_case("tab") {
+			return Ok(Self::Tab);
+		}
+
+		value
+			.parse()
+			.map(Self::Spaces)
+			.map_err(|_| ParseError::Unsupported)
+	}
+}
+
+impl TryFrom<IndentationValue> for Indentation {
+	type Error = String;
+
+	fn try_from(value: IndentationValue) -> Result<Self, Self::Error> {
+		match value {
+			IndentationValue::Number(spaces) => u8::try_from(spaces)
+				.map(Self::Spaces)
+				.map_err(|_| format!("expected `tab` or a number of spaces, found `{spaces}`")),
+			IndentationValue::Text(text) => Self::try_from(text.trim())
+				.map_err(|_| format!("expected `tab` or a number of spaces, found `{text}`")),
+		}
+	}
+}
+
+impl From<Indentation> for IndentationValue {
+	fn from(indentation: Indentation) -> Self {
+		match indentation {
+			Indentation::Tab => Self::Text("tab".to_owned()),
+			Indentation::Spaces(spaces) => Self::Number(spaces.into()),
+		}
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use figment::{
+		providers::{Format, Serialized, Toml},
+		Figment,
+	};
+	use pemv::error::ParseError;
+
+	use super::Indentation;
+	use crate::config::Config;
+
+	// Tests
+	fn indentation_from_toml(toml: &str) -> Result<Indentation, String> {
+		Config::try_from(
+			Figment::from(Serialized::defaults(Config::default())).merge(Toml::string(toml)),
+		)
+		.map(|config| config.indentation)
+		.map_err(|error| error.to_string())
+	}
+
+	#[test]
+	fn from_str() {
+		let expected = vec![
+			Ok(Indentation::Tab),
+			Ok(Indentation::Tab),
+			Ok(Indentation::Spaces(2)),
+		];
+		let result = ["tab", "TAB", "2"]
+			.into_iter()
+			.map(Indentation::try_from)
+			.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn from_str_error() {
+		let expected = Err(ParseError::Unsupported);
+		let result = Indentation::try_from("\t");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn unit() {
+		let expected = ("\t".to_owned(), "    ".to_owned());
+		let result = (Indentation::Tab.unit(), Indentation::Spaces(4).unit());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn config_number_of_spaces() {
+		let expected = Ok(Indentation::Spaces(2));
+		let result = indentation_from_toml("indentation = 2");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn config_text() {
+		let expected = (Ok(Indentation::Tab), Ok(Indentation::Spaces(4)));
+		let result = (
+			indentation_from_toml("indentation = \"tab\""),
+			indentation_from_toml("indentation = \"4\""),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn config_invalid() {
+		let result = (
+			indentation_from_toml("indentation = \"banana\""),
+			indentation_from_toml("indentation = 300"),
+		);
+
+		assert!(result.0.is_err());
+		assert!(result.1.is_err());
+	}
+}
@@ -0,0 +1,104 @@
+// Uses
+use pemv::{emv::MaskingMode as LibMaskingMode, error::ParseError};
+use serde_derive::{Deserialize, Serialize};
+
+/// Wraps the library's [`MaskingMode`] enum, with support for
+/// serialisation.
+///
+/// [`MaskingMode`]: pemv::emv::MaskingMode
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum MaskingMode {
+	/// The data is masked if every character is the same masking character.
+	#[default]
+	#[serde(rename = "all-same")]
+	AllSame,
+	/// The data is masked if it contains a run of masking characters, in any
+	/// combination.
+	#[serde(rename = "any-configured-char")]
+	AnyConfiguredChar,
+}
+
+impl From<LibMaskingMode> for MaskingMode {
+	fn from(mode: LibMaskingMode) -> Self {
+		match mode {
+			LibMaskingMode::AllSame => Self::AllSame,
+			LibMaskingMode::AnyConfiguredChar => Self::AnyConfiguredChar,
+		}
+	}
+}
+
+impl From<MaskingMode> for LibMaskingMode {
+	fn from(mode: MaskingMode) -> Self {
+		match mode {
+			MaskingMode::AllSame => Self::AllSame,
+			MaskingMode::AnyConfiguredChar => Self::AnyConfiguredChar,
+		}
+	}
+}
+
+impl TryFrom<&str> for MaskingMode {
+	type Error = ParseError;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		match value.to_lowercase().as_str() {
+			"all-same" => Ok(Self::AllSame),
+			"any-configured-char" => Ok(Self::AnyConfiguredChar),
+			_ => Err(ParseError::Unsupported),
+		}
+	}
+}
+
+impl From<MaskingMode> for &str {
+	fn from(mode: MaskingMode) -> Self {
+		match mode {
+			MaskingMode::AllSame => "all-same",
+			MaskingMode::AnyConfiguredChar => "any-configured-char",
+		}
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use pemv::{emv::MaskingMode as LibMaskingMode, error::ParseError};
+
+	use super::MaskingMode;
+
+	// Tests
+	#[test]
+	fn round_trip_conversions() {
+		macro_rules! test_round_trip {
+			($first_type:ty, $second_type:ty, $variant:ident) => {
+				let expected = <$first_type>::$variant;
+				let result: $second_type = <$first_type>::$variant.into();
+				let result: $first_type = result.into();
+				assert_eq!(expected, result);
+			};
+		}
+		macro_rules! test_round_trip_second_type_try {
+			($first_type:ty, $second_type:ty, $variant:ident) => {
+				let expected = <$first_type>::$variant;
+				let result: $second_type = <$first_type>::$variant.into();
+				let result: $first_type = result
+					.try_into()
+					.expect("error checking will be done separately");
+				assert_eq!(expected, result);
+			};
+		}
+
+		test_round_trip!(MaskingMode, LibMaskingMode, AllSame);
+		test_round_trip!(MaskingMode, LibMaskingMode, AnyConfiguredChar);
+		test_round_trip!(LibMaskingMode, MaskingMode, AllSame);
+		test_round_trip!(LibMaskingMode, MaskingMode, AnyConfiguredChar);
+		test_round_trip_second_type_try!(MaskingMode, &str, AllSame);
+		test_round_trip_second_type_try!(MaskingMode, &str, AnyConfiguredChar);
+	}
+	#[test]
+	fn from_str_error() {
+		let expected = Err(ParseError::Unsupported);
+		let result = MaskingMode::try_from("unsupported value");
+
+		assert_eq!(expected, result);
+	}
+}
@@ -1,5 +1,9 @@
 // Modules
 pub mod colour_choice;
+pub mod indentation;
+pub mod masking_mode;
+pub mod output_format;
+pub mod tag_dictionary;
 
 // Uses
 use clap::{parser::ValueSource, ArgMatches};
@@ -14,7 +18,12 @@ use figment::{
 };
 use serde_derive::{Deserialize, Serialize};
 
-use self::colour_choice::ColourChoice;
+use self::{
+	colour_choice::ColourChoice,
+	indentation::Indentation,
+	masking_mode::MaskingMode,
+	output_format::OutputFormat,
+};
 
 // Constants
 const FILE_NAME: &str = "pemv.toml";
@@ -29,19 +38,37 @@ const ENV_PROFILE: &str = "PROFILE";
 #[derive(Deserialize, Serialize)]
 pub struct Config {
 	#[serde(skip)]
-	pub profile:            Profile,
-	pub cli_colour:         ColourChoice,
-	pub masking_characters: Vec<char>,
-	pub sort_parsed_tags:   bool,
+	pub profile:               Profile,
+	pub cli_colour:            ColourChoice,
+	pub masking_characters:    Vec<char>,
+	pub masking_bytes:         Vec<u8>,
+	pub masking_mode:          MaskingMode,
+	pub mask_single_byte:      bool,
+	pub sort_parsed_tags:      bool,
+	pub show_raw_values:       bool,
+	pub show_severity_colours: bool,
+	pub show_severity_summary: bool,
+	pub indentation:           Indentation,
+	pub output_format:         OutputFormat,
+	pub tag_dictionaries:      Vec<String>,
 }
 
 impl Default for Config {
 	fn default() -> Config {
 		Config {
-			profile:            Self::DEFAULT_PROFILE,
-			cli_colour:         ColourChoice::default(),
-			masking_characters: vec!['*'],
-			sort_parsed_tags:   true,
+			profile:               Self::DEFAULT_PROFILE,
+			cli_colour:            ColourChoice::default(),
+			masking_characters:    vec!['*'],
+			masking_bytes:         Vec::new(),
+			masking_mode:          MaskingMode::default(),
+			mask_single_byte:      false,
+			sort_parsed_tags:      true,
+			show_raw_values:       true,
+			show_severity_colours: true,
+			show_severity_summary: false,
+			indentation:           Indentation::default(),
+			output_format:         OutputFormat::default(),
+			tag_dictionaries:      Vec::new(),
 		}
 	}
 }
@@ -50,9 +77,18 @@ impl Config {
 	// Constants
 	pub const CLI_COLOUR: &'static str = "cli_colour";
 	pub const DEFAULT_PROFILE: Profile = Profile::const_new("default");
+	pub const INDENTATION: &'static str = "indentation";
+	pub const MASKING_BYTES: &'static str = "masking_bytes";
 	pub const MASKING_CHARACTERS: &'static str = "masking_characters";
+	pub const MASKING_MODE: &'static str = "masking_mode";
+	pub const MASK_SINGLE_BYTE: &'static str = "mask_single_byte";
+	pub const OUTPUT_FORMAT: &'static str = "output_format";
 	pub const PROFILE: &'static str = "profile";
+	pub const SHOW_RAW_VALUES: &'static str = "show_raw_values";
+	pub const SHOW_SEVERITY_COLOURS: &'static str = "show_severity_colours";
+	pub const SHOW_SEVERITY_SUMMARY: &'static str = "show_severity_summary";
 	pub const SORT_PARSED_TAGS: &'static str = "sort_parsed_tags";
+	pub const TAG_DICTIONARIES: &'static str = "tag_dictionaries";
 
 	/// Allows the configuration to be extracted from any [`Provider`].
 	///
@@ -118,6 +154,34 @@ pub fn apply_cli_arguments(mut figment: Figment, matches: &ArgMatches) -> Figmen
 		}
 	}
 
+	// Output Format
+	if let Some(output_format) = matches.get_one::<String>("format") {
+		if matches.value_source("format").unwrap() != ValueSource::DefaultValue {
+			figment = figment.merge((
+				Config::OUTPUT_FORMAT,
+				OutputFormat::try_from(output_format.as_str())
+					.expect("this value's validity is enforced by clap"),
+			));
+		}
+	}
+
+	// Indentation
+	if let Some(indent) = matches.get_one::<String>("indent") {
+		figment = figment.merge((
+			Config::INDENTATION,
+			Indentation::try_from(indent.as_str())
+				.expect("this value's validity is enforced by clap"),
+		));
+	}
+
+	// Tag Dictionaries
+	if let Some(tag_dictionaries) = matches.get_many::<String>("tag-dictionary") {
+		figment = figment.merge((
+			Config::TAG_DICTIONARIES,
+			tag_dictionaries.cloned().collect::<Vec<String>>(),
+		));
+	}
+
 	// Masking Characters
 	if let Some(masking_characters) = matches.get_many::<char>("masking-character") {
 		if matches.value_source("masking-character").unwrap() != ValueSource::DefaultValue {
@@ -128,6 +192,28 @@ pub fn apply_cli_arguments(mut figment: Figment, matches: &ArgMatches) -> Figmen
 		}
 	}
 
+	// Masking Bytes
+	if let Some(masking_bytes) = matches.get_many::<u8>("masking-byte") {
+		figment = figment.merge((
+			Config::MASKING_BYTES,
+			masking_bytes.copied().collect::<Vec<u8>>(),
+		));
+	}
+
+	// Masking Mode
+	if let Some(masking_mode) = matches.get_one::<String>("masking-mode") {
+		figment = figment.merge((
+			Config::MASKING_MODE,
+			MaskingMode::try_from(masking_mode.as_str())
+				.expect("this value's validity is enforced by clap"),
+		));
+	}
+
+	// Single-Byte Masking
+	if let Some(&mask_single_byte) = matches.get_one::<bool>("mask-single-byte") {
+		figment = figment.merge((Config::MASK_SINGLE_BYTE, mask_single_byte));
+	}
+
 	// Sorting
 	let mut cli_provided_sort_preference = None;
 	if let Some(&sort_parsed_tags) = matches.get_one::<bool>("sort-parsed-tags") {
@@ -144,5 +230,20 @@ pub fn apply_cli_arguments(mut figment: Figment, matches: &ArgMatches) -> Figmen
 		figment = figment.merge((Config::SORT_PARSED_TAGS, sort_parsed_tags));
 	}
 
+	// Raw Values
+	if matches.get_flag("no-raw") {
+		figment = figment.merge((Config::SHOW_RAW_VALUES, false));
+	}
+
+	// Severity Colours
+	if matches.get_flag("no-severity-colour") {
+		figment = figment.merge((Config::SHOW_SEVERITY_COLOURS, false));
+	}
+
+	// Severity Summary
+	if let Some(&show_severity_summary) = matches.get_one::<bool>("severity-summary") {
+		figment = figment.merge((Config::SHOW_SEVERITY_SUMMARY, show_severity_summary));
+	}
+
 	figment
 }
@@ -0,0 +1,69 @@
+// Uses
+use pemv::error::ParseError;
+use serde_derive::{Deserialize, Serialize};
+
+/// The format to write parsed output in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum OutputFormat {
+	/// A human-readable breakdown, with colour if enabled.
+	#[default]
+	#[serde(rename = "human")]
+	Human,
+	/// Machine-readable JSON, without any colour.
+	#[serde(rename = "json")]
+	Json,
+}
+
+impl TryFrom<&str> for OutputFormat {
+	type Error = ParseError;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		match value.to_lowercase().as_str() {
+			"human" => Ok(Self::Human),
+			"json" => Ok(Self::Json),
+			_ => Err(ParseError::Unsupported),
+		}
+	}
+}
+
+impl From<OutputFormat> for &str {
+	fn from(format: OutputFormat) -> Self {
+		match format {
+			OutputFormat::Human => "human",
+			OutputFormat::Json => "json",
+		}
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use pemv::error::ParseError;
+
+	use super::OutputFormat;
+
+	// Tests
+	#[test]
+	fn round_trip_conversions() {
+		macro_rules! test_round_trip {
+			($variant:ident) => {
+				let expected = OutputFormat::$variant;
+				let result: &str = OutputFormat::$variant.into();
+				let result =
+					OutputFormat::try_from(result).expect("error checking will be done separately");
+				assert_eq!(expected, result);
+			};
+		}
+
+		test_round_trip!(Human);
+		test_round_trip!(Json);
+	}
+	#[test]
+	fn from_str_error() {
+		let expected = Err(ParseError::Unsupported);
+		let result = OutputFormat::try_from("unsupported value");
+
+		assert_eq!(expected, result);
+	}
+}
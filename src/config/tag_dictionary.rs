@@ -0,0 +1,200 @@
+//! Loading of user-supplied tag dictionaries, which name tags that `pemv`
+//! doesn't know about or override the names of ones it does.
+//!
+//! A dictionary maps tags to their definitions, and can be written in TOML:
+//!
+//! ```toml
+//! DF8101 = { name = "Acquirer Reference" }
+//! DF8102 = { name = "Proprietary TTQ", parse_as = "9F66" }
+//! ```
+//!
+//! Or in JSON, if the file has a `.json` extension:
+//!
+//! ```json
+//! { "DF8101": { "name": "Acquirer Reference" } }
+//! ```
+
+// Uses
+use std::{collections::BTreeMap, fs::read_to_string, path::Path};
+
+use figment::{
+	providers::{Format, Toml},
+	Figment,
+};
+use pemv::{emv::CustomTag, util::parse_hex_str_strict};
+use serde_derive::Deserialize;
+
+// Constants
+const JSON_EXTENSION: &str = "json";
+
+/// A single entry in a tag dictionary.
+#[derive(Debug, Deserialize)]
+struct TagDictionaryEntry {
+	name:     String,
+	/// A known tag whose parser should be used for the value.
+	#[serde(default)]
+	parse_as: Option<String>,
+}
+
+/// The format a tag dictionary is written in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TagDictionaryFormat {
+	Toml,
+	Json,
+}
+
+impl TagDictionaryFormat {
+	/// Determines the format from a file's extension, defaulting to TOML.
+	pub fn from_path(path: &Path) -> Self {
+		match path.extension() {
+			Some(extension) if extension.eq_ignore_ascii_case(JSON_EXTENSION) => Self::Json,
+			_ => Self::Toml,
+		}
+	}
+}
+
+/// Reads and parses the tag dictionary at `path`.
+pub fn load_tag_dictionary(path: &str) -> Result<Vec<CustomTag>, String> {
+	let contents = read_to_string(path).map_err(|error| error.to_string())?;
+
+	parse_tag_dictionary(
+		contents.as_str(),
+		TagDictionaryFormat::from_path(Path::new(path)),
+	)
+}
+
+/// Parses the contents of a tag dictionary.
+pub fn parse_tag_dictionary(
+	contents: &str,
+	format: TagDictionaryFormat,
+) -> Result<Vec<CustomTag>, String> {
+	let entries = match format {
+		TagDictionaryFormat::Toml => Figment::from(Toml::string(contents))
+			.extract::<BTreeMap<String, TagDictionaryEntry>>()
+			.map_err(|error| error.to_string())?,
+		TagDictionaryFormat::Json => {
+			serde_json::from_str(contents).map_err(|error| error.to_string())?
+		}
+	};
+
+	entries
+		.into_iter()
+		.map(|(tag_str, entry)| {
+			let parse_tag = |tag_str: &str| {
+				parse_hex_str_strict(tag_str.trim())
+					.ok()
+					.filter(|tag| !tag.is_empty())
+					.ok_or_else(|| format!("`{tag_str}` isn't a valid tag"))
+			};
+
+			Ok(CustomTag {
+				tag:      parse_tag(tag_str.as_str())?,
+				name:     entry.name,
+				parse_as: entry.parse_as.as_deref().map(parse_tag).transpose()?,
+			})
+		})
+		.collect()
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::path::Path;
+
+	use pemv::emv::{CustomTag, ParserKind};
+
+	use super::{parse_tag_dictionary, TagDictionaryFormat};
+
+	// Tests
+	#[test]
+	fn parse_toml() {
+		let expected = Ok(vec![
+			CustomTag {
+				tag:      vec![0x95],
+				name:     "TVR".to_owned(),
+				parse_as: None,
+			},
+			CustomTag {
+				tag:      vec![0xDF, 0x81, 0x01],
+				name:     "Proprietary TTQ".to_owned(),
+				parse_as: Some(vec![0x9F, 0x66]),
+			},
+		]);
+		let result = parse_tag_dictionary(
+			"95 = { name = \"TVR\" }\nDF8101 = { name = \"Proprietary TTQ\", parse_as = \"9F66\" }",
+			TagDictionaryFormat::Toml,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_json() {
+		let expected = Ok(vec![CustomTag {
+			tag:      vec![0xDF, 0x81, 0x01],
+			name:     "Acquirer Reference".to_owned(),
+			parse_as: None,
+		}]);
+		let result = parse_tag_dictionary(
+			"{ \"DF8101\": { \"name\": \"Acquirer Reference\" } }",
+			TagDictionaryFormat::Json,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_invalid_tag() {
+		let expected = Err("`DF8G` isn't a valid tag".to_owned());
+		let result =
+			parse_tag_dictionary("DF8G = { name = \"Invalid\" }", TagDictionaryFormat::Toml);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn loaded_definitions_add_and_override() {
+		let expected = Ok(vec![
+			(
+				[0x95].as_slice(),
+				"TVR",
+				ParserKind::TerminalVerificationResults,
+			),
+			(
+				[0xDF, 0x81, 0x01].as_slice(),
+				"Acquirer Reference",
+				ParserKind::None,
+			),
+		]);
+		let result = parse_tag_dictionary(
+			"95 = { name = \"TVR\" }\nDF8101 = { name = \"Acquirer Reference\" }",
+			TagDictionaryFormat::Toml,
+		)
+		.and_then(|custom_tags| {
+			custom_tags
+				.into_iter()
+				.map(|custom_tag| {
+					custom_tag
+						.into_definition()
+						.map(|definition| (definition.tag, definition.name, definition.parser))
+						.map_err(|error| error.to_string())
+				})
+				.collect::<Result<Vec<_>, _>>()
+		});
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn format_from_path() {
+		let expected = vec![
+			TagDictionaryFormat::Json,
+			TagDictionaryFormat::Json,
+			TagDictionaryFormat::Toml,
+			TagDictionaryFormat::Toml,
+		];
+		let result = ["tags.json", "tags.JSON", "tags.toml", "tags"]
+			.into_iter()
+			.map(|path| TagDictionaryFormat::from_path(Path::new(path)))
+			.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+}
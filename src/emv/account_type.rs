@@ -0,0 +1,100 @@
+//! The account type value, typically from EMV tag `0x5F57`.
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::Value as JsonValue;
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::util::print_indentation;
+use crate::{enum_repr_fallible, error::ParseError, DisplayBreakdown};
+
+// Enum Implementation
+enum_repr_fallible! {
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AccountType: u8, ParseError, { |_| ParseError::Unrecognised } {
+	Default = 0x00 => "Default - Unspecified",
+	Savings = 0x10 => "Savings",
+	Cheque  = 0x20 => "Cheque/Debit",
+	Credit  = 0x30 => "Credit",
+}
+}
+
+impl TryFrom<&[u8]> for AccountType {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 1;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Self::try_from(raw_bytes[0])
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for AccountType {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		writeln!(stdout, "{self}").ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::String(self.to_string())
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::AccountType;
+	use crate::{
+		enum_byte_slice_result_matches_true_value_result,
+		error::ParseError,
+		wrong_byte_count,
+	};
+
+	// Tests
+	wrong_byte_count!(super::AccountType, 1);
+	enum_byte_slice_result_matches_true_value_result!(
+		super::AccountType,
+		1,
+		0x30,
+		[0x30].as_slice()
+	);
+
+	#[test]
+	fn parse_defined_values() {
+		let expected = Ok(vec![
+			AccountType::Default,
+			AccountType::Savings,
+			AccountType::Cheque,
+			AccountType::Credit,
+		]);
+		let result = [0x00, 0x10, 0x20, 0x30]
+			.into_iter()
+			.map(AccountType::try_from)
+			.collect::<Result<Vec<_>, _>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_undefined_value() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = AccountType::try_from([0x40].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
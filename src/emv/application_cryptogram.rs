@@ -0,0 +1,100 @@
+//! The Application Cryptogram, typically from EMV tag `0x9F26`.
+//!
+//! It's generated by the card in response to the GENERATE AC command, and its
+//! value is opaque without the issuer's keys - the type of cryptogram is given
+//! by the Cryptogram Information Data (`0x9F27`) instead.
+//!
+//! Information for this can be found in EMV Book 2, under section `8.1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::header_colour_spec, util::print_indentation};
+
+// Constants
+/// The number of bytes in an Application Cryptogram.
+const NUM_BYTES: usize = 8;
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ApplicationCryptogram {
+	pub cryptogram: [u8; NUM_BYTES],
+}
+
+impl TryFrom<&[u8]> for ApplicationCryptogram {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		let cryptogram = raw_bytes
+			.try_into()
+			.map_err(|_| ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			})?;
+
+		Ok(Self { cryptogram })
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ApplicationCryptogram {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec()).ok();
+		write!(stdout, "Application Cryptogram ({NUM_BYTES} bytes):").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {}", bytes_to_str(&self.cryptogram)).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"cryptogram": bytes_to_str(&self.cryptogram),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::ApplicationCryptogram;
+	use crate::{error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::ApplicationCryptogram, 8);
+
+	#[test]
+	fn parse_valid() {
+		let expected = Ok(ApplicationCryptogram {
+			cryptogram: [0x8B, 0x5C, 0x3E, 0x1A, 0x2F, 0x47, 0x90, 0xD6],
+		});
+		let result = ApplicationCryptogram::try_from(
+			[0x8B, 0x5C, 0x3E, 0x1A, 0x2F, 0x47, 0x90, 0xD6].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_too_short() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Equal,
+			expected: 8,
+			found:    7,
+		});
+		let result =
+			ApplicationCryptogram::try_from([0x8B, 0x5C, 0x3E, 0x1A, 0x2F, 0x47, 0x90].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
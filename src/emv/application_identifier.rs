@@ -0,0 +1,191 @@
+//! The Application Identifier (AID), typically from EMV tags `0x4F`, `0x84`
+//! (as the DF name), and `0x9F06`.
+//!
+//! The first 5 bytes of an AID are the Registered Application Provider
+//! Identifier (RID), which identifies the payment scheme. The rest is the
+//! Proprietary Application Identifier Extension (PIX), which is assigned by the
+//! scheme.
+//!
+//! Only AIDs with well-known RIDs are parsed, since tags like `0x84` can also
+//! hold names that aren't AIDs, like `1PAY.SYS.DDF01`.
+//!
+//! Information for this can be found in EMV Book 1, under section `12.2.1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{bold_colour_spec, header_colour_spec},
+	util::print_indentation,
+};
+
+// Constants
+/// The number of bytes in a RID.
+const RID_BYTES: usize = 5;
+/// The maximum number of bytes in an AID.
+const MAX_BYTES: usize = 16;
+/// Well-known RIDs and the payment schemes they belong to.
+const KNOWN_RIDS: &[([u8; RID_BYTES], &str)] = &[
+	([0xA0, 0x00, 0x00, 0x00, 0x03], "Visa"),
+	([0xA0, 0x00, 0x00, 0x00, 0x04], "Mastercard"),
+	([0xA0, 0x00, 0x00, 0x00, 0x05], "Mastercard (Maestro UK)"),
+	([0xA0, 0x00, 0x00, 0x00, 0x25], "American Express"),
+	([0xA0, 0x00, 0x00, 0x00, 0x42], "Cartes Bancaires (CB)"),
+	([0xA0, 0x00, 0x00, 0x00, 0x65], "JCB"),
+	([0xA0, 0x00, 0x00, 0x01, 0x21], "Dankort"),
+	([0xA0, 0x00, 0x00, 0x01, 0x52], "Discover"),
+	([0xA0, 0x00, 0x00, 0x02, 0x77], "Interac"),
+	([0xA0, 0x00, 0x00, 0x03, 0x24], "Discover"),
+	([0xA0, 0x00, 0x00, 0x03, 0x33], "UnionPay"),
+	([0xA0, 0x00, 0x00, 0x05, 0x24], "RuPay"),
+];
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ApplicationIdentifier {
+	pub rid:    [u8; RID_BYTES],
+	pub pix:    Vec<u8>,
+	/// The payment scheme the RID belongs to.
+	pub scheme: &'static str,
+}
+
+impl TryFrom<&[u8]> for ApplicationIdentifier {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		if raw_bytes.len() < RID_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Greater,
+				expected: RID_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+		if raw_bytes.len() > MAX_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Less,
+				expected: MAX_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		let mut rid = [0u8; RID_BYTES];
+		rid.copy_from_slice(&raw_bytes[..RID_BYTES]);
+		let scheme = KNOWN_RIDS
+			.iter()
+			.find(|(known_rid, _)| *known_rid == rid)
+			.map(|(_, scheme)| *scheme)
+			.ok_or(ParseError::Unrecognised)?;
+
+		Ok(Self {
+			rid,
+			pix: raw_bytes[RID_BYTES..].to_vec(),
+			scheme,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ApplicationIdentifier {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		let header_colour_spec = header_colour_spec();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "RID:").ok();
+		stdout.reset().ok();
+		write!(stdout, " {} - ", bytes_to_str(&self.rid)).ok();
+		stdout.set_color(&bold_colour_spec()).ok();
+		write!(stdout, "{}", self.scheme).ok();
+		stdout.reset().ok();
+		writeln!(stdout).ok();
+
+		if !self.pix.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "PIX:").ok();
+			stdout.reset().ok();
+			writeln!(stdout, " {}", bytes_to_str(self.pix.as_slice())).ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"rid": bytes_to_str(&self.rid),
+			"pix": bytes_to_str(self.pix.as_slice()),
+			"scheme": self.scheme,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::ApplicationIdentifier;
+	use crate::error::ParseError;
+
+	// Tests
+	#[test]
+	fn parse_visa() {
+		let expected = Ok(ApplicationIdentifier {
+			rid:    [0xA0, 0x00, 0x00, 0x00, 0x03],
+			pix:    vec![0x10, 0x10],
+			scheme: "Visa",
+		});
+		let result =
+			ApplicationIdentifier::try_from([0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_mastercard() {
+		let expected = Ok(ApplicationIdentifier {
+			rid:    [0xA0, 0x00, 0x00, 0x00, 0x04],
+			pix:    vec![0x10, 0x10],
+			scheme: "Mastercard",
+		});
+		let result =
+			ApplicationIdentifier::try_from([0xA0, 0x00, 0x00, 0x00, 0x04, 0x10, 0x10].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_unknown_rid() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = ApplicationIdentifier::try_from([0xA0, 0x00, 0x00, 0x99, 0x99].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_too_short() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Greater,
+			expected: 5,
+			found:    4,
+		});
+		let result = ApplicationIdentifier::try_from([0xA0, 0x00, 0x00, 0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_too_long() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Less,
+			expected: 16,
+			found:    17,
+		});
+		let result = ApplicationIdentifier::try_from([0xA0; 17].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
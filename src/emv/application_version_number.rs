@@ -0,0 +1,79 @@
+//! The application version number, typically from EMV tags `0x9F08` (ICC) and
+//! `0x9F09` (terminal).
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ApplicationVersionNumber {
+	pub version: u16,
+}
+
+impl TryFrom<&[u8]> for ApplicationVersionNumber {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 2;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Ok(Self {
+			version: u16::from_be_bytes([raw_bytes[0], raw_bytes[1]]),
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ApplicationVersionNumber {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		write!(stdout, "Version ").ok();
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "v{:04X}", self.version).ok();
+		stdout.reset().ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"version": format!("{:04X}", self.version),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::ApplicationVersionNumber;
+	use crate::wrong_byte_count;
+
+	// Tests
+	wrong_byte_count!(super::ApplicationVersionNumber, 2);
+
+	#[test]
+	fn parse_version() {
+		let expected = Ok(ApplicationVersionNumber { version: 0x008C });
+		let result = ApplicationVersionNumber::try_from([0x00, 0x8C].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -1,18 +1,21 @@
 //! The module for the trait that defines the interface for bitflag values.
 
 // Uses
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
 use derivative::Derivative;
-use termcolor::{Color, ColorSpec, StandardStream, WriteColor};
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
-// Public Exports
+#[cfg(feature = "display")]
 use crate::{
-	output_colours::bold_colour_spec,
+	output_colours::{bold_colour_spec, severity_colour_spec},
 	util::print_indentation,
-	DisplayBreakdown,
 	BITS_PER_BYTE,
 };
+// Public Exports
+use crate::{util::bytes_to_str, DisplayBreakdown};
 
 // Utility structures for child implementations
 
@@ -31,12 +34,102 @@ pub struct EnabledBitRange {
 
 /// Represents the severity of a bit being enabled.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum Severity {
 	Normal,
 	Warning,
 	Error,
 }
 
+#[cfg(not(tarpaulin_include))]
+impl Display for Severity {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(match self {
+			Self::Normal => "Normal",
+			Self::Warning => "Warning",
+			Self::Error => "Error",
+		})
+	}
+}
+
+/// A tally of how many warnings and errors were found.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct SeverityCounts {
+	pub warnings: usize,
+	pub errors:   usize,
+}
+
+impl SeverityCounts {
+	/// Counts one more occurrence of the provided severity.
+	///
+	/// [`Severity::Normal`] isn't counted.
+	pub fn add(&mut self, severity: Severity) {
+		match severity {
+			Severity::Normal => {}
+			Severity::Warning => self.warnings += 1,
+			Severity::Error => self.errors += 1,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for SeverityCounts {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(
+			f,
+			"{} warning{}, {} error{}",
+			self.warnings,
+			if self.warnings == 1 { "" } else { "s" },
+			self.errors,
+			if self.errors == 1 { "" } else { "s" }
+		)
+	}
+}
+
+/// A note about something worth drawing attention to, like an inconsistency
+/// between tags in a block or a value that fails validation.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Finding {
+	pub message:  String,
+	pub severity: Severity,
+}
+
+impl From<EnabledBitRange> for Finding {
+	fn from(enabled_bit: EnabledBitRange) -> Self {
+		Self {
+			message:  enabled_bit.explanation,
+			severity: enabled_bit.severity,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for Finding {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		print_indentation(stdout, indentation);
+		stdout
+			.set_color(&severity_colour_spec(self.severity, show_severity_colours))
+			.ok();
+		writeln!(stdout, "{}", self.message).ok();
+		stdout.reset().ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"message": self.message,
+			"severity": self.severity.to_string(),
+		})
+	}
+}
+
 /// A value that is stored in a bitflag-style format according to the EMV Books.
 pub trait BitflagValue
 where
@@ -67,12 +160,13 @@ where
 
 impl<V> DisplayBreakdown for V
 where
-	V: BitflagValue + Debug,
+	V: BitflagValue + Debug + 'static,
 {
 	#[cfg(not(tarpaulin_include))]
+	#[cfg(feature = "display")]
 	fn display_breakdown(
 		&self,
-		stdout: &mut StandardStream,
+		stdout: &mut dyn WriteColor,
 		indentation: u8,
 		show_severity_colours: bool,
 	) {
@@ -85,24 +179,24 @@ where
 		let binary_repr = self.get_binary_representation();
 
 		// Print the binary representation
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&bold_colour_spec).ok();
 		let mut first = true;
 		for byte in binary_repr {
 			if first {
 				first = false;
 			} else {
-				print!(" ");
+				write!(stdout, " ").ok();
 			}
 			for offset in (0..BITS_PER_BYTE).rev() {
 				if byte & (1 << offset) > 0 {
-					print!("1");
+					write!(stdout, "1").ok();
 				} else {
-					print!("0");
+					write!(stdout, "0").ok();
 				}
 			}
 		}
-		println!();
+		writeln!(stdout).ok();
 		stdout.reset().ok();
 
 		// Print the breakdown
@@ -118,24 +212,24 @@ where
 		// denoting each one's width
 		if multi_bit_value {
 			let mut current_offset = num_bits - 1;
-			print_indentation(indentation);
+			print_indentation(stdout, indentation);
 			for enabled_bit_range in &enabled_bit_ranges {
 				for i in enabled_bit_range.offset..=current_offset {
 					if (i + 1) % 8 == 0 && i + 1 < num_bits {
-						print!(" ");
+						write!(stdout, " ").ok();
 					}
 					if i != enabled_bit_range.offset {
-						print!(" ");
+						write!(stdout, " ").ok();
 					}
 				}
 				if enabled_bit_range.len > 1 {
-					print!("\u{251c}");
+					write!(stdout, "\u{251c}").ok();
 					for _ in 0..(enabled_bit_range.len - 2) {
-						print!("\u{2500}");
+						write!(stdout, "\u{2500}").ok();
 					}
-					print!("\u{2518}");
+					write!(stdout, "\u{2518}").ok();
 				} else {
-					print!("\u{2502}");
+					write!(stdout, "\u{2502}").ok();
 				}
 				// This somewhat bizarre condition is to handle the case of, for example:
 				// offset = 7, len = 8 (1 byte, and the final segment)
@@ -145,35 +239,57 @@ where
 					current_offset = 0;
 				}
 			}
-			println!();
+			writeln!(stdout).ok();
 		}
 		for enabled_bit in enabled_bit_ranges.iter().rev() {
-			print_indentation(indentation);
+			print_indentation(stdout, indentation);
 			// Print leading space
 			for i in 1..(num_bits - enabled_bit.offset) {
 				if arm_bits & (1 << (num_bits - i)) > 0 {
-					print!("\u{2502}");
+					write!(stdout, "\u{2502}").ok();
 				} else {
-					print!(" ");
+					write!(stdout, " ").ok();
 				}
 				if (num_bits - i) % 8 == 0 {
-					print!(" ");
+					write!(stdout, " ").ok();
 				}
 			}
-			print!("\u{2514} ");
+			write!(stdout, "\u{2514} ").ok();
 			stdout
-				.set_color(ColorSpec::new().set_fg(if show_severity_colours {
-					match enabled_bit.severity {
-						Severity::Normal => None,
-						Severity::Warning => Some(Color::Yellow),
-						Severity::Error => Some(Color::Red),
-					}
-				} else {
-					None
-				}))
+				.set_color(&severity_colour_spec(
+					enabled_bit.severity,
+					show_severity_colours,
+				))
 				.ok();
-			println!("{}", enabled_bit.explanation);
+			writeln!(stdout, "{}", enabled_bit.explanation).ok();
 			stdout.reset().ok();
 		}
 	}
+
+	#[cfg(not(tarpaulin_include))]
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"raw": bytes_to_str(self.get_binary_representation().as_slice()),
+			"bits": self
+				.get_bit_display_information()
+				.iter()
+				.map(|enabled_bit| {
+					json!({
+						"offset": enabled_bit.offset,
+						"length": enabled_bit.len,
+						"explanation": enabled_bit.explanation,
+						"severity": enabled_bit.severity.to_string(),
+					})
+				})
+				.collect::<Vec<_>>(),
+		})
+	}
+
+	fn get_notable_bits(&self) -> Vec<Finding> {
+		self.get_bit_display_information()
+			.into_iter()
+			.filter(|enabled_bit| enabled_bit.severity != Severity::Normal)
+			.map(Finding::from)
+			.collect()
+	}
 }
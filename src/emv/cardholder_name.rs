@@ -0,0 +1,152 @@
+//! The cardholder name, typically from EMV tag `0x5F20`.
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+use crate::{error::ParseError, util::bytes_to_printable_ascii, DisplayBreakdown};
+
+// Constants
+const PADDING_CHAR: char = ' ';
+const NON_PRINTABLE_REPLACEMENT_CHAR: char = '.';
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct CardholderName {
+	/// The name, with trailing padding removed and any non-printable bytes
+	/// replaced.
+	pub name:                  String,
+	/// The offsets of any bytes that aren't printable ASCII.
+	pub non_printable_offsets: Vec<usize>,
+}
+
+impl TryFrom<&[u8]> for CardholderName {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const MAX_BYTES: usize = 26;
+
+		if raw_bytes.len() > MAX_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Less,
+				expected: MAX_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		let (mut name, non_printable_offsets) =
+			bytes_to_printable_ascii(raw_bytes, NON_PRINTABLE_REPLACEMENT_CHAR);
+		name.truncate(name.trim_end_matches(PADDING_CHAR).len());
+
+		Ok(Self {
+			name,
+			non_printable_offsets,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for CardholderName {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "{}", self.name).ok();
+		stdout.reset().ok();
+
+		if !self.non_printable_offsets.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Warning,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(
+				stdout,
+				"Contains non-printable bytes at offset{} {}",
+				if self.non_printable_offsets.len() == 1 {
+					""
+				} else {
+					"s"
+				},
+				self.non_printable_offsets
+					.iter()
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+					.join(", ")
+			)
+			.ok();
+			stdout.reset().ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"name": self.name,
+			"non_printable_offsets": self.non_printable_offsets,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::CardholderName;
+	use crate::error::ParseError;
+
+	// Tests
+	#[test]
+	fn parse_padded() {
+		let expected = Ok(CardholderName {
+			name:                  "DOE/JANE".to_owned(),
+			non_printable_offsets: vec![],
+		});
+		let result = CardholderName::try_from(b"DOE/JANE    ".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_non_printable() {
+		let expected = Ok(CardholderName {
+			name:                  "DOE.JANE".to_owned(),
+			non_printable_offsets: vec![3],
+		});
+		let result = CardholderName::try_from(b"DOE\x00JANE ".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_too_many_bytes() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Less,
+			expected: 26,
+			found:    27,
+		});
+		let result = CardholderName::try_from([b'A'; 27].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
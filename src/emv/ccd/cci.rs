@@ -1,6 +1,10 @@
 //! Everything for handling the Common Core Identifier (CCI).
 //!
 //! Information for this can be found in EMV Book 3, under section `C7.1`.
+//!
+//! Only IAD Format A and cryptogram versions `5` (Triple DES) and `6` (AES)
+//! are defined there. Every other value is RFU, so the data isn't treated as
+//! CCD-compliant if it has one.
 
 // Uses
 use std::cmp::Ordering;
@@ -59,6 +63,20 @@ mod tests {
 		let expected = Err(ParseError::NonCcdCompliant);
 		let result = CommonCoreIdentifier::try_from([0b1000_1010].as_slice());
 
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_from_bytes_rfu_format_code() {
+		let expected = Err(ParseError::NonCcdCompliant);
+		let result = CommonCoreIdentifier::try_from([0b1011_0101].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_from_bytes_rfu_cryptogram_version() {
+		let expected = Err(ParseError::NonCcdCompliant);
+		let result = CommonCoreIdentifier::try_from([0b1010_0111].as_slice());
+
 		assert_eq!(expected, result);
 	}
 }
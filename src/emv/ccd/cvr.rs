@@ -96,10 +96,31 @@ pub enum GenAc2ApplicationCryptogramType: u8, ParseError, { |_| ParseError::NonC
 #[cfg(test)]
 mod tests {
 	// Uses
-	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+	use crate::{
+		bitflag_display_bits,
+		bitflag_unique_values,
+		display_breakdown_matches,
+		wrong_byte_count,
+	};
 
 	// Tests
 	wrong_byte_count!(super::CardVerificationResults, 5);
 	bitflag_unique_values!(super::CardVerificationResults, 5);
 	bitflag_display_bits!(super::CardVerificationResults, 5);
+	display_breakdown_matches!(
+		display_breakdown,
+		super::CardVerificationResults::try_from([0xA0, 0x30, 0x00, 0x00, 0x00].as_slice())
+			.expect("the CVR is valid"),
+		concat!(
+			"10100000 00110000 00000000 00000000 00000000\n",
+			"├┘├┘     ├──┘              ├──┘\n",
+			"│ │      │                 └ Number of successfully processed issuer script commands \
+			 containing secure messaging: 0\n",
+			"│ │      └ PIN try count: 3\n",
+			"│ └ Application cryptogram type returned in 1st GENERATE AC: ARQC (Authorization \
+			 Request Cryptogram)\n",
+			"└ Application cryptogram type returned in 2nd GENERATE AC: Second GENERATE AC not \
+			 requested\n",
+		)
+	);
 }
@@ -3,18 +3,21 @@
 //! Information for this can be found in EMV Book 3, under section `C7`.
 
 // Uses
-use termcolor::{StandardStream, WriteColor};
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
 use super::{CardVerificationResults, CommonCoreIdentifier, FormatCode};
+use crate::{error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
 use crate::{
-	error::ParseError,
 	output_colours::header_colour_spec,
 	util::{print_bytes, print_indentation},
-	DisplayBreakdown,
 };
 
 // Struct Implementation
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct IssuerApplicationData {
 	pub cci:                  CommonCoreIdentifier,
 	pub format_specific_data: FormatSpecificData,
@@ -48,6 +51,7 @@ impl TryFrom<&[u8]> for IssuerApplicationData {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum FormatSpecificData {
 	A {
 		dki: u8,
@@ -101,17 +105,18 @@ impl PartialEq<FormatSpecificData> for FormatCode {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for IssuerApplicationData {
+	#[cfg(feature = "display")]
 	fn display_breakdown(
 		&self,
-		stdout: &mut StandardStream,
+		stdout: &mut dyn WriteColor,
 		indentation: u8,
 		show_severity_colours: bool,
 	) {
 		let header_colour_spec = header_colour_spec();
 
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		println!("Common Core Identifier:");
+		writeln!(stdout, "Common Core Identifier:").ok();
 		stdout.reset().ok();
 		self.cci
 			.display_breakdown(stdout, indentation + 1, show_severity_colours);
@@ -124,35 +129,57 @@ impl DisplayBreakdown for IssuerApplicationData {
 				issuer_discretionary_data,
 			} => {
 				// Print the DKI
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				print!("Derivation Key Index:");
+				write!(stdout, "Derivation Key Index:").ok();
 				stdout.reset().ok();
-				println!(" {dki:#04X}");
+				writeln!(stdout, " {dki:#04X}").ok();
 
 				// Print the CVR
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				println!("Card Verification Results:");
+				writeln!(stdout, "Card Verification Results:").ok();
 				stdout.reset().ok();
 				cvr.display_breakdown(stdout, indentation + 1, show_severity_colours);
 
 				// Print the counter bytes
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				println!("Counters: (Payment System-Specific)");
+				writeln!(stdout, "Counters: (Payment System-Specific)").ok();
 				stdout.reset().ok();
-				print_bytes(&counter_bytes[..], 16, indentation + 1);
+				print_bytes(stdout, &counter_bytes[..], 16, indentation + 1);
 
 				// Print the issuer-discretionary data
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				println!("Issuer-Discretionary Data");
+				writeln!(stdout, "Issuer-Discretionary Data").ok();
 				stdout.reset().ok();
-				print_bytes(&issuer_discretionary_data[..], 16, indentation + 1);
+				print_bytes(stdout, &issuer_discretionary_data[..], 16, indentation + 1);
 			}
 		}
 	}
+
+	fn to_json_value(&self) -> JsonValue {
+		let format_specific_data = match &self.format_specific_data {
+			FormatSpecificData::A {
+				dki,
+				cvr,
+				counter_bytes,
+				issuer_discretionary_data,
+			} => json!({
+				"format": "A",
+				"dki": dki,
+				"cvr": cvr.to_json_value(),
+				"counters": bytes_to_str(&counter_bytes[..]),
+				"issuer_discretionary_data": bytes_to_str(&issuer_discretionary_data[..]),
+			}),
+		};
+
+		json!({
+			"cci": self.cci.to_json_value(),
+			"format_specific_data": format_specific_data,
+		})
+	}
 }
 
 // Unit Tests
@@ -272,6 +299,23 @@ mod tests {
 		assert_eq!(expected, result);
 	}
 
+	/// This tests with data that would otherwise be valid, but with an RFU IAD
+	/// format code in the CCI.
+	#[test]
+	fn non_ccd_compliant_rfu_format_code() {
+		let expected = Err(ParseError::NonCcdCompliant);
+		let result = IssuerApplicationData::try_from(
+			[
+				0x0F, 0xB5, 0x01, 0xA2, 0x30, 0x30, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0x0F, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0x00, 0x00,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
 	#[test]
 	fn comparing_format_codes() {
 		let format_code = FormatCode::A;
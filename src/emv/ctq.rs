@@ -0,0 +1,77 @@
+//! Everything for handling Card Transaction Qualifiers (CTQ) values, typically
+//! from EMV tag `0x9F6C`.
+//!
+//! The CTQ is the card's counterpart to the Terminal Transaction Qualifiers,
+//! and indicates which CVMs the card requires and how the reader should handle
+//! failures.
+//!
+//! Information for this can be found in EMV Contactless Book C-3, under
+//! section `A.2`.
+
+// Uses
+use std::cmp::Ordering;
+
+use crate::{bitflag_value, error::ParseError};
+
+// Struct Implementation
+bitflag_value! {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CardTransactionQualifiers: 2 {
+	0 {
+		pub online_pin_required: bool =                        0b1000_0000
+			=> "Online PIN required",
+		pub signature_required: bool =                         0b0100_0000
+			=> "Signature required",
+		pub go_online_if_oda_fails: bool =                     0b0010_0000
+			=> "Go online if offline data authentication fails and the reader is online capable",
+		pub switch_interface_if_oda_fails: bool =              0b0001_0000
+			=> "Switch interface if offline data authentication fails and the reader supports \
+				contact chip",
+		pub go_online_if_application_expired: bool =           0b0000_1000
+			=> "Go online if the application has expired",
+		pub switch_interface_for_cash_transactions: bool =     0b0000_0100
+			=> "Switch interface for cash transactions",
+		pub switch_interface_for_cashback_transactions: bool = 0b0000_0010
+			=> "Switch interface for cashback transactions",
+	}
+	1 {
+		pub consumer_device_cvm_performed: bool =              0b1000_0000
+			=> "Consumer device CVM performed",
+		pub issuer_update_processing_supported: bool =         0b0100_0000
+			=> "Card supports issuer update processing at the POS",
+	}
+}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::CardTransactionQualifiers;
+	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::CardTransactionQualifiers, 2);
+	bitflag_unique_values!(super::CardTransactionQualifiers, 2);
+	bitflag_display_bits!(super::CardTransactionQualifiers, 2);
+
+	#[test]
+	fn parse_consumer_device_cvm_performed() {
+		let result = CardTransactionQualifiers::try_from([0x00, 0x80].as_slice())
+			.expect("the testing value should be able to be parsed without error");
+
+		assert!(result.consumer_device_cvm_performed);
+		assert!(!result.online_pin_required);
+		assert!(!result.signature_required);
+	}
+	#[test]
+	fn parse_go_online_bits() {
+		let result = CardTransactionQualifiers::try_from([0x28, 0x00].as_slice())
+			.expect("the testing value should be able to be parsed without error");
+
+		assert!(result.go_online_if_oda_fails);
+		assert!(result.go_online_if_application_expired);
+		assert!(!result.switch_interface_if_oda_fails);
+		assert!(!result.consumer_device_cvm_performed);
+	}
+}
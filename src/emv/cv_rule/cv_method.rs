@@ -27,6 +27,7 @@ pub enum CvMethod: u8, ParseError, { |_| ParseError::NonCompliant } {
 
 /// A somewhat dumb workaround to have custom impls on [`Option<CvMethod>`].
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct OptionalCvMethod {
 	pub internal: Option<CvMethod>,
 }
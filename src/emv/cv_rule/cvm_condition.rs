@@ -46,6 +46,7 @@ impl CvmCondition {
 /// A somewhat dumb workaround to have a [`Display`] impl on
 /// [`Option<CvmCondition>`].
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct OptionalCvmCondition {
 	pub internal: Option<CvmCondition>,
 }
@@ -5,14 +5,18 @@
 // Uses
 use std::cmp::Ordering;
 
-use termcolor::{StandardStream, WriteColor};
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
-use super::{BitflagValue, CardholderVerificationRule, CvmCondition};
+#[cfg(feature = "display")]
+use super::CvmCondition;
+use super::{BitflagValue, CardholderVerificationRule};
+use crate::{error::ParseError, util::byte_slice_to_u32, DisplayBreakdown};
+#[cfg(feature = "display")]
 use crate::{
-	error::ParseError,
 	output_colours::{bold_colour_spec, header_colour_spec},
-	util::{byte_slice_to_u32, num_dec_digits, print_indentation},
-	DisplayBreakdown,
+	util::{num_dec_digits, print_indentation},
 };
 
 // Constants
@@ -20,6 +24,7 @@ const MIN_BYTES: usize = 8;
 
 // Struct Implementation
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct CardholderVerificationMethodList {
 	pub x_value:  u32,
 	pub y_value:  u32,
@@ -41,8 +46,8 @@ impl TryFrom<&[u8]> for CardholderVerificationMethodList {
 			return Err(ParseError::ByteCountNotDivisibleIntoComponents);
 		}
 
-		let x_value = byte_slice_to_u32(&bytes[0..4]);
-		let y_value = byte_slice_to_u32(&bytes[4..8]);
+		let x_value = byte_slice_to_u32(&bytes[0..4])?;
+		let y_value = byte_slice_to_u32(&bytes[4..8])?;
 		let mut cv_rules =
 			Vec::with_capacity((bytes.len() - MIN_BYTES) / CardholderVerificationRule::NUM_BYTES);
 		for byte_pair in bytes[8..].chunks(CardholderVerificationRule::NUM_BYTES) {
@@ -59,7 +64,8 @@ impl TryFrom<&[u8]> for CardholderVerificationMethodList {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for CardholderVerificationMethodList {
-	fn display_breakdown(&self, stdout: &mut StandardStream, indentation: u8, _: bool) {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
 		/// This value is chosen as 3 because common currency denominations have
 		/// 2 digits for the cents (or equivalent) and this allows 1 additional
 		/// digit to represent the whole amount. For example, `$0.00`.
@@ -84,65 +90,87 @@ impl DisplayBreakdown for CardholderVerificationMethodList {
 				.max(MIN_VALUE_DIGITS);
 
 			// Print the X value
-			print_indentation(indentation);
+			print_indentation(stdout, indentation);
 			stdout.set_color(&header_colour_spec).ok();
-			print!("X Value:");
+			write!(stdout, "X Value:").ok();
 			stdout.reset().ok();
-			println!(
+			writeln!(
+				stdout,
 				" {:0>value_padding_length$} (implicit decimal point based on application \
 				 currency)",
 				self.x_value
-			);
+			)
+			.ok();
 
 			// Print the Y value
-			print_indentation(indentation);
+			print_indentation(stdout, indentation);
 			stdout.set_color(&header_colour_spec).ok();
-			print!("Y Value:");
+			write!(stdout, "Y Value:").ok();
 			stdout.reset().ok();
-			println!(" {:0>value_padding_length$}", self.y_value);
+			writeln!(stdout, " {:0>value_padding_length$}", self.y_value).ok();
 		}
 
 		// Print the CV Rules
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		println!("Cardholder Verification Rules:");
+		writeln!(stdout, "Cardholder Verification Rules:").ok();
 		stdout.reset().ok();
 		for (i, cv_rule) in self.cv_rules.iter().enumerate() {
 			// Print the CVM index
-			print_indentation(indentation);
+			print_indentation(stdout, indentation);
 			stdout.set_color(&bold_colour_spec).ok();
-			println!("CVM {}:", i + 1);
+			writeln!(stdout, "CVM {}:", i + 1).ok();
 			stdout.reset().ok();
 
 			// Print the method
-			print_indentation(indentation + 1);
+			print_indentation(stdout, indentation + 1);
 			stdout.set_color(&bold_colour_spec).ok();
-			print!("Method:         ");
+			write!(stdout, "Method:         ").ok();
 			stdout.reset().ok();
-			println!(" {}", cv_rule.method);
+			writeln!(stdout, " {}", cv_rule.method).ok();
 
 			// Print the condition
-			print_indentation(indentation + 1);
+			print_indentation(stdout, indentation + 1);
 			stdout.set_color(&bold_colour_spec).ok();
-			print!("Condition:      ");
+			write!(stdout, "Condition:      ").ok();
 			stdout.reset().ok();
-			println!(" {}", cv_rule.condition);
+			writeln!(stdout, " {}", cv_rule.condition).ok();
 
 			// Print whether to continue if unsuccessful
-			print_indentation(indentation + 1);
+			print_indentation(stdout, indentation + 1);
 			stdout.set_color(&bold_colour_spec).ok();
-			print!("If Unsuccessful:");
+			write!(stdout, "If Unsuccessful:").ok();
 			stdout.reset().ok();
-			println!(
+			writeln!(
+				stdout,
 				" {}",
 				if cv_rule.continue_if_unsuccessful {
 					"Next CVM"
 				} else {
 					"Fail"
 				}
-			);
+			)
+			.ok();
 		}
 	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"x_value": self.x_value,
+			"y_value": self.y_value,
+			"cv_rules": self
+				.cv_rules
+				.iter()
+				.map(|cv_rule| {
+					json!({
+						"method": cv_rule.method.to_string(),
+						"condition": cv_rule.condition.to_string(),
+						"continue_if_unsuccessful": cv_rule.continue_if_unsuccessful,
+					})
+				})
+				.collect::<Vec<_>>(),
+		})
+	}
 }
 
 // Unit Tests
@@ -10,6 +10,7 @@ use crate::{enum_repr_fallible, error::ParseError};
 
 // Struct Implementation
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct CardholderVerificationMethodResults {
 	// CV Rule
 	pub cv_rule: CardholderVerificationRule,
@@ -0,0 +1,198 @@
+//! A Data Object List (DOL), like the CDOL1 (`0x8C`), the PDOL (`0x9F38`), or
+//! the Log Format (`0x9F4F`).
+//!
+//! A DOL lists the tags and lengths of the values that should be concatenated
+//! together to build some data, without the values themselves.
+//!
+//! Information for this can be found in EMV Book 3, under section `5.4`.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{emv::identify_tag, error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::header_colour_spec, util::print_indentation};
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct DataObjectList {
+	pub entries: Vec<DataObjectListEntry>,
+}
+
+/// A single tag in a DOL, and the length its value should be.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct DataObjectListEntry {
+	pub tag:    Vec<u8>,
+	pub name:   Option<&'static str>,
+	pub length: u8,
+}
+
+impl DataObjectList {
+	/// The total length of the data the DOL describes.
+	pub fn data_length(&self) -> usize {
+		self.entries
+			.iter()
+			.map(|entry| usize::from(entry.length))
+			.sum()
+	}
+}
+
+impl TryFrom<&[u8]> for DataObjectList {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		let bytes_len = raw_bytes.len();
+		let mut entries = Vec::new();
+		let mut index = 0;
+		while index < bytes_len {
+			// The tag continues if the last 5 bits of the first byte are all 1, and each
+			// subsequent byte indicates if another byte follows if the first bit is 1
+			let tag_start_index = index;
+			let mut tag_continues = 0b0001_1111 & raw_bytes[index] == 0b0001_1111;
+			while tag_continues {
+				index += 1;
+				if index >= bytes_len {
+					return Err(ParseError::NonCompliant);
+				}
+				tag_continues = 0b1000_0000 & raw_bytes[index] > 0;
+			}
+			index += 1;
+
+			// Each tag is followed by a single length byte
+			if index >= bytes_len {
+				return Err(ParseError::NonCompliant);
+			}
+			let tag = &raw_bytes[tag_start_index..index];
+			entries.push(DataObjectListEntry {
+				tag:    tag.to_vec(),
+				name:   identify_tag(tag),
+				length: raw_bytes[index],
+			});
+			index += 1;
+		}
+
+		Ok(Self { entries })
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for DataObjectList {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		let header_colour_spec = header_colour_spec();
+
+		for entry in &self.entries {
+			let tag_str = bytes_to_str(entry.tag.as_slice());
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "{tag_str:<8}").ok();
+			stdout.reset().ok();
+			write!(
+				stdout,
+				"{:>3} byte{}",
+				entry.length,
+				if entry.length == 1 { "" } else { "s" }
+			)
+			.ok();
+			match entry.name {
+				Some(name) => writeln!(stdout, " - {name}").ok(),
+				None => writeln!(stdout).ok(),
+			};
+		}
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Total:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {} bytes", self.data_length()).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"entries": self
+				.entries
+				.iter()
+				.map(|entry| json!({
+					"tag": bytes_to_str(entry.tag.as_slice()),
+					"name": entry.name,
+					"length": entry.length,
+				}))
+				.collect::<Vec<_>>(),
+			"data_length": self.data_length(),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{DataObjectList, DataObjectListEntry};
+	use crate::error::ParseError;
+
+	// Tests
+	#[test]
+	fn parse_log_format() {
+		let expected = Ok(DataObjectList {
+			entries: vec![
+				DataObjectListEntry {
+					tag:    vec![0x9A],
+					name:   Some("Transaction Date"),
+					length: 3,
+				},
+				DataObjectListEntry {
+					tag:    vec![0x9F, 0x21],
+					name:   Some("Transaction Time"),
+					length: 3,
+				},
+				DataObjectListEntry {
+					tag:    vec![0x9F, 0x02],
+					name:   Some("Amount, Authorised (Numeric)"),
+					length: 6,
+				},
+				DataObjectListEntry {
+					tag:    vec![0x5F, 0x2A],
+					name:   Some("Transaction Currency Code"),
+					length: 2,
+				},
+				DataObjectListEntry {
+					tag:    vec![0x9F, 0x36],
+					name:   Some("Application Transaction Counter (ATC)"),
+					length: 2,
+				},
+			],
+		});
+		let result = DataObjectList::try_from(
+			[
+				0x9A, 0x03, 0x9F, 0x21, 0x03, 0x9F, 0x02, 0x06, 0x5F, 0x2A, 0x02, 0x9F, 0x36, 0x02,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn data_length() {
+		let expected = Ok(16);
+		let result = DataObjectList::try_from(
+			[
+				0x9A, 0x03, 0x9F, 0x21, 0x03, 0x9F, 0x02, 0x06, 0x5F, 0x2A, 0x02, 0x9F, 0x36, 0x02,
+			]
+			.as_slice(),
+		)
+		.map(|dol| dol.data_length());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_missing_length() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = DataObjectList::try_from([0x9A, 0x03, 0x9F, 0x21].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -21,18 +21,17 @@
 //! > ICC requesting a `TC`.
 
 // Uses
-use termcolor::{StandardStream, WriteColor};
+use serde_json::Value as JsonValue;
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
-use crate::{
-	error::ParseError,
-	output_colours::header_colour_spec,
-	util::print_indentation,
-	DisplayBreakdown,
-	TerminalVerificationResults,
-};
+use crate::{emv::TerminalVerificationResults, error::ParseError, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::header_colour_spec, util::print_indentation};
 
 // Struct Implementation
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct IssuerActionCodeDefault {
 	pub tvr: TerminalVerificationResults,
 }
@@ -70,19 +69,26 @@ impl TryFrom<&[u8]> for IssuerActionCodeDefault {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for IssuerActionCodeDefault {
-	fn display_breakdown(&self, stdout: &mut StandardStream, indentation: u8, _: bool) {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
 		let header_colour_spec = header_colour_spec();
 
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		println!(
+		writeln!(
+			stdout,
 			"If not an online transaction and any of the following match the TVR, reject the \
 			 transaction:"
-		);
+		)
+		.ok();
 		stdout.reset().ok();
 
 		self.tvr.display_breakdown(stdout, indentation, false);
 	}
+
+	fn to_json_value(&self) -> JsonValue {
+		self.tvr.to_json_value()
+	}
 }
 
 // Unit Tests
@@ -0,0 +1,314 @@
+//! The Issuer Code Table Index, typically from EMV tag `0x9F11`.
+//!
+//! It indicates which part of ISO/IEC 8859 the Application Preferred Name
+//! (`0x9F12`) is encoded with. The value is 2 BCD digits.
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`, and in
+//! EMV Book 4, under section `11.1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, util::bcd_to_decimal_string, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
+
+// Constants
+/// The names of each ISO/IEC 8859 part, starting from part 1.
+const CODE_TABLE_NAMES: [&str; 10] = [
+	"Latin-1, Western European",
+	"Latin-2, Central European",
+	"Latin-3, South European",
+	"Latin-4, North European",
+	"Latin/Cyrillic",
+	"Latin/Arabic",
+	"Latin/Greek",
+	"Latin/Hebrew",
+	"Latin-5, Turkish",
+	"Latin-6, Nordic",
+];
+/// The first byte where the ISO/IEC 8859 parts differ from each other. Bytes
+/// below this are ASCII or control characters in every part.
+const UPPER_HALF_START: u8 = 0xA0;
+/// The characters for bytes `0xA0` to `0xFF` in each ISO/IEC 8859 part,
+/// starting from part 1. Positions that aren't assigned in a part are `0x0000`.
+const CODE_TABLE_UPPER_HALVES: [[u16; 96]; 10] = [
+	// Part 1
+	[
+		0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x00AA,
+		0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF, 0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5,
+		0x00B6, 0x00B7, 0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF, 0x00C0,
+		0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7, 0x00C8, 0x00C9, 0x00CA, 0x00CB,
+		0x00CC, 0x00CD, 0x00CE, 0x00CF, 0x00D0, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6,
+		0x00D7, 0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF, 0x00E0, 0x00E1,
+		0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7, 0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC,
+		0x00ED, 0x00EE, 0x00EF, 0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+		0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF,
+	],
+	// Part 2
+	[
+		0x00A0, 0x0104, 0x02D8, 0x0141, 0x00A4, 0x013D, 0x015A, 0x00A7, 0x00A8, 0x0160, 0x015E,
+		0x0164, 0x0179, 0x00AD, 0x017D, 0x017B, 0x00B0, 0x0105, 0x02DB, 0x0142, 0x00B4, 0x013E,
+		0x015B, 0x02C7, 0x00B8, 0x0161, 0x015F, 0x0165, 0x017A, 0x02DD, 0x017E, 0x017C, 0x0154,
+		0x00C1, 0x00C2, 0x0102, 0x00C4, 0x0139, 0x0106, 0x00C7, 0x010C, 0x00C9, 0x0118, 0x00CB,
+		0x011A, 0x00CD, 0x00CE, 0x010E, 0x0110, 0x0143, 0x0147, 0x00D3, 0x00D4, 0x0150, 0x00D6,
+		0x00D7, 0x0158, 0x016E, 0x00DA, 0x0170, 0x00DC, 0x00DD, 0x0162, 0x00DF, 0x0155, 0x00E1,
+		0x00E2, 0x0103, 0x00E4, 0x013A, 0x0107, 0x00E7, 0x010D, 0x00E9, 0x0119, 0x00EB, 0x011B,
+		0x00ED, 0x00EE, 0x010F, 0x0111, 0x0144, 0x0148, 0x00F3, 0x00F4, 0x0151, 0x00F6, 0x00F7,
+		0x0159, 0x016F, 0x00FA, 0x0171, 0x00FC, 0x00FD, 0x0163, 0x02D9,
+	],
+	// Part 3
+	[
+		0x00A0, 0x0126, 0x02D8, 0x00A3, 0x00A4, 0x0000, 0x0124, 0x00A7, 0x00A8, 0x0130, 0x015E,
+		0x011E, 0x0134, 0x00AD, 0x0000, 0x017B, 0x00B0, 0x0127, 0x00B2, 0x00B3, 0x00B4, 0x00B5,
+		0x0125, 0x00B7, 0x00B8, 0x0131, 0x015F, 0x011F, 0x0135, 0x00BD, 0x0000, 0x017C, 0x00C0,
+		0x00C1, 0x00C2, 0x0000, 0x00C4, 0x010A, 0x0108, 0x00C7, 0x00C8, 0x00C9, 0x00CA, 0x00CB,
+		0x00CC, 0x00CD, 0x00CE, 0x00CF, 0x0000, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x0120, 0x00D6,
+		0x00D7, 0x011C, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x016C, 0x015C, 0x00DF, 0x00E0, 0x00E1,
+		0x00E2, 0x0000, 0x00E4, 0x010B, 0x0109, 0x00E7, 0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC,
+		0x00ED, 0x00EE, 0x00EF, 0x0000, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x0121, 0x00F6, 0x00F7,
+		0x011D, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x016D, 0x015D, 0x02D9,
+	],
+	// Part 4
+	[
+		0x00A0, 0x0104, 0x0138, 0x0156, 0x00A4, 0x0128, 0x013B, 0x00A7, 0x00A8, 0x0160, 0x0112,
+		0x0122, 0x0166, 0x00AD, 0x017D, 0x00AF, 0x00B0, 0x0105, 0x02DB, 0x0157, 0x00B4, 0x0129,
+		0x013C, 0x02C7, 0x00B8, 0x0161, 0x0113, 0x0123, 0x0167, 0x014A, 0x017E, 0x014B, 0x0100,
+		0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x012E, 0x010C, 0x00C9, 0x0118, 0x00CB,
+		0x0116, 0x00CD, 0x00CE, 0x012A, 0x0110, 0x0145, 0x014C, 0x0136, 0x00D4, 0x00D5, 0x00D6,
+		0x00D7, 0x00D8, 0x0172, 0x00DA, 0x00DB, 0x00DC, 0x0168, 0x016A, 0x00DF, 0x0101, 0x00E1,
+		0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x012F, 0x010D, 0x00E9, 0x0119, 0x00EB, 0x0117,
+		0x00ED, 0x00EE, 0x012B, 0x0111, 0x0146, 0x014D, 0x0137, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+		0x00F8, 0x0173, 0x00FA, 0x00FB, 0x00FC, 0x0169, 0x016B, 0x02D9,
+	],
+	// Part 5
+	[
+		0x00A0, 0x0401, 0x0402, 0x0403, 0x0404, 0x0405, 0x0406, 0x0407, 0x0408, 0x0409, 0x040A,
+		0x040B, 0x040C, 0x00AD, 0x040E, 0x040F, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415,
+		0x0416, 0x0417, 0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420,
+		0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B,
+		0x042C, 0x042D, 0x042E, 0x042F, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0436,
+		0x0437, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E, 0x043F, 0x0440, 0x0441,
+		0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044A, 0x044B, 0x044C,
+		0x044D, 0x044E, 0x044F, 0x2116, 0x0451, 0x0452, 0x0453, 0x0454, 0x0455, 0x0456, 0x0457,
+		0x0458, 0x0459, 0x045A, 0x045B, 0x045C, 0x00A7, 0x045E, 0x045F,
+	],
+	// Part 6
+	[
+		0x00A0, 0x0000, 0x0000, 0x0000, 0x00A4, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+		0x0000, 0x060C, 0x00AD, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+		0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x061B, 0x0000, 0x0000, 0x0000, 0x061F, 0x0000,
+		0x0621, 0x0622, 0x0623, 0x0624, 0x0625, 0x0626, 0x0627, 0x0628, 0x0629, 0x062A, 0x062B,
+		0x062C, 0x062D, 0x062E, 0x062F, 0x0630, 0x0631, 0x0632, 0x0633, 0x0634, 0x0635, 0x0636,
+		0x0637, 0x0638, 0x0639, 0x063A, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0640, 0x0641,
+		0x0642, 0x0643, 0x0644, 0x0645, 0x0646, 0x0647, 0x0648, 0x0649, 0x064A, 0x064B, 0x064C,
+		0x064D, 0x064E, 0x064F, 0x0650, 0x0651, 0x0652, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+		0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+	],
+	// Part 7
+	[
+		0x00A0, 0x2018, 0x2019, 0x00A3, 0x20AC, 0x20AF, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x037A,
+		0x00AB, 0x00AC, 0x00AD, 0x0000, 0x2015, 0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x0384, 0x0385,
+		0x0386, 0x00B7, 0x0388, 0x0389, 0x038A, 0x00BB, 0x038C, 0x00BD, 0x038E, 0x038F, 0x0390,
+		0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A, 0x039B,
+		0x039C, 0x039D, 0x039E, 0x039F, 0x03A0, 0x03A1, 0x0000, 0x03A3, 0x03A4, 0x03A5, 0x03A6,
+		0x03A7, 0x03A8, 0x03A9, 0x03AA, 0x03AB, 0x03AC, 0x03AD, 0x03AE, 0x03AF, 0x03B0, 0x03B1,
+		0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC,
+		0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7,
+		0x03C8, 0x03C9, 0x03CA, 0x03CB, 0x03CC, 0x03CD, 0x03CE, 0x0000,
+	],
+	// Part 8
+	[
+		0x00A0, 0x0000, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x00D7,
+		0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF, 0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5,
+		0x00B6, 0x00B7, 0x00B8, 0x00B9, 0x00F7, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x0000, 0x0000,
+		0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+		0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+		0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2017, 0x05D0, 0x05D1,
+		0x05D2, 0x05D3, 0x05D4, 0x05D5, 0x05D6, 0x05D7, 0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC,
+		0x05DD, 0x05DE, 0x05DF, 0x05E0, 0x05E1, 0x05E2, 0x05E3, 0x05E4, 0x05E5, 0x05E6, 0x05E7,
+		0x05E8, 0x05E9, 0x05EA, 0x0000, 0x0000, 0x200E, 0x200F, 0x0000,
+	],
+	// Part 9
+	[
+		0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x00AA,
+		0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF, 0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5,
+		0x00B6, 0x00B7, 0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF, 0x00C0,
+		0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7, 0x00C8, 0x00C9, 0x00CA, 0x00CB,
+		0x00CC, 0x00CD, 0x00CE, 0x00CF, 0x011E, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6,
+		0x00D7, 0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x0130, 0x015E, 0x00DF, 0x00E0, 0x00E1,
+		0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7, 0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC,
+		0x00ED, 0x00EE, 0x00EF, 0x011F, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+		0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x0131, 0x015F, 0x00FF,
+	],
+	// Part 10
+	[
+		0x00A0, 0x0104, 0x0112, 0x0122, 0x012A, 0x0128, 0x0136, 0x00A7, 0x013B, 0x0110, 0x0160,
+		0x0166, 0x017D, 0x00AD, 0x016A, 0x014A, 0x00B0, 0x0105, 0x0113, 0x0123, 0x012B, 0x0129,
+		0x0137, 0x00B7, 0x013C, 0x0111, 0x0161, 0x0167, 0x017E, 0x2015, 0x016B, 0x014B, 0x0100,
+		0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x012E, 0x010C, 0x00C9, 0x0118, 0x00CB,
+		0x0116, 0x00CD, 0x00CE, 0x00CF, 0x00D0, 0x0145, 0x014C, 0x00D3, 0x00D4, 0x00D5, 0x00D6,
+		0x0168, 0x00D8, 0x0172, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF, 0x0101, 0x00E1,
+		0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x012F, 0x010D, 0x00E9, 0x0119, 0x00EB, 0x0117,
+		0x00ED, 0x00EE, 0x00EF, 0x00F0, 0x0146, 0x014D, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x0169,
+		0x00F8, 0x0173, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x0138,
+	],
+];
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct IssuerCodeTableIndex {
+	pub index: u8,
+}
+
+impl IssuerCodeTableIndex {
+	/// The name of the ISO/IEC 8859 part the index refers to.
+	pub fn code_table_name(&self) -> &'static str {
+		CODE_TABLE_NAMES[usize::from(self.index) - 1]
+	}
+
+	/// Decodes text with the ISO/IEC 8859 part the index refers to.
+	///
+	/// Control characters and positions that aren't assigned in the part are
+	/// replaced with `U+FFFD`.
+	pub fn decode(&self, bytes: &[u8]) -> String {
+		let upper_half = &CODE_TABLE_UPPER_HALVES[usize::from(self.index) - 1];
+
+		bytes
+			.iter()
+			.map(|&byte| match byte {
+				0x20..=0x7E => char::from(byte),
+				UPPER_HALF_START..=0xFF => match upper_half[usize::from(byte - UPPER_HALF_START)] {
+					0x0000 => char::REPLACEMENT_CHARACTER,
+					code_point => {
+						char::from_u32(u32::from(code_point)).unwrap_or(char::REPLACEMENT_CHARACTER)
+					}
+				},
+				_ => char::REPLACEMENT_CHARACTER,
+			})
+			.collect()
+	}
+}
+
+impl TryFrom<&[u8]> for IssuerCodeTableIndex {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 1;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		// The index is 2 BCD digits, so padding isn't allowed either
+		let digits = bcd_to_decimal_string(raw_bytes).map_err(|_| ParseError::NonCompliant)?;
+		if digits.len() != NUM_BYTES * 2 {
+			return Err(ParseError::NonCompliant);
+		}
+		let index = digits.parse::<u8>().map_err(|_| ParseError::NonCompliant)?;
+		if !(1..=CODE_TABLE_NAMES.len()).contains(&usize::from(index)) {
+			return Err(ParseError::NonCompliant);
+		}
+
+		Ok(Self { index })
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for IssuerCodeTableIndex {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		write!(stdout, "Index ").ok();
+		stdout.set_color(&bold_colour_spec()).ok();
+		write!(stdout, "{}", self.index).ok();
+		stdout.reset().ok();
+		writeln!(
+			stdout,
+			" - ISO/IEC 8859-{} ({})",
+			self.index,
+			self.code_table_name()
+		)
+		.ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"index": self.index,
+			"code_table": format!("ISO/IEC 8859-{}", self.index),
+			"code_table_name": self.code_table_name(),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::IssuerCodeTableIndex;
+	use crate::{error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::IssuerCodeTableIndex, 1);
+
+	#[test]
+	fn parse_valid() {
+		let expected = Ok("Latin-2, Central European");
+		let result =
+			IssuerCodeTableIndex::try_from([0x02].as_slice()).map(|index| index.code_table_name());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_bcd_ten() {
+		let expected = Ok(IssuerCodeTableIndex { index: 10 });
+		let result = IssuerCodeTableIndex::try_from([0x10].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_zero() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = IssuerCodeTableIndex::try_from([0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_out_of_range() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = IssuerCodeTableIndex::try_from([0x11].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_not_bcd() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = IssuerCodeTableIndex::try_from([0x0A].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn decode_cyrillic() {
+		let expected = "\u{41A}\u{430}\u{440}\u{442}\u{430} 1".to_owned();
+		let result = IssuerCodeTableIndex { index: 5 }
+			.decode([0xBA, 0xD0, 0xE0, 0xE2, 0xD0, 0x20, 0x31].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn decode_unassigned_and_control() {
+		let expected = "A\u{FFFD}\u{FFFD}".to_owned();
+		let result = IssuerCodeTableIndex { index: 3 }.decode([0x41, 0xA5, 0x0A].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
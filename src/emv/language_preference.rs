@@ -0,0 +1,260 @@
+//! The language preference value, typically from EMV tag `0x5F2D`.
+//!
+//! Information for this can be found in EMV Book 1, under section `11.1`, and
+//! the language codes themselves come from [ISO 639-1](https://www.iso.org/standard/22109.html).
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{
+	emv::{Finding, Severity},
+	error::ParseError,
+	DisplayBreakdown,
+};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+
+// Constants
+const BYTES_PER_LANGUAGE: usize = 2;
+const MAX_LANGUAGES: usize = 4;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LanguagePreference {
+	/// The language codes, in order of preference.
+	pub languages:     Vec<String>,
+	/// A trailing byte that doesn't make up a complete language code, if there
+	/// is one.
+	pub trailing_byte: Option<u8>,
+}
+
+impl TryFrom<&[u8]> for LanguagePreference {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const MAX_BYTES: usize = BYTES_PER_LANGUAGE * MAX_LANGUAGES;
+
+		if raw_bytes.len() > MAX_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Less,
+				expected: MAX_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+		if !raw_bytes.iter().all(u8::is_ascii_alphabetic) {
+			return Err(ParseError::InvalidBytes);
+		}
+
+		let chunks = raw_bytes.chunks_exact(BYTES_PER_LANGUAGE);
+		let trailing_byte = chunks.remainder().first().copied();
+		let languages = chunks
+			.map(|chunk| {
+				chunk
+					.iter()
+					.map(|byte| byte.to_ascii_lowercase() as char)
+					.collect()
+			})
+			.collect();
+
+		Ok(Self {
+			languages,
+			trailing_byte,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for LanguagePreference {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let bold_colour_spec = bold_colour_spec();
+
+		for language in &self.languages {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&bold_colour_spec).ok();
+			write!(stdout, "{language}").ok();
+			stdout.reset().ok();
+			writeln!(
+				stdout,
+				" - {}",
+				language_name(language.as_str()).unwrap_or("<Unknown>")
+			)
+			.ok();
+		}
+
+		if let Some(trailing_byte) = self.trailing_byte {
+			print_indentation(stdout, indentation);
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Warning,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(
+				stdout,
+				"{}  - Trailing byte that isn't a complete language code",
+				trailing_byte as char
+			)
+			.ok();
+			stdout.reset().ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"languages": self
+				.languages
+				.iter()
+				.map(|language| {
+					json!({
+						"code": language,
+						"name": language_name(language.as_str()),
+					})
+				})
+				.collect::<Vec<_>>(),
+			"trailing_byte": self.trailing_byte.map(|byte| (byte as char).to_string()),
+		})
+	}
+
+	fn get_notable_bits(&self) -> Vec<Finding> {
+		self.trailing_byte
+			.map(|trailing_byte| Finding {
+				message:  format!(
+					"Trailing byte that isn't a complete language code: {}",
+					trailing_byte as char
+				),
+				severity: Severity::Warning,
+			})
+			.into_iter()
+			.collect()
+	}
+}
+
+/// Gets the English name of a lowercase ISO 639-1 language code.
+///
+/// Only the more common languages are included.
+#[cfg(not(tarpaulin_include))]
+fn language_name(code: &str) -> Option<&'static str> {
+	match code {
+		"ar" => Some("Arabic"),
+		"cs" => Some("Czech"),
+		"da" => Some("Danish"),
+		"de" => Some("German"),
+		"el" => Some("Greek"),
+		"en" => Some("English"),
+		"es" => Some("Spanish"),
+		"fi" => Some("Finnish"),
+		"fr" => Some("French"),
+		"he" => Some("Hebrew"),
+		"hi" => Some("Hindi"),
+		"hu" => Some("Hungarian"),
+		"id" => Some("Indonesian"),
+		"it" => Some("Italian"),
+		"ja" => Some("Japanese"),
+		"ko" => Some("Korean"),
+		"nl" => Some("Dutch"),
+		"no" => Some("Norwegian"),
+		"pl" => Some("Polish"),
+		"pt" => Some("Portuguese"),
+		"ro" => Some("Romanian"),
+		"ru" => Some("Russian"),
+		"sv" => Some("Swedish"),
+		"th" => Some("Thai"),
+		"tr" => Some("Turkish"),
+		"uk" => Some("Ukrainian"),
+		"vi" => Some("Vietnamese"),
+		"zh" => Some("Chinese"),
+		_ => None,
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::LanguagePreference;
+	use crate::{emv::Severity, error::ParseError, DisplayBreakdown};
+
+	// Tests
+	#[test]
+	fn parse_multiple_languages() {
+		let expected = Ok(LanguagePreference {
+			languages:     vec!["en".to_owned(), "fr".to_owned(), "de".to_owned()],
+			trailing_byte: None,
+		});
+		let result = LanguagePreference::try_from(b"enfrde".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_uppercase() {
+		let expected = Ok(LanguagePreference {
+			languages:     vec!["en".to_owned()],
+			trailing_byte: None,
+		});
+		let result = LanguagePreference::try_from(b"EN".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_odd_length() {
+		let expected = Ok(LanguagePreference {
+			languages:     vec!["en".to_owned(), "fr".to_owned()],
+			trailing_byte: Some(b'd'),
+		});
+		let result = LanguagePreference::try_from(b"enfrd".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_non_alphabetic() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = LanguagePreference::try_from(b"en1r".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_too_many_bytes() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Less,
+			expected: 8,
+			found:    10,
+		});
+		let result = LanguagePreference::try_from(b"enfrdeesit".as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn trailing_byte_is_notable() {
+		let expected = Ok(vec![Severity::Warning]);
+		let result = LanguagePreference::try_from(b"enf".as_slice()).map(|language_preference| {
+			language_preference
+				.get_notable_bits()
+				.into_iter()
+				.map(|notable_bit| notable_bit.severity)
+				.collect::<Vec<_>>()
+		});
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,105 @@
+//! The Log Entry, typically from EMV tag `0x9F4D`.
+//!
+//! It gives the SFI of the card's transaction log, and the maximum number of
+//! records the log can hold. The layout of each record is described by the Log
+//! Format (`0x9F4F`).
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`, and in
+//! EMV Book 4, under section `11.3`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{emv::ShortFileIdentifier, error::ParseError, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::header_colour_spec, util::print_indentation};
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LogEntry {
+	pub sfi:          ShortFileIdentifier,
+	/// The maximum number of records in the log.
+	pub record_count: u8,
+}
+
+impl TryFrom<&[u8]> for LogEntry {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 2;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Ok(Self {
+			sfi:          ShortFileIdentifier::try_from(raw_bytes[0])?,
+			record_count: raw_bytes[1],
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for LogEntry {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		let header_colour_spec = header_colour_spec();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "SFI:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {}", self.sfi.sfi).ok();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Maximum Records:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {}", self.record_count).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"sfi": self.sfi.sfi,
+			"record_count": self.record_count,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::LogEntry;
+	use crate::{emv::ShortFileIdentifier, error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::LogEntry, 2);
+
+	#[test]
+	fn parse_valid() {
+		let expected = Ok(LogEntry {
+			sfi:          ShortFileIdentifier { sfi: 11 },
+			record_count: 10,
+		});
+		let result = LogEntry::try_from([0x0B, 0x0A].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_invalid_sfi() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = LogEntry::try_from([0x1F, 0x0A].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,104 @@
+//! Everything for handling Mastercard Card Verification Results (CVR) values.
+//!
+//! Information for this can be found in the M/Chip 4 Card Application
+//! Specifications for Credit and Debit, under section `C.2`.
+
+// Uses
+use std::{cmp::Ordering, fmt::Debug};
+
+use crate::{
+	bitflag_value,
+	emv::ccd::{GenAc1ApplicationCryptogramType, GenAc2ApplicationCryptogramType},
+	error::ParseError,
+};
+
+// Struct Implementation
+bitflag_value! {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MastercardCardVerificationResults: 6 {
+	0 {
+		pub gen_ac_2_application_cryptogram_type: GenAc2ApplicationCryptogramType = (0b1100_0000 >> 6)
+			=> (Normal, "Application cryptogram type returned in 2nd GENERATE AC: {}"),
+		pub gen_ac_1_application_cryptogram_type: GenAc1ApplicationCryptogramType = (0b0011_0000 >> 4)
+			=> (Normal, "Application cryptogram type returned in 1st GENERATE AC: {}"),
+		pub offline_pin_verification_performed: bool =                               0b0000_0100
+			=> "Offline PIN verification performed",
+		pub offline_encrypted_pin_verification_performed: bool =                     0b0000_0010
+			=> "Offline encrypted PIN verification performed",
+		pub offline_pin_verification_successful: bool =                              0b0000_0001
+			=> "Offline PIN verification successful",
+	}
+	1 {
+		pub dda_returned: bool =                                                     0b1000_0000
+			=> "DDA (Dynamic Data Authentication) returned",
+		pub cda_returned_in_gen_ac_1: bool =                                         0b0100_0000
+			=> "Combined DDA/AC generation returned in 1st GENERATE AC",
+		pub cda_returned_in_gen_ac_2: bool =                                         0b0010_0000
+			=> "Combined DDA/AC generation returned in 2nd GENERATE AC",
+		pub issuer_authentication_performed: bool =                                  0b0001_0000
+			=> "Issuer authentication performed",
+		pub ciac_default_skipped_on_cat3: bool =                                     0b0000_1000
+			=> "CIAC-Default skipped on CAT3 (Cardholder-Activated Terminal Level 3)",
+	}
+	2 {
+		pub issuer_script_counter: u8 =                                             (0b1111_0000 >> 4)
+			=> (Normal, "Low-order nibble of the issuer script counter: {}"),
+		pub pin_try_counter: u8 =                                                    0b0000_1111
+			=> (Normal, "Low-order nibble of the PIN try counter: {}"),
+	}
+	3 {
+		pub last_online_transaction_not_completed: bool =                            0b1000_0000
+			=> (Warning, "Last online transaction not completed"),
+		pub unable_to_go_online: bool =                                              0b0100_0000
+			=> (Warning, "Unable to go online"),
+		pub offline_pin_verification_not_performed: bool =                           0b0010_0000
+			=> (Warning, "Offline PIN verification not performed"),
+		pub offline_pin_verification_failed: bool =                                  0b0001_0000
+			=> (Error, "Offline PIN verification failed"),
+		pub pin_try_limit_exceeded: bool =                                           0b0000_1000
+			=> (Error, "PIN try limit exceeded"),
+		pub international_transaction: bool =                                        0b0000_0100
+			=> "International transaction",
+		pub domestic_transaction: bool =                                             0b0000_0010
+			=> "Domestic transaction",
+		pub terminal_erroneously_considers_offline_pin_ok: bool =                    0b0000_0001
+			=> (Error, "Terminal erroneously considers offline PIN OK"),
+	}
+	4 {
+		pub lower_consecutive_offline_limit_exceeded: bool =                         0b1000_0000
+			=> (Warning, "Lower consecutive offline limit exceeded"),
+		pub upper_consecutive_offline_limit_exceeded: bool =                         0b0100_0000
+			=> (Warning, "Upper consecutive offline limit exceeded"),
+		pub lower_cumulative_offline_limit_exceeded: bool =                          0b0010_0000
+			=> (Warning, "Lower cumulative offline limit exceeded"),
+		pub upper_cumulative_offline_limit_exceeded: bool =                          0b0001_0000
+			=> (Warning, "Upper cumulative offline limit exceeded"),
+		pub go_online_on_next_transaction: bool =                                    0b0000_1000
+			=> (Warning, "Go online on next transaction was set"),
+		pub issuer_authentication_failed: bool =                                     0b0000_0100
+			=> (Error, "Issuer authentication failed"),
+		pub script_received: bool =                                                  0b0000_0010
+			=> "Script received",
+		pub script_failed: bool =                                                    0b0000_0001
+			=> (Error, "Script failed"),
+	}
+	5 {
+		pub match_found_in_additional_check_table: bool =                            0b0000_0010
+			=> "Match found in additional check table",
+		pub no_match_found_in_additional_check_table: bool =                         0b0000_0001
+			=> "No match found in additional check table",
+	}
+}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::MastercardCardVerificationResults, 6);
+	bitflag_unique_values!(super::MastercardCardVerificationResults, 6);
+	bitflag_display_bits!(super::MastercardCardVerificationResults, 6);
+}
@@ -0,0 +1,294 @@
+//! Everything for handling Mastercard Issuer Application Data (IAD).
+//!
+//! Information for this can be found in the M/Chip 4 Card Application
+//! Specifications for Credit and Debit, under section `C.3`. The M/Chip
+//! Advance layout is the same, with some extra issuer-discretionary bytes at
+//! the end.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::MastercardCardVerificationResults;
+use crate::{error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::header_colour_spec,
+	util::{print_bytes, print_indentation},
+};
+
+// Constants
+/// The number of bytes that are always present - the KDI, CVN, CVR, and the
+/// DAC/ICC Dynamic Number.
+const MANDATORY_BYTES: usize = 10;
+/// The number of bytes of counters that may follow the mandatory bytes.
+const COUNTERS_BYTES: usize = 8;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct MastercardIssuerApplicationData {
+	/// The Key Derivation Index (KDI).
+	pub kdi: u8,
+	/// The Cryptogram Version Number (CVN).
+	pub cryptogram_version_number: u8,
+	pub cvr: MastercardCardVerificationResults,
+	/// The Data Authentication Code (DAC) or ICC Dynamic Number, depending on
+	/// whether SDA or DDA/CDA was performed.
+	pub dac_or_icc_dynamic_number: u16,
+	/// The plaintext or encrypted counters, as configured by the issuer.
+	pub counters: Option<Vec<u8>>,
+	pub issuer_discretionary_data: Option<Vec<u8>>,
+}
+
+impl TryFrom<&[u8]> for MastercardIssuerApplicationData {
+	type Error = ParseError;
+
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		// The counters are either present in full or not at all
+		if bytes.len() < MANDATORY_BYTES
+			|| (bytes.len() > MANDATORY_BYTES && bytes.len() < MANDATORY_BYTES + COUNTERS_BYTES)
+		{
+			return Err(ParseError::Unrecognised);
+		}
+
+		// M/Chip 4 uses CVNs in the 0x1X range, and M/Chip Advance uses 0x2X
+		let cryptogram_version_number = bytes[1];
+		if !matches!(cryptogram_version_number >> 4, 0x1 | 0x2) {
+			return Err(ParseError::Unrecognised);
+		}
+
+		let kdi = bytes[0];
+		let cvr = MastercardCardVerificationResults::try_from(&bytes[2..8])?;
+		let dac_or_icc_dynamic_number = u16::from_be_bytes([bytes[8], bytes[9]]);
+		let counters = bytes
+			.get(MANDATORY_BYTES..MANDATORY_BYTES + COUNTERS_BYTES)
+			.map(<[u8]>::to_vec);
+		let issuer_discretionary_data = bytes
+			.get(MANDATORY_BYTES + COUNTERS_BYTES..)
+			.filter(|data| !data.is_empty())
+			.map(<[u8]>::to_vec);
+
+		Ok(Self {
+			kdi,
+			cryptogram_version_number,
+			cvr,
+			dac_or_icc_dynamic_number,
+			counters,
+			issuer_discretionary_data,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for MastercardIssuerApplicationData {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let header_colour_spec = header_colour_spec();
+
+		// Print the KDI
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Key Derivation Index:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {:#04X}", self.kdi).ok();
+
+		// Print the CVN
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Cryptogram Version Number:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {:#04X}", self.cryptogram_version_number).ok();
+
+		// Print the CVR
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(stdout, "Card Verification Results:").ok();
+		stdout.reset().ok();
+		self.cvr
+			.display_breakdown(stdout, indentation + 1, show_severity_colours);
+
+		// Print the DAC/IDN
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "DAC/ICC Dynamic Number:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {:04X}", self.dac_or_icc_dynamic_number).ok();
+
+		// Print the counters
+		if let Some(counters) = &self.counters {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Counters:").ok();
+			stdout.reset().ok();
+			print_bytes(stdout, counters.as_slice(), 16, indentation + 1);
+		}
+
+		// Print the issuer-discretionary data
+		if let Some(issuer_discretionary_data) = &self.issuer_discretionary_data {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Issuer-Discretionary Data").ok();
+			stdout.reset().ok();
+			print_bytes(
+				stdout,
+				issuer_discretionary_data.as_slice(),
+				16,
+				indentation + 1,
+			);
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"kdi": self.kdi,
+			"cryptogram_version_number": self.cryptogram_version_number,
+			"cvr": self.cvr.to_json_value(),
+			"dac_or_icc_dynamic_number": format!("{:04X}", self.dac_or_icc_dynamic_number),
+			"counters": self
+				.counters
+				.as_ref()
+				.map(|data| bytes_to_str(data.as_slice())),
+			"issuer_discretionary_data": self
+				.issuer_discretionary_data
+				.as_ref()
+				.map(|data| bytes_to_str(data.as_slice())),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{super::MastercardCardVerificationResults, MastercardIssuerApplicationData};
+	use crate::{
+		emv::ccd::{GenAc1ApplicationCryptogramType, GenAc2ApplicationCryptogramType},
+		error::ParseError,
+	};
+
+	// Tests
+	fn arqc_cvr() -> MastercardCardVerificationResults {
+		MastercardCardVerificationResults {
+			gen_ac_2_application_cryptogram_type:
+				GenAc2ApplicationCryptogramType::SecondGenAcNotRequested,
+			gen_ac_1_application_cryptogram_type: GenAc1ApplicationCryptogramType::Arqc,
+			offline_pin_verification_performed: false,
+			offline_encrypted_pin_verification_performed: false,
+			offline_pin_verification_successful: false,
+			dda_returned: false,
+			cda_returned_in_gen_ac_1: false,
+			cda_returned_in_gen_ac_2: false,
+			issuer_authentication_performed: false,
+			ciac_default_skipped_on_cat3: false,
+			issuer_script_counter: 0,
+			pin_try_counter: 3,
+			last_online_transaction_not_completed: false,
+			unable_to_go_online: false,
+			offline_pin_verification_not_performed: true,
+			offline_pin_verification_failed: false,
+			pin_try_limit_exceeded: false,
+			international_transaction: false,
+			domestic_transaction: true,
+			terminal_erroneously_considers_offline_pin_ok: false,
+			lower_consecutive_offline_limit_exceeded: false,
+			upper_consecutive_offline_limit_exceeded: false,
+			lower_cumulative_offline_limit_exceeded: false,
+			upper_cumulative_offline_limit_exceeded: false,
+			go_online_on_next_transaction: false,
+			issuer_authentication_failed: false,
+			script_received: false,
+			script_failed: false,
+			match_found_in_additional_check_table: false,
+			no_match_found_in_additional_check_table: false,
+		}
+	}
+
+	#[test]
+	fn without_counters() {
+		let expected = Ok(MastercardIssuerApplicationData {
+			kdi: 0x01,
+			cryptogram_version_number: 0x10,
+			cvr: arqc_cvr(),
+			dac_or_icc_dynamic_number: 0x1234,
+			counters: None,
+			issuer_discretionary_data: None,
+		});
+		let result = MastercardIssuerApplicationData::try_from(
+			[0x01, 0x10, 0xA0, 0x00, 0x03, 0x22, 0x00, 0x00, 0x12, 0x34].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn with_counters() {
+		let expected = Ok(MastercardIssuerApplicationData {
+			kdi: 0x01,
+			cryptogram_version_number: 0x10,
+			cvr: arqc_cvr(),
+			dac_or_icc_dynamic_number: 0x1234,
+			counters: Some(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00]),
+			issuer_discretionary_data: None,
+		});
+		let result = MastercardIssuerApplicationData::try_from(
+			[
+				0x01, 0x10, 0xA0, 0x00, 0x03, 0x22, 0x00, 0x00, 0x12, 0x34, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0xFF, 0x00,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn with_issuer_discretionary_data() {
+		let expected = Ok(MastercardIssuerApplicationData {
+			kdi: 0x01,
+			cryptogram_version_number: 0x20,
+			cvr: arqc_cvr(),
+			dac_or_icc_dynamic_number: 0x1234,
+			counters: Some(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00]),
+			issuer_discretionary_data: Some(vec![0x00, 0x05]),
+		});
+		let result = MastercardIssuerApplicationData::try_from(
+			[
+				0x01, 0x20, 0xA0, 0x00, 0x03, 0x22, 0x00, 0x00, 0x12, 0x34, 0x00, 0x00, 0x00, 0x00,
+				0x00, 0x00, 0xFF, 0x00, 0x00, 0x05,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn partial_counters() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = MastercardIssuerApplicationData::try_from(
+			[
+				0x01, 0x10, 0xA0, 0x00, 0x03, 0x22, 0x00, 0x00, 0x12, 0x34, 0x00, 0x00,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn not_mastercard_layout() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = MastercardIssuerApplicationData::try_from(
+			[0x06, 0x01, 0x0A, 0x03, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,13 @@
+//! Mastercard-specific values. Mastercard cards don't follow the EMV Common
+//! Core Definitions (CCD), so their proprietary layouts are handled here.
+//!
+//! Information for this can be found in the M/Chip 4 Card Application
+//! Specifications for Credit and Debit, and the M/Chip Advance Card Application
+//! Specification.
+
+// Modules
+mod cvr;
+mod iad;
+
+// Public Exports
+pub use self::{cvr::*, iad::*};
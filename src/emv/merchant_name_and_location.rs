@@ -0,0 +1,124 @@
+//! The Merchant Name and Location, typically from EMV tag `0x9F4E`.
+//!
+//! It's free-form text, which is often printed on receipts.
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+use crate::{error::ParseError, util::bytes_to_printable_ascii, DisplayBreakdown};
+
+// Constants
+const PADDING_CHAR: char = ' ';
+const NON_PRINTABLE_REPLACEMENT_CHAR: char = '.';
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct MerchantNameAndLocation {
+	/// The text, with padding removed and any non-printable bytes replaced.
+	pub text:                  String,
+	/// The offsets of any bytes that aren't printable ASCII.
+	pub non_printable_offsets: Vec<usize>,
+}
+
+impl TryFrom<&[u8]> for MerchantNameAndLocation {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		let (text, non_printable_offsets) =
+			bytes_to_printable_ascii(raw_bytes, NON_PRINTABLE_REPLACEMENT_CHAR);
+
+		Ok(Self {
+			text: text.trim_matches(PADDING_CHAR).to_owned(),
+			non_printable_offsets,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for MerchantNameAndLocation {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "{}", self.text).ok();
+		stdout.reset().ok();
+
+		if !self.non_printable_offsets.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Warning,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(
+				stdout,
+				"Contains non-printable bytes at offset{} {}",
+				if self.non_printable_offsets.len() == 1 {
+					""
+				} else {
+					"s"
+				},
+				self.non_printable_offsets
+					.iter()
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+					.join(", ")
+			)
+			.ok();
+			stdout.reset().ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"text": self.text,
+			"non_printable_offsets": self.non_printable_offsets,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::MerchantNameAndLocation;
+
+	// Tests
+	#[test]
+	fn parse_padded() {
+		let expected = Ok(MerchantNameAndLocation {
+			text:                  "CORNER SHOP, TORONTO".to_owned(),
+			non_printable_offsets: vec![],
+		});
+		let result = MerchantNameAndLocation::try_from(b"CORNER SHOP, TORONTO    ".as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_control_character() {
+		let expected = Ok(MerchantNameAndLocation {
+			text:                  "CORNER SHOP.TORONTO".to_owned(),
+			non_printable_offsets: vec![11],
+		});
+		let result = MerchantNameAndLocation::try_from(b"CORNER SHOP\nTORONTO".as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -1,38 +1,76 @@
 //! All EMV-related parsers.
 
 // Modules
+mod account_type;
 mod additional_terminal_capabilities;
+mod application_cryptogram;
+mod application_identifier;
+mod application_version_number;
 mod authorisation_response_code;
 mod bitflag_values;
+mod cardholder_name;
 pub mod ccd;
+mod ctq;
 mod cv_rule;
 mod cvm_list;
 mod cvm_results;
+mod data_object_list;
 mod iac;
+mod issuer_code_table_index;
+mod language_preference;
+mod log_entry;
+pub mod mastercard;
+mod merchant_name_and_location;
 mod pos_entry_mode;
+mod short_file_identifier;
 mod tag_basic_info;
+mod tag_list;
+mod tag_reference;
 mod terminal_capabilities;
+mod terminal_floor_limit;
+mod terminal_risk_management_data;
 mod terminal_type;
 mod tlv_parsing;
+mod track_2_equivalent_data;
 mod transaction_type;
 mod tsi;
+mod ttq;
 mod tvr;
+pub mod visa;
 
 // Public Exports
 pub use self::{
+	account_type::*,
 	additional_terminal_capabilities::*,
+	application_cryptogram::*,
+	application_identifier::*,
+	application_version_number::*,
 	authorisation_response_code::*,
 	bitflag_values::*,
+	cardholder_name::*,
+	ctq::*,
 	cv_rule::*,
 	cvm_list::*,
 	cvm_results::*,
+	data_object_list::*,
 	iac::*,
+	issuer_code_table_index::*,
+	language_preference::*,
+	log_entry::*,
+	merchant_name_and_location::*,
 	pos_entry_mode::*,
+	short_file_identifier::*,
 	tag_basic_info::*,
+	tag_list::*,
+	tag_reference::*,
 	terminal_capabilities::*,
+	terminal_floor_limit::*,
+	terminal_risk_management_data::*,
 	terminal_type::*,
 	tlv_parsing::*,
+	track_2_equivalent_data::*,
 	transaction_type::*,
 	tsi::*,
+	ttq::*,
 	tvr::*,
 };
@@ -3,15 +3,23 @@
 //! The possible values come from the first two digits of the POS Entry Mode in
 //! the ISO 8583:1987 specification.
 //!
+//! Some systems provide the full ISO 8583 field, which is 2 bytes long. The
+//! third digit is the PIN entry capability of the terminal, and the last digit
+//! is padding.
+//!
 //! This could be incomplete - it's difficult to find a complete list of values
 //! online.
 
 // Uses
 use std::cmp::Ordering;
 
-use termcolor::StandardStream;
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
-use crate::{enum_no_repr_fallible, error::ParseError, util::print_indentation, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::util::print_indentation;
+use crate::{enum_no_repr_fallible, enum_repr_fallible, error::ParseError, DisplayBreakdown};
 
 // Enum Implementation
 enum_no_repr_fallible! {
@@ -37,6 +45,51 @@ pub enum PosEntryMode: u8, ParseError, { |_| ParseError::Unrecognised } {
 }
 }
 
+enum_repr_fallible! {
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PinEntryCapability: u8, ParseError, { |_| ParseError::Unrecognised } {
+	Unspecified       = 0x0 => "Unspecified",
+	CanAcceptPin      = 0x1 => "Can accept PIN",
+	CannotAcceptPin   = 0x2 => "Cannot accept PIN",
+	PinPadInoperative = 0x8 => "PIN pad inoperative",
+}
+}
+
+/// The POS entry mode, along with the PIN entry capability if the full 2-byte
+/// field was provided.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct PosEntryModeField {
+	pub entry_mode:           PosEntryMode,
+	pub pin_entry_capability: Option<PinEntryCapability>,
+}
+
+impl TryFrom<&[u8]> for PosEntryModeField {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, ParseError> {
+		const MAX_BYTES: usize = 2;
+
+		match raw_bytes {
+			[entry_mode] => Ok(Self {
+				entry_mode:           PosEntryMode::try_from(*entry_mode)?,
+				pin_entry_capability: None,
+			}),
+			[entry_mode, pin_entry_capability] => Ok(Self {
+				entry_mode:           PosEntryMode::try_from(*entry_mode)?,
+				pin_entry_capability: Some(PinEntryCapability::try_from(
+					pin_entry_capability >> 4,
+				)?),
+			}),
+			_ => Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Less,
+				expected: MAX_BYTES,
+				found:    raw_bytes.len(),
+			}),
+		}
+	}
+}
+
 impl TryFrom<&[u8]> for PosEntryMode {
 	type Error = ParseError;
 
@@ -57,9 +110,39 @@ impl TryFrom<&[u8]> for PosEntryMode {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for PosEntryMode {
-	fn display_breakdown(&self, _: &mut StandardStream, indentation: u8, _: bool) {
-		print_indentation(indentation);
-		println!("{self}");
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		writeln!(stdout, "{self}").ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::String(self.to_string())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for PosEntryModeField {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		self.entry_mode
+			.display_breakdown(stdout, indentation, show_severity_colours);
+		if let Some(pin_entry_capability) = self.pin_entry_capability {
+			print_indentation(stdout, indentation);
+			writeln!(stdout, "PIN Entry Capability: {pin_entry_capability}").ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"entry_mode": self.entry_mode.to_string(),
+			"pin_entry_capability": self.pin_entry_capability.map(|capability| capability.to_string()),
+		})
 	}
 }
 
@@ -67,7 +150,14 @@ impl DisplayBreakdown for PosEntryMode {
 #[cfg(test)]
 mod tests {
 	// Uses
-	use crate::{enum_byte_slice_result_matches_true_value_result, wrong_byte_count};
+	use std::cmp::Ordering;
+
+	use super::{PinEntryCapability, PosEntryMode, PosEntryModeField};
+	use crate::{
+		enum_byte_slice_result_matches_true_value_result,
+		error::ParseError,
+		wrong_byte_count,
+	};
 
 	// Tests
 	wrong_byte_count!(super::PosEntryMode, 1);
@@ -77,4 +167,43 @@ mod tests {
 		0x80,
 		[0x80].as_slice()
 	);
+
+	#[test]
+	fn parse_field_single_byte() {
+		let expected = Ok(PosEntryModeField {
+			entry_mode:           PosEntryMode::ContactIcc,
+			pin_entry_capability: None,
+		});
+		let result = PosEntryModeField::try_from([0x05].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_field_with_pin_entry_capability() {
+		let expected = Ok(PosEntryModeField {
+			entry_mode:           PosEntryMode::ContactlessIcc,
+			pin_entry_capability: Some(PinEntryCapability::CanAcceptPin),
+		});
+		let result = PosEntryModeField::try_from([0x07, 0x10].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_field_unrecognised_pin_entry_capability() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = PosEntryModeField::try_from([0x05, 0x50].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_field_too_long() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Less,
+			expected: 2,
+			found:    3,
+		});
+		let result = PosEntryModeField::try_from([0x05, 0x10, 0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
 }
@@ -0,0 +1,140 @@
+//! The Short File Identifier (SFI), typically from EMV tag `0x88`.
+//!
+//! SFIs are also referenced by the Application File Locator (AFL) and by the
+//! `P2` parameter of the `READ RECORD` command, where the identifier is stored
+//! in the high 5 bits of the byte instead.
+//!
+//! Information for this can be found in EMV Book 1, under section `10.2`, and
+//! in EMV Book 3, under sections `6.5.11` and `10.2`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
+
+// Constants
+const MIN_SFI: u8 = 1;
+const MAX_SFI: u8 = 30;
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ShortFileIdentifier {
+	pub sfi: u8,
+}
+
+impl ShortFileIdentifier {
+	/// Extracts the SFI from a byte that references it in the high 5 bits, like
+	/// the first byte of each AFL entry or the `P2` parameter of `READ RECORD`.
+	///
+	/// The low 3 bits are ignored, since their meaning depends on the context.
+	pub fn from_reference_byte(byte: u8) -> Result<Self, ParseError> {
+		Self::try_from(byte >> 3)
+	}
+}
+
+impl TryFrom<u8> for ShortFileIdentifier {
+	type Error = ParseError;
+
+	/// Validates a plain SFI value. `0` and `31` are reserved, and anything
+	/// larger can't be an SFI at all.
+	fn try_from(sfi: u8) -> Result<Self, Self::Error> {
+		if !(MIN_SFI..=MAX_SFI).contains(&sfi) {
+			return Err(ParseError::NonCompliant);
+		}
+
+		Ok(Self { sfi })
+	}
+}
+
+impl TryFrom<&[u8]> for ShortFileIdentifier {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 1;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		// The tag value is the SFI itself, with the high 3 bits set to zero
+		Self::try_from(raw_bytes[0])
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ShortFileIdentifier {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		write!(stdout, "SFI ").ok();
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "{}", self.sfi).ok();
+		stdout.reset().ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"sfi": self.sfi,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::ShortFileIdentifier;
+	use crate::{error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::ShortFileIdentifier, 1);
+
+	#[test]
+	fn parse_valid() {
+		let expected = Ok(ShortFileIdentifier { sfi: 11 });
+		let result = ShortFileIdentifier::try_from([0x0B].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_zero() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = ShortFileIdentifier::try_from([0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_reserved() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = ShortFileIdentifier::try_from([0x1F].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	/// Tag `0x88` holds the SFI in the low 5 bits, so a value in the reference
+	/// format (with the SFI in the high 5 bits) is out of range.
+	#[test]
+	fn parse_reference_format() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = ShortFileIdentifier::try_from([0b0101_1000].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn from_reference_byte() {
+		let expected = Ok(ShortFileIdentifier { sfi: 2 });
+		let result = ShortFileIdentifier::from_reference_byte(0b0001_0100);
+
+		assert_eq!(expected, result);
+	}
+}
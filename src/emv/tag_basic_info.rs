@@ -1,31 +1,56 @@
-//! The terminal type value, typically from EMV tag `0x9F35`.
+//! Basic information about an EMV tag, based on the tag alone and without any
+//! data.
 //!
-//! Information for this can be found in EMV Book 4, under section `A1`.
+//! Information for this can be found in EMV Book 3, under `Annex B`.
 
 // Uses
 use std::cmp::Ordering;
 
-use termcolor::{StandardStream, WriteColor};
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
 use crate::{
-	emv::{ber_tlv::parse_tag_metadata, identify_tag, DataObjectType, TagClass},
+	emv::{
+		ber_tlv::parse_tag_metadata,
+		find_tag_definition,
+		has_dedicated_parser,
+		DataFormat,
+		DataObjectType,
+		TagClass,
+		TagLength,
+	},
 	error::ParseError,
-	header_colour_spec,
-	output_colours::bold_colour_spec,
-	util::{print_bytes_small, print_indentation},
+	util::bytes_to_str,
 	DisplayBreakdown,
 };
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{bold_colour_spec, header_colour_spec},
+	util::{print_bytes_small, print_indentation},
+};
 
 // Constants
 const MIN_BYTES: usize = 1;
 
 // Struct Implementation
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct TagBasicInfo {
-	pub tag:              Vec<u8>,
-	pub name:             Option<&'static str>,
-	pub class:            TagClass,
-	pub data_object_type: DataObjectType,
+	pub tag:                  Vec<u8>,
+	pub name:                 Option<&'static str>,
+	pub class:                TagClass,
+	pub data_object_type:     DataObjectType,
+	/// Whether the value of the tag can be broken down, instead of only being
+	/// identified by name.
+	pub has_dedicated_parser: bool,
+	/// The format of the tag's value, if it's a known tag.
+	pub format:               Option<DataFormat>,
+	/// The length of the tag's value, if it's a known tag.
+	pub length:               Option<TagLength>,
+	/// The specification and section the tag is documented in, if it's a
+	/// known EMV tag.
+	pub source:               Option<&'static str>,
 }
 
 impl TryFrom<&[u8]> for TagBasicInfo {
@@ -40,52 +65,203 @@ impl TryFrom<&[u8]> for TagBasicInfo {
 			});
 		}
 
-		let name = identify_tag(tag);
+		// Multi-byte tags have to be well-formed, with every byte but the last
+		// indicating that another byte follows
+		let expected_len = if 0b0001_1111 & tag[0] == 0b0001_1111 {
+			1 + tag[1..]
+				.iter()
+				.position(|byte| 0b1000_0000 & byte == 0)
+				.ok_or(ParseError::NonCompliant)?
+				+ 1
+		} else {
+			1
+		};
+		if tag.len() != expected_len {
+			return Err(ParseError::NonCompliant);
+		}
+
+		let definition = find_tag_definition(tag);
 		let (class, data_object_type) = parse_tag_metadata(tag[0]);
 
 		Ok(Self {
 			tag: tag.to_vec(),
-			name,
+			name: definition.map(|definition| definition.name),
 			class,
 			data_object_type,
+			has_dedicated_parser: has_dedicated_parser(tag),
+			format: definition.map(|definition| definition.format),
+			length: definition.map(|definition| definition.length),
+			source: definition.and_then(|definition| definition.source),
 		})
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for TagBasicInfo {
-	fn display_breakdown(&self, stdout: &mut StandardStream, indentation: u8, _: bool) {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
 		let bold_colour_spec = bold_colour_spec();
 		let header_colour_spec = header_colour_spec();
 
-		let name = self.name.unwrap_or("<Unknown>");
-
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		print!("Name:");
+		write!(stdout, "Name:").ok();
 		stdout.reset().ok();
-		println!("             {name}");
+		match self.name {
+			Some(name) => writeln!(stdout, "             {name}").ok(),
+			None => writeln!(
+				stdout,
+				"             <Unknown> - this tag isn't in the list of known EMV tags"
+			)
+			.ok(),
+		};
 
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		print!("Tag:");
+		write!(stdout, "Tag:").ok();
 		stdout.reset().ok();
-		print!("              0x");
+		write!(stdout, "              0x").ok();
 		stdout.set_color(&bold_colour_spec).ok();
-		print_bytes_small(self.tag.as_slice());
+		print_bytes_small(stdout, self.tag.as_slice());
 		stdout.reset().ok();
-		println!();
+		writeln!(stdout).ok();
 
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		print!("Class:");
+		write!(stdout, "Class:").ok();
 		stdout.reset().ok();
-		println!("            {}", self.class);
+		writeln!(stdout, "            {}", self.class).ok();
 
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&header_colour_spec).ok();
-		print!("Data Object Type:");
+		write!(stdout, "Data Object Type:").ok();
 		stdout.reset().ok();
-		println!(" {}", self.data_object_type);
+		writeln!(stdout, " {}", self.data_object_type).ok();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Dedicated Parser:").ok();
+		stdout.reset().ok();
+		writeln!(
+			stdout,
+			" {}",
+			if self.has_dedicated_parser {
+				"Yes"
+			} else {
+				"No"
+			}
+		)
+		.ok();
+
+		if let Some(format) = self.format {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "Format:").ok();
+			stdout.reset().ok();
+			writeln!(stdout, "           {format}").ok();
+		}
+
+		if let Some(length) = self.length {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "Length:").ok();
+			stdout.reset().ok();
+			writeln!(stdout, "           {length}").ok();
+		}
+
+		if let Some(source) = self.source {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "Reference:").ok();
+			stdout.reset().ok();
+			writeln!(stdout, "        {source}").ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"tag": bytes_to_str(self.tag.as_slice()),
+			"name": self.name,
+			"class": self.class.to_string(),
+			"data_object_type": self.data_object_type.to_string(),
+			"has_dedicated_parser": self.has_dedicated_parser,
+			"format": self.format.map(|format| format.to_string()),
+			"length": self.length.map(|length| length.to_string()),
+			"reference": self.source,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::TagBasicInfo;
+	use crate::{
+		emv::{DataFormat, DataObjectType, TagClass, TagLength},
+		error::ParseError,
+	};
+
+	// Tests
+	#[test]
+	fn known_tag_with_parser() {
+		let expected = Ok(TagBasicInfo {
+			tag:                  vec![0x95],
+			name:                 Some("Terminal Verification Results (TVR)"),
+			class:                TagClass::ContextSpecific,
+			data_object_type:     DataObjectType::Primitive,
+			has_dedicated_parser: true,
+			format:               Some(DataFormat::Binary),
+			length:               Some(TagLength::Exactly(5)),
+			source:               Some("EMV Book 3, Annex A1"),
+		});
+		let result = TagBasicInfo::try_from([0x95].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn known_multi_byte_tag_without_parser() {
+		let expected = Ok(TagBasicInfo {
+			tag:                  vec![0x9F, 0x02],
+			name:                 Some("Amount, Authorised (Numeric)"),
+			class:                TagClass::ContextSpecific,
+			data_object_type:     DataObjectType::Primitive,
+			has_dedicated_parser: false,
+			format:               Some(DataFormat::Numeric),
+			length:               Some(TagLength::Exactly(6)),
+			source:               Some("EMV Book 3, Annex A1"),
+		});
+		let result = TagBasicInfo::try_from([0x9F, 0x02].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn unknown_tag() {
+		let expected = Ok(None);
+		let result = TagBasicInfo::try_from([0xDF, 0x81, 0x01].as_slice()).map(|info| info.name);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn known_tag_reference() {
+		let expected = Ok(Some((DataFormat::Numeric, TagLength::Exactly(6))));
+		let result = TagBasicInfo::try_from([0x9F, 0x02].as_slice())
+			.map(|info| info.format.zip(info.length));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn incomplete_multi_byte_tag() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = TagBasicInfo::try_from([0x9F, 0x81].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn single_byte_tag_with_extra_bytes() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = TagBasicInfo::try_from([0x95, 0x01].as_slice());
+
+		assert_eq!(expected, result);
 	}
 }
@@ -0,0 +1,135 @@
+//! A browsable list of the tags that can be identified, along with whether
+//! each one has a dedicated parser.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::{all_tag_definitions, ParserKind, TagDefinition};
+#[cfg(feature = "display")]
+use crate::{output_colours::header_colour_spec, util::print_indentation};
+use crate::{util::bytes_to_str, DisplayBreakdown};
+
+// Constants
+/// The width of the tag column, which fits the longest known tags.
+#[cfg(feature = "display")]
+const TAG_COLUMN_WIDTH: usize = 8;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TagList {
+	pub tags: Vec<TagDefinition>,
+}
+
+impl TagList {
+	/// Lists every tag that can be identified, including any custom tags.
+	pub fn all() -> Self {
+		Self {
+			tags: all_tag_definitions(),
+		}
+	}
+
+	/// Lists the tags with names that contain the query, ignoring case.
+	pub fn search(query: &str) -> Self {
+		let query = query.trim().to_lowercase();
+
+		Self {
+			tags: all_tag_definitions()
+				.into_iter()
+				.filter(|definition| definition.name.to_lowercase().contains(query.as_str()))
+				.collect(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for TagList {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		let header_colour_spec = header_colour_spec();
+
+		if self.tags.is_empty() {
+			print_indentation(stdout, indentation);
+			writeln!(stdout, "No matching tags").ok();
+			return;
+		}
+
+		for TagDefinition {
+			tag, name, parser, ..
+		} in &self.tags
+		{
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "{:<TAG_COLUMN_WIDTH$}", bytes_to_str(tag)).ok();
+			stdout.reset().ok();
+			if *parser == ParserKind::None {
+				writeln!(stdout, "{name} (annotated only)").ok();
+			} else {
+				writeln!(stdout, "{name} (parsed)").ok();
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::Array(
+			self.tags
+				.iter()
+				.map(
+					|TagDefinition {
+					     tag, name, parser, ..
+					 }| {
+						json!({
+							"tag": bytes_to_str(tag),
+							"name": name,
+							"has_dedicated_parser": *parser != ParserKind::None,
+						})
+					},
+				)
+				.collect(),
+		)
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::TagList;
+	use crate::emv::{ParserKind, KNOWN_TAGS};
+
+	// Tests
+	#[test]
+	fn all_includes_known_tags() {
+		let result = TagList::all();
+
+		assert!(result.tags.len() >= KNOWN_TAGS.len());
+		assert!(result.tags.iter().any(|definition| {
+			definition.tag == [0x9F, 0x66]
+				&& definition.name == "Terminal Transaction Qualifiers (TTQ)"
+				&& definition.parser == ParserKind::TerminalTransactionQualifiers
+		}));
+		assert!(result.tags.iter().any(|definition| {
+			definition.tag == [0x50]
+				&& definition.name == "Application Label"
+				&& definition.parser == ParserKind::None
+		}));
+	}
+	#[test]
+	fn search_ignores_case() {
+		let expected = vec![[0x9F, 0x26].as_slice(), [0x9F, 0x27].as_slice()];
+		let result = TagList::search("CRYPTOGRAM")
+			.tags
+			.into_iter()
+			.map(|definition| definition.tag)
+			.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn search_no_matches() {
+		let result = TagList::search("not a tag name");
+
+		assert!(result.tags.is_empty());
+	}
+}
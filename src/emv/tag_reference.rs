@@ -0,0 +1,72 @@
+//! Reference information about the values of known EMV tags, like their format
+//! and length.
+//!
+//! Information for this can be found in EMV Book 3, under `Annex A1`, and in
+//! EMV Contactless Book C-3, under `Annex A`.
+
+// Uses
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+// Struct Implementation
+/// The format of a tag's value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum DataFormat {
+	/// `b`
+	Binary,
+	/// `n`, with one digit per nibble, padded with leading zeroes.
+	Numeric,
+	/// `cn`, with one digit per nibble, padded with trailing `F` nibbles.
+	CompressedNumeric,
+	/// `a`
+	Alphabetic,
+	/// `an`
+	Alphanumeric,
+	/// `ans`
+	AlphanumericSpecial,
+	/// A constructed tag that contains other tags.
+	Template,
+	/// The format isn't fixed by the specification.
+	Variable,
+}
+
+/// The length of a tag's value, in bytes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum TagLength {
+	Exactly(u16),
+	Between(u16, u16),
+	UpTo(u16),
+	/// The length isn't limited by the specification, or depends on other
+	/// values like the length of a key.
+	Variable,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for DataFormat {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str(match self {
+			Self::Binary => "Binary (b)",
+			Self::Numeric => "Numeric (n)",
+			Self::CompressedNumeric => "Compressed Numeric (cn)",
+			Self::Alphabetic => "Alphabetic (a)",
+			Self::Alphanumeric => "Alphanumeric (an)",
+			Self::AlphanumericSpecial => "Alphanumeric Special (ans)",
+			Self::Template => "Template",
+			Self::Variable => "Variable",
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for TagLength {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::Exactly(1) => write!(f, "1 byte"),
+			Self::Exactly(length) => write!(f, "{length} bytes"),
+			Self::Between(min, max) => write!(f, "{min} to {max} bytes"),
+			Self::UpTo(max) => write!(f, "Up to {max} bytes"),
+			Self::Variable => write!(f, "Variable"),
+		}
+	}
+}
@@ -30,7 +30,7 @@ pub struct TerminalCapabilities: 3 {
 		pub cvm_no_cvm_required: bool =                         0b0000_1000
 			=> "No CVM Required",
 	}
-	// Security Capabilities
+	// Security Capabilities (bits 5 and 3-1 are RFU)
 	2 {
 		pub security_sda: bool =          0b1000_0000 => "SDA (Static Data Authentication)",
 		pub security_dda: bool =          0b0100_0000 => "DDA (Dynamic Data Authentication)",
@@ -44,10 +44,32 @@ pub struct TerminalCapabilities: 3 {
 #[cfg(test)]
 mod tests {
 	// Uses
+	use super::TerminalCapabilities;
 	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
 
 	// Tests
 	wrong_byte_count!(super::TerminalCapabilities, 3);
 	bitflag_unique_values!(super::TerminalCapabilities, 3);
 	bitflag_display_bits!(super::TerminalCapabilities, 3);
+
+	#[test]
+	fn parse_security_capabilities() {
+		let result = TerminalCapabilities::try_from([0x00, 0x00, 0b1110_1000].as_slice())
+			.expect("the testing value should be able to be parsed without error");
+
+		assert!(result.security_sda);
+		assert!(result.security_dda);
+		assert!(result.security_card_capture);
+		assert!(result.security_cda);
+	}
+	#[test]
+	fn parse_security_capabilities_rfu_ignored() {
+		let result = TerminalCapabilities::try_from([0x00, 0x00, 0b0001_0111].as_slice())
+			.expect("the testing value should be able to be parsed without error");
+
+		assert!(!result.security_sda);
+		assert!(!result.security_dda);
+		assert!(!result.security_card_capture);
+		assert!(!result.security_cda);
+	}
 }
@@ -0,0 +1,102 @@
+//! The Terminal Floor Limit, typically from EMV tag `0x9F1B`.
+//!
+//! It's the amount above which a transaction has to be authorised online,
+//! given as a binary integer in the minor unit of the transaction currency.
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, util::byte_slice_to_u32, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct TerminalFloorLimit {
+	/// The limit, in the minor unit of the transaction currency.
+	pub limit: u32,
+}
+
+impl TryFrom<&[u8]> for TerminalFloorLimit {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 4;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Ok(Self {
+			limit: byte_slice_to_u32(raw_bytes)?,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for TerminalFloorLimit {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		write!(stdout, "{}", self.limit).ok();
+		stdout.reset().ok();
+		writeln!(stdout, " (minor units)").ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"limit": self.limit,
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::TerminalFloorLimit;
+	use crate::{error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::TerminalFloorLimit, 4);
+
+	#[test]
+	fn parse_zero() {
+		let expected = Ok(TerminalFloorLimit { limit: 0 });
+		let result = TerminalFloorLimit::try_from([0x00, 0x00, 0x00, 0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_typical() {
+		let expected = Ok(TerminalFloorLimit { limit: 5000 });
+		let result = TerminalFloorLimit::try_from([0x00, 0x00, 0x13, 0x88].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_too_short() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Equal,
+			expected: 4,
+			found:    3,
+		});
+		let result = TerminalFloorLimit::try_from([0x00, 0x13, 0x88].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,167 @@
+//! Everything for handling Terminal Risk Management Data values, typically
+//! from EMV tag `0x9F1D`.
+//!
+//! The value is defined by the issuer or acquirer, so most of it is
+//! proprietary. The bits that are commonly documented (largely by Mastercard
+//! for contactless kernels) are named, and everything else is treated as
+//! issuer/acquirer discretionary. Field names use the EMV numbering of bytes
+//! and bits, which starts from 1.
+//!
+//! Information for this can be found in EMV Contactless Book C-2, under
+//! section `A.1.161`.
+
+// Uses
+use std::cmp::Ordering;
+
+use crate::{bitflag_value, error::ParseError};
+
+// Struct Implementation
+bitflag_value! {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TerminalRiskManagementData: 8 {
+	// CVM Capabilities (Contactless)
+	0 {
+		pub discretionary_byte_1_bit_8: bool =         0b1000_0000
+			=> "Issuer/acquirer discretionary",
+		pub contactless_enciphered_pin_online: bool =  0b0100_0000
+			=> "Contactless: Enciphered PIN verified online",
+		pub contactless_signature: bool =              0b0010_0000
+			=> "Contactless: Signature (paper)",
+		pub contactless_enciphered_pin_offline: bool = 0b0001_0000
+			=> "Contactless: Enciphered PIN verification performed by ICC",
+		pub contactless_no_cvm_required: bool =        0b0000_1000
+			=> "Contactless: No CVM required",
+		pub contactless_cdcvm: bool =                  0b0000_0100
+			=> "Contactless: CDCVM (Consumer Device CVM)",
+		pub contactless_plaintext_pin_offline: bool =  0b0000_0010
+			=> "Contactless: Plaintext PIN verification performed by ICC",
+		pub present_and_hold_supported: bool =         0b0000_0001
+			=> "Present and Hold supported",
+	}
+	// CVM Capabilities (Contact)
+	1 {
+		pub discretionary_byte_2_bit_8: bool =     0b1000_0000
+			=> "Issuer/acquirer discretionary",
+		pub contact_enciphered_pin_online: bool =  0b0100_0000
+			=> "Contact: Enciphered PIN verified online",
+		pub contact_signature: bool =              0b0010_0000
+			=> "Contact: Signature (paper)",
+		pub contact_enciphered_pin_offline: bool = 0b0001_0000
+			=> "Contact: Enciphered PIN verification performed by ICC",
+		pub contact_no_cvm_required: bool =        0b0000_1000
+			=> "Contact: No CVM required",
+		pub contact_cdcvm: bool =                  0b0000_0100
+			=> "Contact: CDCVM (Consumer Device CVM)",
+		pub contact_plaintext_pin_offline: bool =  0b0000_0010
+			=> "Contact: Plaintext PIN verification performed by ICC",
+		pub discretionary_byte_2_bit_1: bool =     0b0000_0001
+			=> "Issuer/acquirer discretionary",
+	}
+	// Contactless Transaction Support
+	2 {
+		pub mag_stripe_mode_not_supported: bool = 0b1000_0000
+			=> "Mag-stripe mode contactless transactions not supported",
+		pub emv_mode_not_supported: bool =        0b0100_0000
+			=> "EMV mode contactless transactions not supported",
+		pub cdcvm_without_cda_supported: bool =   0b0010_0000
+			=> "CDCVM without CDA supported",
+		pub discretionary_byte_3_bit_5: bool =    0b0001_0000
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_3_bit_4: bool =    0b0000_1000
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_3_bit_3: bool =    0b0000_0100
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_3_bit_2: bool =    0b0000_0010
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_3_bit_1: bool =    0b0000_0001
+			=> "Issuer/acquirer discretionary",
+	}
+	// Cardholder Verification
+	3 {
+		pub cdcvm_bypass_requested: bool =     0b1000_0000
+			=> "CDCVM bypass requested",
+		pub sca_exempt: bool =                 0b0100_0000
+			=> "SCA (Strong Customer Authentication) exempt",
+		pub discretionary_byte_4_bit_6: bool = 0b0010_0000
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_4_bit_5: bool = 0b0001_0000
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_4_bit_4: bool = 0b0000_1000
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_4_bit_3: bool = 0b0000_0100
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_4_bit_2: bool = 0b0000_0010
+			=> "Issuer/acquirer discretionary",
+		pub discretionary_byte_4_bit_1: bool = 0b0000_0001
+			=> "Issuer/acquirer discretionary",
+	}
+	4 {
+		pub discretionary_byte_5_bit_8: bool = 0b1000_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_7: bool = 0b0100_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_6: bool = 0b0010_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_5: bool = 0b0001_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_4: bool = 0b0000_1000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_3: bool = 0b0000_0100 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_2: bool = 0b0000_0010 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_5_bit_1: bool = 0b0000_0001 => "Issuer/acquirer discretionary",
+	}
+	5 {
+		pub discretionary_byte_6_bit_8: bool = 0b1000_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_7: bool = 0b0100_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_6: bool = 0b0010_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_5: bool = 0b0001_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_4: bool = 0b0000_1000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_3: bool = 0b0000_0100 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_2: bool = 0b0000_0010 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_6_bit_1: bool = 0b0000_0001 => "Issuer/acquirer discretionary",
+	}
+	6 {
+		pub discretionary_byte_7_bit_8: bool = 0b1000_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_7: bool = 0b0100_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_6: bool = 0b0010_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_5: bool = 0b0001_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_4: bool = 0b0000_1000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_3: bool = 0b0000_0100 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_2: bool = 0b0000_0010 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_7_bit_1: bool = 0b0000_0001 => "Issuer/acquirer discretionary",
+	}
+	7 {
+		pub discretionary_byte_8_bit_8: bool = 0b1000_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_7: bool = 0b0100_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_6: bool = 0b0010_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_5: bool = 0b0001_0000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_4: bool = 0b0000_1000 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_3: bool = 0b0000_0100 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_2: bool = 0b0000_0010 => "Issuer/acquirer discretionary",
+		pub discretionary_byte_8_bit_1: bool = 0b0000_0001 => "Issuer/acquirer discretionary",
+	}
+}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::TerminalRiskManagementData;
+	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::TerminalRiskManagementData, 8);
+	bitflag_unique_values!(super::TerminalRiskManagementData, 8);
+	bitflag_display_bits!(super::TerminalRiskManagementData, 8);
+
+	#[test]
+	fn parse_contactless_cvms() {
+		let result = TerminalRiskManagementData::try_from(
+			[0x6C, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00].as_slice(),
+		)
+		.expect("the testing value should be able to be parsed without error");
+
+		assert!(result.contactless_enciphered_pin_online);
+		assert!(result.contactless_signature);
+		assert!(result.contactless_no_cvm_required);
+		assert!(result.contactless_cdcvm);
+		assert!(!result.contactless_enciphered_pin_offline);
+		assert!(result.mag_stripe_mode_not_supported);
+	}
+}
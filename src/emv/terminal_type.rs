@@ -5,9 +5,13 @@
 // Uses
 use std::cmp::Ordering;
 
-use termcolor::StandardStream;
+use serde_json::Value as JsonValue;
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
-use crate::{enum_repr_fallible, error::ParseError, util::print_indentation, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::util::print_indentation;
+use crate::{enum_repr_fallible, error::ParseError, DisplayBreakdown};
 
 // Enum Implementation
 enum_repr_fallible! {
@@ -70,9 +74,14 @@ impl TryFrom<&[u8]> for TerminalType {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for TerminalType {
-	fn display_breakdown(&self, _: &mut StandardStream, indentation: u8, _: bool) {
-		print_indentation(indentation);
-		println!("{self}");
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		writeln!(stdout, "{self}").ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::String(self.to_string())
 	}
 }
 
@@ -2,40 +2,152 @@
 //!
 //! This is used to parse TLV data where the exact format is unknown. It calls
 //! into the other TLV format parsers.
+//!
+//! When the data can be parsed in more than one format, the formats take
+//! precedence in the following order:
+//! 1. Ingenico TLV
+//! 2. BER-TLV
+//! 3. Compact-TLV (only if the data is entirely hex)
+//!
+//! When the data can't be parsed in any format, the error from the format it
+//! was most likely meant to be in is returned: BER-TLV if the data is entirely
+//! hex, and Ingenico TLV otherwise.
 
 // Uses
 use super::{
-	ber_tlv::parse as parse_ber_tlv,
+	ber_tlv::{parse as parse_ber_tlv, parse_strict as parse_ber_tlv_strict},
+	compact_tlv::parse as parse_compact_tlv,
 	ingenico_tlv::parse as parse_ingenico_tlv,
+	MaskingSettings,
 	RawEmvBlock,
 	TlvFormat,
 };
-use crate::{error::ParseError, parse_hex_str};
+use crate::{
+	error::ParseError,
+	util::{parse_hex_str, parse_hex_str_strict},
+};
+
+// Struct Implementation
+/// The result of automatically detecting the format of a block of TLV data.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Detection {
+	pub format:       TlvFormat,
+	pub block:        RawEmvBlock,
+	/// The other formats that the data could be parsed as, which were passed
+	/// over because of their precedence. If this isn't empty, the detected
+	/// format may not be the one that was intended.
+	pub also_matches: Vec<TlvFormat>,
+}
 
 /// Parses a block of TLV data, attempting to find the format automatically.
 pub fn parse(
 	tlv_str: &str,
-	masking_characters: &[char],
+	masking: MaskingSettings,
 ) -> Result<(TlvFormat, RawEmvBlock), ParseError> {
-	// Don't accept empty input because it'll match to anything
-	if tlv_str.is_empty() {
-		return Err(ParseError::Unrecognised);
-	}
+	detect(tlv_str, masking).map(|detection| (detection.format, detection.block))
+}
+
+/// The same as [`parse`], but also reports the other formats that the data
+/// could be parsed as.
+///
+/// Line breaks and surrounding whitespace are removed before detection, so
+/// that data pasted across multiple lines is handled the same as data on a
+/// single line.
+pub fn detect(tlv_str: &str, masking: MaskingSettings) -> Result<Detection, ParseError> {
+	detect_with_rules(tlv_str, masking, false)
+}
+
+/// The same as [`detect`], but BER-TLV data has to be well-formed, as it does
+/// with [`parse_ber_tlv_strict`], and padding between tags isn't skipped.
+pub fn detect_strict(tlv_str: &str, masking: MaskingSettings) -> Result<Detection, ParseError> {
+	detect_with_rules(tlv_str, masking, true)
+}
+
+/// Detects the format of a block of TLV data, parsing BER-TLV data strictly if
+/// `strict` is `true`.
+fn detect_with_rules(
+	tlv_str: &str,
+	masking: MaskingSettings,
+	strict: bool,
+) -> Result<Detection, ParseError> {
+	let normalised = tlv_str
+		.trim()
+		.chars()
+		.filter(|c| !matches!(c, '\r' | '\n'))
+		.collect::<String>();
 
-	// Ingenico TLV
-	if let Ok(raw_emv_block) = parse_ingenico_tlv(tlv_str, masking_characters) {
-		Ok((TlvFormat::Ingenico, raw_emv_block))
+	if normalised.is_empty() {
+		return Err(ParseError::EmptyInput);
 	}
-	// BER-TLV
-	else if let Ok(raw_emv_block) =
-		parse_ber_tlv(parse_hex_str(tlv_str).as_slice(), masking_characters)
-	{
-		Ok((TlvFormat::BerTlv, raw_emv_block))
+
+	// Compact-TLV can parse most byte strings, so it's only tried if the data
+	// doesn't have anything but hex in it
+	let compact_tlv_bytes = parse_hex_str_strict(
+		normalised
+			.chars()
+			.filter(|c| !c.is_whitespace())
+			.collect::<String>()
+			.as_str(),
+	);
+
+	let results = [
+		(
+			TlvFormat::Ingenico,
+			parse_ingenico_tlv(normalised.as_str(), masking),
+		),
+		(
+			TlvFormat::BerTlv,
+			if strict {
+				parse_ber_tlv_strict(
+					parse_hex_str(normalised.as_str()).as_slice(),
+					masking,
+					false,
+				)
+			} else {
+				parse_ber_tlv(parse_hex_str(normalised.as_str()).as_slice(), masking, true)
+			},
+		),
+		(
+			TlvFormat::Compact,
+			compact_tlv_bytes
+				.clone()
+				.and_then(|bytes| parse_compact_tlv(bytes.as_slice())),
+		),
+	];
+
+	let mut matches = Vec::new();
+	let mut errors = Vec::new();
+	for (format, result) in results {
+		match result {
+			Ok(block) => matches.push((format, block)),
+			Err(error) => errors.push((format, error)),
+		}
 	}
-	// Error
-	else {
-		Err(ParseError::Unrecognised)
+
+	if matches.is_empty() {
+		// Hex data is most likely meant to be BER-TLV, and its errors say where
+		// parsing went wrong, so that error is the most useful. Anything else is
+		// most likely meant to be Ingenico TLV.
+		let intended_format = if compact_tlv_bytes.is_ok() {
+			TlvFormat::BerTlv
+		} else {
+			TlvFormat::Ingenico
+		};
+		return Err(errors
+			.into_iter()
+			.find(|(format, _)| *format == intended_format)
+			.map_or(ParseError::Unrecognised, |(_, error)| error));
 	}
+	let mut matches = matches.into_iter();
+	let (format, block) = matches
+		.next()
+		.expect("there's at least one match at this point");
+
+	Ok(Detection {
+		format,
+		block,
+		also_matches: matches.map(|(format, _)| format).collect(),
+	})
 }
 
 // Unit Tests
@@ -43,7 +155,18 @@ pub fn parse(
 mod tests {
 	// Uses
 	use super::{
-		super::{DataObjectType, EmvData, RawEmvBlock, RawEmvNode, RawEmvTag, TagClass, TlvFormat},
+		super::{
+			DataObjectType,
+			EmvData,
+			MaskingSettings,
+			RawEmvBlock,
+			RawEmvNode,
+			RawEmvTag,
+			TagClass,
+			TlvFormat,
+		},
+		detect,
+		detect_strict,
 		parse,
 	};
 	use crate::error::ParseError;
@@ -51,13 +174,13 @@ mod tests {
 	// Tests
 	#[allow(clippy::needless_pass_by_value)]
 	fn test_parse(data: &str, expected: Result<(TlvFormat, RawEmvBlock), ParseError>) {
-		let result = parse(data, ['*'].as_slice());
+		let result = parse(data, MaskingSettings::default());
 		assert_eq!(expected, result);
 	}
 
 	#[test]
 	fn parse_empty_data() {
-		test_parse("", Err(ParseError::Unrecognised));
+		test_parse("", Err(ParseError::EmptyInput));
 	}
 	#[test]
 	fn parse_ber_tlv_valid() {
@@ -81,7 +204,15 @@ mod tests {
 	}
 	#[test]
 	fn parse_ber_tlv_invalid() {
-		test_parse("5F24032531", Err(ParseError::Unrecognised));
+		test_parse(
+			"5F24032531",
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x5F, 0x24],
+				offset:    0,
+				declared:  3,
+				available: 2,
+			}),
+		);
 	}
 	#[test]
 	fn parse_ingenico_valid() {
@@ -105,6 +236,60 @@ mod tests {
 	}
 	#[test]
 	fn parse_ingenico_invalid() {
-		test_parse("T5F24:03:h2531", Err(ParseError::Unrecognised));
+		test_parse("T5F24:03:h2531", Err(ParseError::NonCompliant));
+	}
+	#[test]
+	fn detect_ber_tlv_and_compact_tlv_prefers_ber_tlv() {
+		let expected = Ok((TlvFormat::BerTlv, vec![TlvFormat::Compact]));
+		let result = detect("C10100", MaskingSettings::default())
+			.map(|detection| (detection.format, detection.also_matches));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn detect_compact_tlv_only() {
+		let expected = Ok((TlvFormat::Compact, vec![]));
+		let result = detect("31C0", MaskingSettings::default())
+			.map(|detection| (detection.format, detection.also_matches));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn detect_constructed_tag_without_tlv_data() {
+		let expected = Ok((TlvFormat::BerTlv, vec![TlvFormat::Compact]));
+		let result = detect("E3020102", MaskingSettings::default())
+			.map(|detection| (detection.format, detection.also_matches));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn detect_strict_constructed_tag_without_tlv_data() {
+		let expected = Ok((TlvFormat::Compact, vec![]));
+		let result = detect_strict("E3020102", MaskingSettings::default())
+			.map(|detection| (detection.format, detection.also_matches));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn detect_unambiguous_ber_tlv() {
+		let expected = Ok((TlvFormat::BerTlv, vec![]));
+		let result = detect("5F2403251231", MaskingSettings::default())
+			.map(|detection| (detection.format, detection.also_matches));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn detect_across_line_breaks() {
+		let expected = detect("T5F24:03:h251231", MaskingSettings::default());
+		let result = detect("  T5F24:03:\r\nh251\n231\n", MaskingSettings::default());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn detect_whitespace_only() {
+		let expected = Err(ParseError::EmptyInput);
+		let result = detect(" \n ", MaskingSettings::default());
+
+		assert_eq!(expected, result);
 	}
 }
@@ -3,18 +3,206 @@
 //! Information for this can be found in EMV Book 3, under `Annex B`.
 
 // Uses
-use super::{DataObjectType, EmvData, RawEmvBlock, RawEmvNode, RawEmvTag, TagClass};
+use std::{cell::Cell, ops::Range};
+
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::{
+	DataObjectType,
+	EmvData,
+	MaskingSettings,
+	RawEmvBlock,
+	RawEmvNode,
+	RawEmvTag,
+	TagClass,
+};
 use crate::{
 	error::ParseError,
-	util::{byte_slice_to_u32, BYTES_PER_32_BITS},
+	util::{byte_slice_to_u64, parse_hex_str_strict},
 };
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{length_bytes_colour_spec, tag_bytes_colour_spec},
+	util::print_bytes_small,
+};
+
+// Constants
+const TAG_VALUE_SEPARATOR: char = '=';
+const TAG_LIST_SEPARATOR: char = ';';
+/// The length byte that indicates the indefinite length form, where the data
+/// continues until an end-of-contents marker.
+const INDEFINITE_LENGTH: u8 = 0b1000_0000;
+/// The marker that ends the data of a tag using the indefinite length form.
+const END_OF_CONTENTS: [u8; 2] = [0x00, 0x00];
+/// The bytes that may be used as padding before, between, or after tags.
+const PADDING_BYTES: [u8; 2] = [0x00, 0xFF];
+/// The default maximum number of constructed tags that can be nested inside
+/// each other.
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
+/// A tag ID and its value, for use with [`encode`].
+pub type TagValuePair = (Vec<u8>, Vec<u8>);
+
+/// The byte ranges that a tag occupied in the data it was parsed from.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TagSpan {
+	pub tag:    Range<usize>,
+	pub length: Range<usize>,
+	/// The tag's value, not including the end-of-contents marker if it used the
+	/// indefinite length form.
+	pub value:  Range<usize>,
+}
+
+/// The rules to follow while parsing, shared between the public entry points.
+#[derive(Copy, Clone, Debug)]
+struct ParsingRules<'a> {
+	skip_padding:   bool,
+	strict:         bool,
+	max_depth:      usize,
+	/// How many constructed tags deep the current data is.
+	depth:          usize,
+	/// Set if the maximum depth was exceeded, since non-strict parsing would
+	/// otherwise leave the too-deep data unparsed without an error.
+	depth_exceeded: &'a Cell<bool>,
+}
+
+impl ParsingRules<'_> {
+	/// The rules for the data of a constructed tag.
+	fn nested(self) -> Self {
+		Self {
+			depth: self.depth + 1,
+			..self
+		}
+	}
+}
 
 /// Parses a block of BER-TLV encoded data.
-pub fn parse(bytes: &[u8], masking_characters: &[char]) -> Result<RawEmvBlock, ParseError> {
+///
+/// If `skip_padding` is `true`, any `0x00` or `0xFF` bytes before, between, or
+/// after tags are ignored, as allowed by EMV Book 3, `Annex B`. Otherwise, they
+/// are read as the start of a tag.
+pub fn parse(
+	bytes: &[u8],
+	masking: MaskingSettings,
+	skip_padding: bool,
+) -> Result<RawEmvBlock, ParseError> {
+	parse_with_max_depth(bytes, masking, skip_padding, false, DEFAULT_MAX_DEPTH)
+}
+
+/// The same as [`parse`], but every byte has to be part of a well-formed tag.
+///
+/// The data of constructed tags has to be valid BER-TLV as well, instead of
+/// being left unparsed if it isn't, and tags can't start with `0x00` since it's
+/// reserved for the end-of-contents marker. Padding is only allowed if
+/// `skip_padding` is `true`.
+pub fn parse_strict(
+	bytes: &[u8],
+	masking: MaskingSettings,
+	skip_padding: bool,
+) -> Result<RawEmvBlock, ParseError> {
+	parse_with_max_depth(bytes, masking, skip_padding, true, DEFAULT_MAX_DEPTH)
+}
+
+/// The same as [`parse`] or [`parse_strict`] (depending on `strict`), but with
+/// a custom limit on how deeply constructed tags can be nested, instead of
+/// [`DEFAULT_MAX_DEPTH`].
+///
+/// Returns [`ParseError::NestingTooDeep`] if the data is nested deeper than
+/// `max_depth`.
+pub fn parse_with_max_depth(
+	bytes: &[u8],
+	masking: MaskingSettings,
+	skip_padding: bool,
+	strict: bool,
+	max_depth: usize,
+) -> Result<RawEmvBlock, ParseError> {
+	parse_with_rules(bytes, masking, skip_padding, strict, max_depth).map(|(block, _)| block)
+}
+
+/// The same as [`parse`] or [`parse_strict`] (depending on `strict`), but also
+/// returns the byte ranges that each top-level tag occupied in `bytes`.
+pub fn parse_with_spans(
+	bytes: &[u8],
+	masking: MaskingSettings,
+	skip_padding: bool,
+	strict: bool,
+) -> Result<(RawEmvBlock, Vec<TagSpan>), ParseError> {
+	parse_with_rules(bytes, masking, skip_padding, strict, DEFAULT_MAX_DEPTH)
+}
+
+/// The shared implementation of the public entry points, returning the block
+/// along with the spans of its top-level tags.
+fn parse_with_rules(
+	bytes: &[u8],
+	masking: MaskingSettings,
+	skip_padding: bool,
+	strict: bool,
+	max_depth: usize,
+) -> Result<(RawEmvBlock, Vec<TagSpan>), ParseError> {
+	if bytes.is_empty() {
+		return Err(ParseError::EmptyInput);
+	}
+
+	let depth_exceeded = Cell::new(false);
+	let rules = ParsingRules {
+		skip_padding,
+		strict,
+		max_depth,
+		depth: 0,
+		depth_exceeded: &depth_exceeded,
+	};
+	let result = parse_nodes(bytes, masking, rules, false).map(|(block, spans, _)| (block, spans));
+	if depth_exceeded.get() {
+		return Err(ParseError::NestingTooDeep {
+			max_depth: rules.max_depth,
+		});
+	}
+
+	result
+}
+
+/// Parses BER-TLV encoded data into a block.
+///
+/// If `stop_at_end_of_contents` is `true`, parsing stops at the first
+/// end-of-contents marker, and it's an error if there isn't one. This is used
+/// for the data of constructed tags that use the indefinite length form.
+///
+/// Returns the block along with the spans of its tags, and the number of bytes
+/// it took up, not including the end-of-contents marker.
+fn parse_nodes(
+	bytes: &[u8],
+	masking: MaskingSettings,
+	rules: ParsingRules,
+	stop_at_end_of_contents: bool,
+) -> Result<(RawEmvBlock, Vec<TagSpan>, usize), ParseError> {
+	// This bounds the recursion, so that deeply-nested data can't exhaust the
+	// stack
+	if rules.depth > rules.max_depth {
+		rules.depth_exceeded.set(true);
+		return Err(ParseError::NestingTooDeep {
+			max_depth: rules.max_depth,
+		});
+	}
+
 	let bytes_len = bytes.len();
 	let mut nodes = Vec::new();
+	let mut spans = Vec::new();
 	let mut index = 0;
 	while index < bytes_len {
+		// The end-of-contents marker takes priority over padding, since it's made up
+		// of the same bytes
+		if stop_at_end_of_contents && bytes[index..].starts_with(&END_OF_CONTENTS) {
+			return Ok((nodes.into(), spans, index));
+		}
+		if rules.skip_padding && PADDING_BYTES.contains(&bytes[index]) {
+			index += 1;
+			continue;
+		}
+		if rules.strict && bytes[index] == END_OF_CONTENTS[0] {
+			return Err(ParseError::NonCompliant);
+		}
+
 		// The first byte contains some metadata about the tag
 		let tag_start_index = index;
 		let tag_byte_0 = bytes[index];
@@ -38,50 +226,104 @@ pub fn parse(bytes: &[u8], masking_characters: &[char]) -> Result<RawEmvBlock, P
 		}
 
 		// The length is next
+		let length_start_index = index;
 		let length_byte_0 = bytes[index];
+		if length_byte_0 == INDEFINITE_LENGTH {
+			// Only constructed tags can use the indefinite length form, since the data of
+			// primitive tags could contain the end-of-contents marker
+			if data_object_type == DataObjectType::Primitive {
+				return Err(ParseError::NonCompliant);
+			}
+			index += 1;
+			let (child_block, _, content_length) =
+				parse_nodes(&bytes[index..], masking, rules.nested(), true)
+					.map_err(|error| offset_error(error, index))?;
+			nodes.push(RawEmvNode {
+				child_block,
+				tag: RawEmvTag {
+					tag: bytes[tag_start_index..=tag_end_index].to_vec(),
+					class,
+					data_object_type,
+					data: EmvData::Normal(bytes[index..(index + content_length)].to_vec()),
+				},
+			});
+			spans.push(TagSpan {
+				tag:    tag_start_index..(tag_end_index + 1),
+				length: (index - 1)..index,
+				value:  index..(index + content_length),
+			});
+			index += content_length + END_OF_CONTENTS.len();
+			continue;
+		}
 		let length = if 0b1000_0000 & length_byte_0 > 0 {
 			let subsequent_length_byte_count = (0b0111_1111 & length_byte_0) as usize;
-			// Tag lengths greater than the maximum unsigned 32-bit integer value are
-			// unsupported
-			if subsequent_length_byte_count > BYTES_PER_32_BITS {
-				return Err(ParseError::Unsupported);
-			}
 			let start_index = index;
 			index += 1 + subsequent_length_byte_count;
-			if index >= bytes_len {
+			if index > bytes_len {
 				return Err(ParseError::NonCompliant);
 			}
-			byte_slice_to_u32(
+			// Tag lengths greater than the maximum unsigned 64-bit integer value, or that
+			// don't fit in memory on this platform, are unsupported
+			usize::try_from(byte_slice_to_u64(
 				&bytes[(start_index + 1)..=(start_index + subsequent_length_byte_count)],
-			) as usize
+			)?)
+			.map_err(|_| ParseError::Unsupported)?
 		} else {
 			index += 1;
 			usize::from(length_byte_0)
 		};
-		if index + length >= bytes_len + 1 {
-			return Err(ParseError::NonCompliant);
+		// This is compared against the remaining data instead of adding the length to
+		// the index, since a large enough length would overflow
+		if length > bytes_len - index {
+			return Err(ParseError::LengthExceedsData {
+				tag:       bytes[tag_start_index..=tag_end_index].to_vec(),
+				offset:    tag_start_index,
+				declared:  length,
+				available: bytes_len - index,
+			});
 		}
 
 		// Store a reference to the data
 		let data = &bytes[index..(index + length)];
 
 		// Push the resulting tag to the list
-		let tag_data = EmvData::from_u8_check_for_masked(data.to_vec(), masking_characters);
+		let tag_data = EmvData::from_u8_check_for_masked(data.to_vec(), masking);
+		let child_block = parse_child_block(data_object_type, &tag_data, masking, rules.nested())
+			.map_err(|error| offset_error(error, index))?;
 		nodes.push(RawEmvNode {
-			child_block: get_child_block(data_object_type, &tag_data, masking_characters),
-			tag:         RawEmvTag {
+			child_block,
+			tag: RawEmvTag {
 				tag: bytes[tag_start_index..=tag_end_index].to_vec(),
 				class,
 				data_object_type,
 				data: tag_data,
 			},
 		});
+		spans.push(TagSpan {
+			tag:    tag_start_index..(tag_end_index + 1),
+			length: length_start_index..index,
+			value:  index..(index + length),
+		});
 
 		// Increment the index
 		index += length;
 	}
 
-	Ok(nodes.into())
+	if stop_at_end_of_contents {
+		return Err(ParseError::NonCompliant);
+	}
+
+	Ok((nodes.into(), spans, index))
+}
+
+/// Adjusts the offset in an error from parsing the data of a constructed tag,
+/// so that it's relative to the start of the whole block instead of the data.
+fn offset_error(mut error: ParseError, data_offset: usize) -> ParseError {
+	if let ParseError::LengthExceedsData { offset, .. } = &mut error {
+		*offset += data_offset;
+	}
+
+	error
 }
 
 /// Parses the class and data object type of the tag from the tag ID's first
@@ -104,30 +346,233 @@ pub fn parse_tag_metadata(tag_byte_0: u8) -> (TagClass, DataObjectType) {
 /// if `data_object_type` is [`DataObjectType::Constructed`].
 ///
 /// Otherwise, it returns [`RawEmvBlock::default`].
+///
+/// Some vendors mark tags as constructed when their data isn't BER-TLV (like
+/// Verifone with `0xE3`), so by default the data is left unparsed if it isn't
+/// valid. If `strict` is `true`, the error is returned instead.
 pub fn get_child_block(
 	data_object_type: DataObjectType,
 	tag_data: &EmvData,
-	masking_characters: &[char],
-) -> RawEmvBlock {
+	masking: MaskingSettings,
+	skip_padding: bool,
+	strict: bool,
+) -> Result<RawEmvBlock, ParseError> {
+	let depth_exceeded = Cell::new(false);
+	let rules = ParsingRules {
+		skip_padding,
+		strict,
+		max_depth: DEFAULT_MAX_DEPTH,
+		depth: 1,
+		depth_exceeded: &depth_exceeded,
+	};
+	let result = parse_child_block(data_object_type, tag_data, masking, rules);
+	if depth_exceeded.get() {
+		return Err(ParseError::NestingTooDeep {
+			max_depth: rules.max_depth,
+		});
+	}
+
+	result
+}
+
+/// The same as [`get_child_block`], but continuing with the rules of the data
+/// the tag is in.
+fn parse_child_block(
+	data_object_type: DataObjectType,
+	tag_data: &EmvData,
+	masking: MaskingSettings,
+	rules: ParsingRules,
+) -> Result<RawEmvBlock, ParseError> {
 	match data_object_type {
-		DataObjectType::Primitive => RawEmvBlock::default(),
+		DataObjectType::Primitive => Ok(RawEmvBlock::default()),
 		DataObjectType::Constructed => match tag_data {
-			EmvData::Normal(data) => parse(data, masking_characters).unwrap_or_default(),
-			EmvData::Masked => RawEmvBlock::default(),
+			EmvData::Normal(data) => {
+				let result = parse_nodes(data, masking, rules, false).map(|(block, ..)| block);
+				if rules.strict {
+					result
+				} else {
+					Ok(result.unwrap_or_default())
+				}
+			}
+			EmvData::Masked => Ok(RawEmvBlock::default()),
 		},
 	}
 }
 
+/// Prints the bytes that make up each tag in `spans` as hex, with the tag,
+/// length, and value bytes in different colours.
+///
+/// Each part of a tag is separated by a space, and each tag by two. Any bytes
+/// between the tags, like padding, are shown on their own.
+#[cfg(feature = "display")]
+#[cfg(not(tarpaulin_include))]
+pub fn print_spans(stdout: &mut dyn WriteColor, bytes: &[u8], spans: &[TagSpan]) {
+	let mut index = 0;
+	for span in spans {
+		if index > 0 {
+			write!(stdout, "  ").ok();
+		}
+		if span.tag.start > index {
+			print_bytes_small(stdout, &bytes[index..span.tag.start]);
+			write!(stdout, "  ").ok();
+		}
+
+		stdout.set_color(&tag_bytes_colour_spec()).ok();
+		print_bytes_small(stdout, &bytes[span.tag.clone()]);
+		stdout.reset().ok();
+		write!(stdout, " ").ok();
+		stdout.set_color(&length_bytes_colour_spec()).ok();
+		print_bytes_small(stdout, &bytes[span.length.clone()]);
+		stdout.reset().ok();
+		if !span.value.is_empty() {
+			write!(stdout, " ").ok();
+			print_bytes_small(stdout, &bytes[span.value.clone()]);
+		}
+
+		index = span.value.end;
+		// Tags using the indefinite length form end with the end-of-contents marker,
+		// which is shown like the length since it serves the same purpose
+		if bytes[span.length.clone()] == [INDEFINITE_LENGTH] {
+			write!(stdout, " ").ok();
+			stdout.set_color(&length_bytes_colour_spec()).ok();
+			print_bytes_small(stdout, &END_OF_CONTENTS);
+			stdout.reset().ok();
+			index += END_OF_CONTENTS.len();
+		}
+	}
+	if index < bytes.len() {
+		if index > 0 {
+			write!(stdout, "  ").ok();
+		}
+		print_bytes_small(stdout, &bytes[index..]);
+	}
+	writeln!(stdout).ok();
+}
+
+/// Encodes a list of tags and their values as BER-TLV data.
+///
+/// This is the inverse of [`parse`]. The tags are written in the order they're
+/// provided, and are expected to already be valid BER-TLV tag IDs.
+pub fn encode(tags: &[TagValuePair]) -> Vec<u8> {
+	let mut bytes = Vec::new();
+	for (tag, value) in tags {
+		encode_tag(&mut bytes, tag.as_slice(), value.as_slice());
+	}
+
+	bytes
+}
+
+/// Encodes a block of EMV data as BER-TLV data.
+///
+/// This is the inverse of [`parse`]. Constructed tags with child tags have
+/// their data rebuilt from the children, so any changes made to the children
+/// are reflected in the output.
+///
+/// Masked data can't be encoded, since the original value isn't known.
+pub fn encode_block(block: &RawEmvBlock) -> Result<Vec<u8>, ParseError> {
+	let mut bytes = Vec::new();
+	for node in &block.nodes {
+		if node.child_block.nodes.is_empty() {
+			match &node.tag.data {
+				EmvData::Normal(data) => {
+					encode_tag(&mut bytes, node.tag.tag.as_slice(), data.as_slice());
+				}
+				EmvData::Masked => return Err(ParseError::Unsupported),
+			}
+		} else {
+			let child_bytes = encode_block(&node.child_block)?;
+			encode_tag(&mut bytes, node.tag.tag.as_slice(), child_bytes.as_slice());
+		}
+	}
+
+	Ok(bytes)
+}
+
+/// Appends a single tag, its length, and its value to `bytes`.
+fn encode_tag(bytes: &mut Vec<u8>, tag: &[u8], value: &[u8]) {
+	bytes.extend_from_slice(tag);
+	bytes.extend(encode_length(value.len()));
+	bytes.extend_from_slice(value);
+}
+
+/// Encodes a data length according to the BER-TLV specification.
+///
+/// Lengths up to 127 are encoded in a single byte. Longer lengths are encoded
+/// as a byte with the high bit set that specifies how many length bytes follow,
+/// then the length itself in big-endian order.
+fn encode_length(length: usize) -> Vec<u8> {
+	if length <= 0b0111_1111 {
+		return vec![length as u8];
+	}
+
+	let length_bytes = length.to_be_bytes();
+	let first_significant_byte = length_bytes
+		.iter()
+		.position(|&byte| byte > 0)
+		.expect("the length is non-zero");
+	let significant_bytes = &length_bytes[first_significant_byte..];
+
+	let mut encoded = Vec::with_capacity(1 + significant_bytes.len());
+	encoded.push(0b1000_0000 | significant_bytes.len() as u8);
+	encoded.extend_from_slice(significant_bytes);
+	encoded
+}
+
+/// Parses a list of tags and their values in the form `5A=4761730000000119;
+/// 5F34=01`, for use with [`encode`].
+///
+/// Both the tags and values are hex, and whitespace is ignored.
+pub fn parse_tag_value_list(list: &str) -> Result<Vec<TagValuePair>, ParseError> {
+	list.split(TAG_LIST_SEPARATOR)
+		.map(|entry| {
+			entry
+				.chars()
+				.filter(|c| !c.is_whitespace())
+				.collect::<String>()
+		})
+		.filter(|entry| !entry.is_empty())
+		.map(|entry| {
+			let (tag_str, value_str) = entry
+				.split_once(TAG_VALUE_SEPARATOR)
+				.ok_or(ParseError::NonCompliant)?;
+			let tag = parse_hex_str_strict(tag_str)?;
+			if tag.is_empty() {
+				return Err(ParseError::NonCompliant);
+			}
+			let value = parse_hex_str_strict(value_str)?;
+
+			Ok((tag, value))
+		})
+		.collect()
+}
+
 // Unit Tests
 #[cfg(test)]
 mod tests {
 	// Uses
 	use super::{
-		super::{DataObjectType, EmvData, RawEmvBlock, RawEmvNode, RawEmvTag, TagClass},
+		super::{
+			DataObjectType,
+			EmvData,
+			MaskingMode,
+			MaskingSettings,
+			RawEmvBlock,
+			RawEmvNode,
+			RawEmvTag,
+			TagClass,
+		},
+		encode,
+		encode_block,
 		parse,
+		parse_strict,
 		parse_tag_metadata,
+		parse_tag_value_list,
+		parse_with_max_depth,
+		parse_with_spans,
+		TagSpan,
+		DEFAULT_MAX_DEPTH,
 	};
-	use crate::error::ParseError;
+	use crate::{error::ParseError, util::parse_base64_str};
 
 	// Tests
 	#[test]
@@ -170,13 +615,13 @@ mod tests {
 
 	#[allow(clippy::needless_pass_by_value)]
 	fn test_parse(bytes: &[u8], expected: Result<RawEmvBlock, ParseError>) {
-		let result = parse(bytes, ['*'].as_slice());
+		let result = parse(bytes, MaskingSettings::default(), true);
 		assert_eq!(expected, result);
 	}
 
 	#[test]
 	fn parse_empty_data() {
-		test_parse([].as_slice(), Ok(RawEmvBlock { nodes: vec![] }));
+		test_parse([].as_slice(), Err(ParseError::EmptyInput));
 	}
 	#[test]
 	fn parse_single_byte_primitive_unmasked_tag() {
@@ -215,6 +660,25 @@ mod tests {
 		);
 	}
 	#[test]
+	fn parse_base64_encoded_block() {
+		test_parse(
+			parse_base64_str("nzYCAAE=")
+				.expect("the base64 decoding is tested separately")
+				.as_slice(),
+			Ok(RawEmvBlock {
+				nodes: vec![RawEmvNode {
+					tag:         RawEmvTag {
+						tag:              vec![0x9F, 0x36],
+						class:            TagClass::ContextSpecific,
+						data_object_type: DataObjectType::Primitive,
+						data:             EmvData::Normal(vec![0x00, 0x01]),
+					},
+					child_block: RawEmvBlock::default(),
+				}],
+			}),
+		);
+	}
+	#[test]
 	fn parse_single_byte_primitive_masked_tag() {
 		test_parse(
 			[0x5A, 0x08, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A].as_slice(),
@@ -249,6 +713,60 @@ mod tests {
 		);
 	}
 	#[test]
+	fn parse_single_byte_value_masked_when_enabled() {
+		let expected = Ok(RawEmvBlock {
+			nodes: vec![RawEmvNode {
+				tag:         RawEmvTag {
+					tag:              vec![0x9F, 0x35],
+					class:            TagClass::ContextSpecific,
+					data_object_type: DataObjectType::Primitive,
+					data:             EmvData::Masked,
+				},
+				child_block: RawEmvBlock::default(),
+			}],
+		});
+		let result = parse(
+			[0x9F, 0x35, 0x01, 0x2A].as_slice(),
+			MaskingSettings {
+				mask_single_byte: true,
+				..MaskingSettings::default()
+			},
+			true,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_longer_value_unaffected_by_mask_single_byte() {
+		let expected = Ok(RawEmvBlock {
+			nodes: vec![RawEmvNode {
+				tag:         RawEmvTag {
+					tag:              vec![0x9F, 0x26],
+					class:            TagClass::ContextSpecific,
+					data_object_type: DataObjectType::Primitive,
+					data:             EmvData::Normal(vec![
+						0x11, 0x2A, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+					]),
+				},
+				child_block: RawEmvBlock::default(),
+			}],
+		});
+		let result = parse(
+			[
+				0x9F, 0x26, 0x08, 0x11, 0x2A, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+			]
+			.as_slice(),
+			MaskingSettings {
+				mode: MaskingMode::AnyConfiguredChar,
+				mask_single_byte: true,
+				..MaskingSettings::default()
+			},
+			true,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
 	fn parse_constructed_unmasked_tag() {
 		test_parse(
 			[
@@ -477,13 +995,184 @@ mod tests {
 		// This input data is actually invalid, but here we're just testing that the
 		// number of bytes indicated is correctly identified as unsupported
 		test_parse(
-			[0x91, 0b1000_0101, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF].as_slice(),
+			[
+				0x91,
+				0b1000_1001,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+			]
+			.as_slice(),
 			Err(ParseError::Unsupported),
 		);
 	}
 	#[test]
+	fn parse_nested_beyond_max_depth() {
+		// Each level wraps the previous one in a constructed `0xE1` tag
+		let mut bytes = vec![0x9F, 0x36, 0x02, 0x00, 0x01];
+		for _ in 0..=DEFAULT_MAX_DEPTH {
+			let length = u8::try_from(bytes.len()).unwrap();
+			bytes.splice(0..0, [0xE1, length]);
+		}
+
+		test_parse(
+			bytes.as_slice(),
+			Err(ParseError::NestingTooDeep {
+				max_depth: DEFAULT_MAX_DEPTH,
+			}),
+		);
+	}
+	#[test]
+	fn parse_nested_within_custom_max_depth() {
+		let expected = Ok(RawEmvBlock::from(vec![RawEmvNode {
+			tag:         RawEmvTag {
+				tag:              vec![0xE1],
+				class:            TagClass::Private,
+				data_object_type: DataObjectType::Constructed,
+				data:             EmvData::Normal(vec![0x9F, 0x36, 0x02, 0x00, 0x01]),
+			},
+			child_block: RawEmvBlock::from(vec![RawEmvNode {
+				tag:         RawEmvTag {
+					tag:              vec![0x9F, 0x36],
+					class:            TagClass::ContextSpecific,
+					data_object_type: DataObjectType::Primitive,
+					data:             EmvData::Normal(vec![0x00, 0x01]),
+				},
+				child_block: RawEmvBlock::default(),
+			}]),
+		}]));
+		let result = parse_with_max_depth(
+			[0xE1, 0x05, 0x9F, 0x36, 0x02, 0x00, 0x01].as_slice(),
+			MaskingSettings::default(),
+			true,
+			true,
+			1,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_nested_beyond_custom_max_depth() {
+		let expected = Err(ParseError::NestingTooDeep { max_depth: 1 });
+		let result = parse_with_max_depth(
+			[0xE1, 0x07, 0xE1, 0x05, 0x9F, 0x36, 0x02, 0x00, 0x01].as_slice(),
+			MaskingSettings::default(),
+			true,
+			false,
+			1,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_spans_of_multiple_tags() {
+		let expected = Ok(vec![
+			TagSpan {
+				tag:    1..3,
+				length: 3..4,
+				value:  4..6,
+			},
+			TagSpan {
+				tag:    6..7,
+				length: 7..9,
+				value:  9..10,
+			},
+			TagSpan {
+				tag:    10..11,
+				length: 11..12,
+				value:  12..15,
+			},
+		]);
+		let result = parse_with_spans(
+			[
+				0x00, 0x9F, 0x36, 0x02, 0x00, 0x01, 0x95, 0x81, 0x01, 0x00, 0xE1, 0x80, 0x5A, 0x01,
+				0x01, 0x00, 0x00,
+			]
+			.as_slice(),
+			MaskingSettings::default(),
+			true,
+			false,
+		)
+		.map(|(_, spans)| spans);
+
+		assert_eq!(expected, result);
+	}
+	#[cfg(feature = "display")]
+	#[test]
+	fn print_spans_of_multiple_tags() {
+		let bytes = [
+			0x00, 0x9F, 0x36, 0x02, 0x00, 0x01, 0xE1, 0x80, 0x5A, 0x01, 0x01, 0x00, 0x00, 0xFF,
+		];
+		let (_, spans) =
+			parse_with_spans(bytes.as_slice(), MaskingSettings::default(), true, false)
+				.expect("the testing value should be able to be parsed without error");
+		let mut buffer = termcolor::Buffer::no_color();
+		super::print_spans(&mut buffer, bytes.as_slice(), spans.as_slice());
+
+		let expected = "00  9F36 02 0001  E1 80 5A0101 0000  FF\n";
+		let result = String::from_utf8_lossy(buffer.as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_big_tag_length() {
+		test_parse(
+			[0x91, 0b1000_0101, 0x00, 0x00, 0x00, 0x00, 0x01, 0xFF].as_slice(),
+			Ok(RawEmvBlock {
+				nodes: vec![RawEmvNode {
+					tag:         RawEmvTag {
+						tag:              vec![0x91],
+						class:            TagClass::ContextSpecific,
+						data_object_type: DataObjectType::Primitive,
+						data:             EmvData::Normal(vec![0xFF]),
+					},
+					child_block: RawEmvBlock::default(),
+				}],
+			}),
+		);
+	}
+	#[test]
+	fn parse_huge_tag_length_exceeds_data() {
+		test_parse(
+			[
+				0x91,
+				0b1000_1000,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0xFF,
+				0x00,
+			]
+			.as_slice(),
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x91],
+				offset:    0,
+				declared:  usize::MAX,
+				available: 1,
+			}),
+		);
+	}
+	#[test]
 	fn parse_invalid_tag_length_too_long() {
-		test_parse([0x91, 0x02, 0x00].as_slice(), Err(ParseError::NonCompliant));
+		test_parse(
+			[0x91, 0x02, 0x00].as_slice(),
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x91],
+				offset:    0,
+				declared:  2,
+				available: 1,
+			}),
+		);
 	}
 	#[test]
 	fn parse_invalid_tag_name_indicates_more_coming_but_ends() {
@@ -497,12 +1186,253 @@ mod tests {
 		);
 	}
 	#[test]
+	fn parse_invalid_multi_byte_tag_length_no_tag_data() {
+		test_parse(
+			[0x9F, 0x10, 0b1000_0001, 0x80].as_slice(),
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x9F, 0x10],
+				offset:    0,
+				declared:  128,
+				available: 0,
+			}),
+		);
+	}
+	#[test]
 	fn parse_invalid_no_tag_length() {
 		test_parse([0x91].as_slice(), Err(ParseError::NonCompliant));
 	}
 	#[test]
 	fn parse_invalid_no_tag_data() {
-		test_parse([0x91, 0x10].as_slice(), Err(ParseError::NonCompliant));
+		test_parse(
+			[0x91, 0x10].as_slice(),
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x91],
+				offset:    0,
+				declared:  16,
+				available: 0,
+			}),
+		);
+	}
+	#[test]
+	fn parse_indefinite_length_constructed_tag() {
+		test_parse(
+			[
+				0x70, 0x80, 0x6F, 0x80, 0x4F, 0x02, 0xA0, 0x00, 0x00, 0x00, 0x95, 0x01, 0x00, 0x00,
+				0x00, 0x9F, 0x09, 0x02, 0x00, 0x8C,
+			]
+			.as_slice(),
+			Ok(RawEmvBlock {
+				nodes: vec![
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x70],
+							class:            TagClass::Application,
+							data_object_type: DataObjectType::Constructed,
+							data:             EmvData::Normal(vec![
+								0x6F, 0x80, 0x4F, 0x02, 0xA0, 0x00, 0x00, 0x00, 0x95, 0x01, 0x00,
+							]),
+						},
+						child_block: RawEmvBlock {
+							nodes: vec![
+								RawEmvNode {
+									tag:         RawEmvTag {
+										tag:              vec![0x6F],
+										class:            TagClass::Application,
+										data_object_type: DataObjectType::Constructed,
+										data:             EmvData::Normal(vec![
+											0x4F, 0x02, 0xA0, 0x00,
+										]),
+									},
+									child_block: RawEmvBlock {
+										nodes: vec![RawEmvNode {
+											tag:         RawEmvTag {
+												tag:              vec![0x4F],
+												class:            TagClass::Application,
+												data_object_type: DataObjectType::Primitive,
+												data:             EmvData::Normal(vec![0xA0, 0x00]),
+											},
+											child_block: RawEmvBlock::default(),
+										}],
+									},
+								},
+								RawEmvNode {
+									tag:         RawEmvTag {
+										tag:              vec![0x95],
+										class:            TagClass::ContextSpecific,
+										data_object_type: DataObjectType::Primitive,
+										data:             EmvData::Normal(vec![0x00]),
+									},
+									child_block: RawEmvBlock::default(),
+								},
+							],
+						},
+					},
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x9F, 0x09],
+							class:            TagClass::ContextSpecific,
+							data_object_type: DataObjectType::Primitive,
+							data:             EmvData::Normal(vec![0x00, 0x8C]),
+						},
+						child_block: RawEmvBlock::default(),
+					},
+				],
+			}),
+		);
+	}
+	#[test]
+	fn parse_invalid_indefinite_length_primitive_tag() {
+		test_parse(
+			[0x9F, 0x09, 0x80, 0x00, 0x8C, 0x00, 0x00].as_slice(),
+			Err(ParseError::NonCompliant),
+		);
+	}
+	#[test]
+	fn parse_invalid_indefinite_length_no_end_of_contents() {
+		test_parse(
+			[0x70, 0x80, 0x9F, 0x09, 0x02, 0x00, 0x8C].as_slice(),
+			Err(ParseError::NonCompliant),
+		);
+	}
+	#[test]
+	fn parse_padding_between_and_after_tags() {
+		test_parse(
+			[
+				0x00, 0x9F, 0x09, 0x02, 0x00, 0x8C, 0xFF, 0x00, 0x5F, 0x34, 0x01, 0x01, 0xFF, 0xFF,
+			]
+			.as_slice(),
+			Ok(RawEmvBlock {
+				nodes: vec![
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x9F, 0x09],
+							class:            TagClass::ContextSpecific,
+							data_object_type: DataObjectType::Primitive,
+							data:             EmvData::Normal(vec![0x00, 0x8C]),
+						},
+						child_block: RawEmvBlock::default(),
+					},
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x5F, 0x34],
+							class:            TagClass::Application,
+							data_object_type: DataObjectType::Primitive,
+							data:             EmvData::Normal(vec![0x01]),
+						},
+						child_block: RawEmvBlock::default(),
+					},
+				],
+			}),
+		);
+	}
+	#[test]
+	fn parse_padding_inside_constructed_tag() {
+		test_parse(
+			[0x70, 0x05, 0x00, 0x5A, 0x01, 0x47, 0xFF].as_slice(),
+			Ok(RawEmvBlock {
+				nodes: vec![RawEmvNode {
+					tag:         RawEmvTag {
+						tag:              vec![0x70],
+						class:            TagClass::Application,
+						data_object_type: DataObjectType::Constructed,
+						data:             EmvData::Normal(vec![0x00, 0x5A, 0x01, 0x47, 0xFF]),
+					},
+					child_block: RawEmvBlock {
+						nodes: vec![RawEmvNode {
+							tag:         RawEmvTag {
+								tag:              vec![0x5A],
+								class:            TagClass::Application,
+								data_object_type: DataObjectType::Primitive,
+								data:             EmvData::Normal(vec![0x47]),
+							},
+							child_block: RawEmvBlock::default(),
+						}],
+					},
+				}],
+			}),
+		);
+	}
+	#[test]
+	fn parse_padding_not_skipped() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = parse(
+			[0x9F, 0x09, 0x02, 0x00, 0x8C, 0xFF, 0xFF].as_slice(),
+			MaskingSettings::default(),
+			false,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_strict_valid() {
+		let expected = parse(
+			[0x70, 0x04, 0x5A, 0x02, 0x47, 0x61].as_slice(),
+			MaskingSettings::default(),
+			false,
+		);
+		let result = parse_strict(
+			[0x70, 0x04, 0x5A, 0x02, 0x47, 0x61].as_slice(),
+			MaskingSettings::default(),
+			false,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_strict_trailing_single_byte() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = parse_strict(
+			[0x9F, 0x09, 0x02, 0x00, 0x8C, 0x5A].as_slice(),
+			MaskingSettings::default(),
+			false,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_strict_trailing_truncated_tag() {
+		let expected = Err(ParseError::LengthExceedsData {
+			tag:       vec![0x5A],
+			offset:    5,
+			declared:  8,
+			available: 2,
+		});
+		let result = parse_strict(
+			[0x9F, 0x09, 0x02, 0x00, 0x8C, 0x5A, 0x08, 0x47, 0x61].as_slice(),
+			MaskingSettings::default(),
+			false,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_strict_trailing_padding() {
+		let bytes = [0x9F, 0x09, 0x02, 0x00, 0x8C, 0x00, 0x00].as_slice();
+
+		assert!(parse(bytes, MaskingSettings::default(), false).is_ok());
+		assert_eq!(
+			Err(ParseError::NonCompliant),
+			parse_strict(bytes, MaskingSettings::default(), false)
+		);
+		assert_eq!(
+			parse(bytes, MaskingSettings::default(), true),
+			parse_strict(bytes, MaskingSettings::default(), true)
+		);
+	}
+	#[test]
+	fn parse_strict_invalid_constructed_data() {
+		let bytes = [0x70, 0x03, 0x5A, 0x05, 0x47].as_slice();
+
+		assert!(parse(bytes, MaskingSettings::default(), false).is_ok());
+		assert_eq!(
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x5A],
+				offset:    2,
+				declared:  5,
+				available: 1,
+			}),
+			parse_strict(bytes, MaskingSettings::default(), false)
+		);
 	}
 	/// Some manufacturer-custom EMV tags indicate they're constructed but don't
 	/// actually store nested EMV data, which can cause problems if not properly
@@ -524,4 +1454,185 @@ mod tests {
 			}),
 		);
 	}
+
+	#[test]
+	fn encode_short_lengths() {
+		let expected = vec![
+			0x5A, 0x08, 0x47, 0x61, 0x73, 0x00, 0x00, 0x00, 0x01, 0x19, 0x5F, 0x34, 0x01, 0x01,
+		];
+		let result = encode(
+			[
+				(
+					vec![0x5A],
+					vec![0x47, 0x61, 0x73, 0x00, 0x00, 0x00, 0x01, 0x19],
+				),
+				(vec![0x5F, 0x34], vec![0x01]),
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn encode_empty_value() {
+		let expected = vec![0x9F, 0x02, 0x00];
+		let result = encode([(vec![0x9F, 0x02], vec![])].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn encode_one_byte_long_length() {
+		let expected = [0x9F, 0x10, 0x81, 0x80];
+		let result = encode([(vec![0x9F, 0x10], vec![0xAA; 0x80])].as_slice());
+
+		assert_eq!(expected.as_slice(), &result[0..4]);
+		assert_eq!(4 + 0x80, result.len());
+	}
+	#[test]
+	fn encode_two_byte_long_length() {
+		let expected = [0x70, 0x82, 0x01, 0x2C];
+		let result = encode([(vec![0x70], vec![0xAA; 300])].as_slice());
+
+		assert_eq!(expected.as_slice(), &result[0..4]);
+		assert_eq!(4 + 300, result.len());
+	}
+	#[test]
+	fn encode_round_trip() {
+		let tags = [
+			(
+				vec![0x5A],
+				vec![0x47, 0x61, 0x73, 0x00, 0x00, 0x00, 0x01, 0x19],
+			),
+			(vec![0x9F, 0x10], vec![0x06; 200]),
+		];
+		let expected = Ok(tags.to_vec());
+		let result = parse(
+			encode(tags.as_slice()).as_slice(),
+			MaskingSettings::default(),
+			true,
+		)
+		.map(|block| {
+			block
+				.nodes
+				.into_iter()
+				.map(|node| match node.tag.data {
+					EmvData::Normal(data) => (node.tag.tag, data),
+					EmvData::Masked => panic!("the testing data isn't masked"),
+				})
+				.collect::<Vec<_>>()
+		});
+
+		assert_eq!(expected, result);
+	}
+	fn test_encode_block_round_trip(bytes: &[u8]) {
+		let block =
+			parse(bytes, MaskingSettings::default(), true).expect("the testing data is valid");
+
+		let expected = Ok(bytes.to_vec());
+		let result = encode_block(&block);
+		assert_eq!(expected, result);
+
+		let expected = Ok(block);
+		let result = parse(
+			result.expect("the result was just checked").as_slice(),
+			MaskingSettings::default(),
+			true,
+		);
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn encode_block_round_trip_constructed() {
+		test_encode_block_round_trip(
+			[
+				0x6F, 0x09, 0x4F, 0x07, 0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10,
+			]
+			.as_slice(),
+		);
+	}
+	#[test]
+	fn encode_block_round_trip_multiple_tags() {
+		test_encode_block_round_trip(
+			[
+				0x4F, 0x07, 0xA0, 0x00, 0x00, 0x00, 0x04, 0x10, 0x10, 0x5F, 0x24, 0x03, 0x25, 0x12,
+				0x31, 0x6F, 0x07, 0x9F, 0x37, 0x04, 0x34, 0xE6, 0x2F, 0x92,
+			]
+			.as_slice(),
+		);
+	}
+	#[test]
+	fn encode_block_round_trip_3_byte_tag_name() {
+		test_encode_block_round_trip([0x9F, 0xA0, 0x20, 0x03, 0x22, 0x12, 0x31].as_slice());
+	}
+	#[test]
+	fn encode_block_round_trip_empty_tag_data() {
+		test_encode_block_round_trip([0x5F, 0x20, 0x00].as_slice());
+	}
+	#[test]
+	fn encode_block_round_trip_long_length() {
+		let mut bytes = vec![0x70, 0x81, 0x84, 0x9F, 0x10, 0x81, 0x80];
+		bytes.extend_from_slice(vec![0x06; 0x80].as_slice());
+
+		test_encode_block_round_trip(bytes.as_slice());
+	}
+	#[test]
+	fn encode_block_rebuilds_constructed_data() {
+		let mut block = parse(
+			[
+				0x6F, 0x09, 0x4F, 0x07, 0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10,
+			]
+			.as_slice(),
+			MaskingSettings::default(),
+			true,
+		)
+		.expect("the testing data is valid");
+		block.nodes[0].child_block.nodes[0].tag.data =
+			EmvData::Normal(vec![0xA0, 0x00, 0x00, 0x00, 0x03]);
+
+		let expected = Ok(vec![0x6F, 0x07, 0x4F, 0x05, 0xA0, 0x00, 0x00, 0x00, 0x03]);
+		let result = encode_block(&block);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn encode_block_masked() {
+		let block = parse(
+			[0x5F, 0x34, 0x02, 0x2A, 0x2A].as_slice(),
+			MaskingSettings::default(),
+			true,
+		)
+		.expect("the testing data is valid");
+
+		let expected = Err(ParseError::Unsupported);
+		let result = encode_block(&block);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_tag_value_list_valid() {
+		let expected = Ok(vec![
+			(
+				vec![0x5A],
+				vec![0x47, 0x61, 0x73, 0x00, 0x00, 0x00, 0x01, 0x19],
+			),
+			(vec![0x5F, 0x34], vec![0x01]),
+		]);
+		let result = parse_tag_value_list("5A=4761730000000119; 5f34=01;");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_tag_value_list_missing_separator() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = parse_tag_value_list("5A4761730000000119");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_tag_value_list_invalid_hex() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = parse_tag_value_list("5A=47617300000001ZZ");
+
+		assert_eq!(expected, result);
+	}
 }
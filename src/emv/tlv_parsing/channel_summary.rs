@@ -0,0 +1,155 @@
+//! A one-line summary of how a transaction was carried out, combining the tags
+//! that describe the card, the terminal, and how the card was read.
+
+// Uses
+use super::ProcessedEmvBlock;
+use crate::{
+	emv::{PosEntryMode, PosEntryModeField, TerminalType},
+	non_emv::Technology,
+};
+
+impl ProcessedEmvBlock {
+	/// Summarises the transaction channel, like "Contactless chip, chip card,
+	/// online-capable, merchant-attended".
+	///
+	/// The summary is built from the POS Entry Mode (`0x9F39`), the service
+	/// code (`0x5F30`, or from the Track 2 Equivalent Data in `0x57`), and the
+	/// Terminal Type (`0x9F35`). Any of them can be missing, but if all of them
+	/// are, there's nothing to summarise and `None` is returned.
+	///
+	/// The values are taken from the tags as they were already parsed, so tags
+	/// that are masked or couldn't be parsed are treated as missing.
+	pub fn summarise_channel(&self) -> Option<String> {
+		let mut parts = Vec::new();
+
+		if let Some(pos_entry_mode) = self.find_parsed::<PosEntryModeField>(&[0x9F, 0x39]) {
+			parts.push(entry_mode_summary(pos_entry_mode.entry_mode));
+		}
+
+		if let Some(service_code) = self.find_service_code() {
+			parts.push(match service_code.technology() {
+				Technology::IntegratedCircuitCard => "chip card",
+				Technology::MagneticStripeOnly => "magnetic stripe only card",
+			});
+		}
+
+		if let Some(terminal_type) = self.find_parsed::<TerminalType>(&[0x9F, 0x35]) {
+			parts.extend(terminal_type_summary(*terminal_type as u8));
+		}
+
+		if parts.is_empty() {
+			return None;
+		}
+
+		// Only the start of the summary is capitalised
+		let summary = parts.join(", ");
+		let mut chars = summary.chars();
+		chars
+			.next()
+			.map(|first| first.to_uppercase().chain(chars).collect())
+	}
+}
+
+/// A short description of how the card was read.
+fn entry_mode_summary(entry_mode: PosEntryMode) -> &'static str {
+	match entry_mode {
+		PosEntryMode::Unknown => "unknown entry mode",
+		PosEntryMode::Manual => "manual entry",
+		PosEntryMode::MagneticStripe | PosEntryMode::Track1 | PosEntryMode::MagneticStripeFull => {
+			"magnetic stripe"
+		}
+		PosEntryMode::Barcode => "barcode",
+		PosEntryMode::Ocr => "OCR",
+		PosEntryMode::ContactIcc | PosEntryMode::ContactIccUnreliable => "contact chip",
+		PosEntryMode::ContactlessIcc | PosEntryMode::ContactlessIccMappingApplied => {
+			"contactless chip"
+		}
+		PosEntryMode::ContactlessMagneticStripe => "contactless magnetic stripe",
+		PosEntryMode::EcommerceIncludingRemoteChip | PosEntryMode::EcommerceIncludingChip => {
+			"e-commerce"
+		}
+		PosEntryMode::CredentialsOnFile => "credentials on file",
+		PosEntryMode::Fallback => "fallback to magnetic stripe",
+		PosEntryMode::ViaServer => "via a server",
+	}
+}
+
+/// Short descriptions of the terminal's online capability and who operates
+/// it, from the two digits of the terminal type.
+///
+/// Information for this can be found in EMV Book 4, under section `A1`.
+fn terminal_type_summary(terminal_type: u8) -> Vec<&'static str> {
+	let operational_control = terminal_type >> 4;
+	let environment = terminal_type & 0b0000_1111;
+
+	let mut parts = Vec::with_capacity(2);
+	parts.extend(match environment {
+		1 | 4 => Some("online-only"),
+		2 | 5 => Some("online-capable"),
+		3 | 6 => Some("offline-only"),
+		_ => None,
+	});
+	let attended = matches!(environment, 1..=3);
+	parts.extend(match (operational_control, attended) {
+		(1, true) => Some("bank-attended"),
+		(1, false) => Some("bank-unattended"),
+		(2, true) => Some("merchant-attended"),
+		(2, false) => Some("merchant-unattended"),
+		(3, _) => Some("cardholder-operated"),
+		_ => None,
+	});
+	parts
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use crate::emv::{ber_tlv::parse as parse_ber_tlv, MaskingSettings, ProcessedEmvBlock};
+
+	// Tests
+	fn summarise_bytes(bytes: &[u8]) -> Option<String> {
+		ProcessedEmvBlock::try_from(
+			parse_ber_tlv(bytes, MaskingSettings::default(), true)
+				.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error")
+		.summarise_channel()
+	}
+
+	#[test]
+	fn contactless_merchant_attended() {
+		let expected =
+			Some("Contactless chip, chip card, online-capable, merchant-attended".to_owned());
+		let result = summarise_bytes(&[
+			0x9F, 0x39, 0x01, 0x07, 0x5F, 0x30, 0x02, 0x02, 0x01, 0x9F, 0x35, 0x01, 0x22,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn service_code_from_track_2() {
+		let expected =
+			Some("Magnetic stripe, chip card, online-only, cardholder-operated".to_owned());
+		let result = summarise_bytes(&[
+			0x9F, 0x39, 0x01, 0x90, 0x57, 0x0C, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+			0xD2, 0x51, 0x22, 0x01, 0x9F, 0x35, 0x01, 0x34,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn masked_tags_are_missing() {
+		let expected = Some("Online-capable, merchant-attended".to_owned());
+		let result = summarise_bytes(&[0x9F, 0x39, 0x02, 0x2A, 0x2A, 0x9F, 0x35, 0x01, 0x22]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn nothing_to_summarise() {
+		let expected = None;
+		let result = summarise_bytes(&[0x9F, 0x36, 0x02, 0x00, 0x01]);
+
+		assert_eq!(expected, result);
+	}
+}
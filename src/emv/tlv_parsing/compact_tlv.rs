@@ -0,0 +1,144 @@
+//! The module for compact-TLV parsing.
+//!
+//! Compact-TLV packs the tag and the length into a single byte, with the tag
+//! in the high nibble and the length in the low nibble. It's used in the
+//! historical bytes of the ATR (Answer to Reset) and in some proprietary data.
+//!
+//! Information for this can be found in ISO/IEC 7816-4, under section `8.1.1`.
+
+// Uses
+use super::{DataObjectType, EmvData, RawEmvBlock, RawEmvNode, RawEmvTag, TagClass};
+use crate::error::ParseError;
+
+/// Parses a block of compact-TLV encoded data.
+///
+/// Each tag is a single nibble, so [`RawEmvTag::tag`] holds a single byte with
+/// the tag number in the low nibble. Compact-TLV has no notion of tag classes
+/// or constructed data objects, so every tag is treated as a primitive,
+/// universal tag.
+pub fn parse(bytes: &[u8]) -> Result<RawEmvBlock, ParseError> {
+	if bytes.is_empty() {
+		return Err(ParseError::EmptyInput);
+	}
+
+	let bytes_len = bytes.len();
+	let mut nodes = Vec::new();
+	let mut index = 0;
+	while index < bytes_len {
+		// The tag and length share the first byte
+		let tag = bytes[index] >> 4;
+		let length = usize::from(bytes[index] & 0b0000_1111);
+		index += 1;
+		if index + length > bytes_len {
+			return Err(ParseError::LengthExceedsData {
+				tag:       vec![tag],
+				offset:    index - 1,
+				declared:  length,
+				available: bytes_len - index,
+			});
+		}
+
+		nodes.push(RawEmvNode {
+			tag:         RawEmvTag {
+				tag:              vec![tag],
+				class:            TagClass::Universal,
+				data_object_type: DataObjectType::Primitive,
+				data:             EmvData::Normal(bytes[index..(index + length)].to_vec()),
+			},
+			child_block: RawEmvBlock::default(),
+		});
+
+		index += length;
+	}
+
+	Ok(nodes.into())
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{
+		super::{DataObjectType, EmvData, RawEmvBlock, RawEmvNode, RawEmvTag, TagClass},
+		parse,
+	};
+	use crate::error::ParseError;
+
+	// Tests
+	#[allow(clippy::needless_pass_by_value)]
+	fn test_parse(data: &[u8], expected: Result<RawEmvBlock, ParseError>) {
+		let result = parse(data);
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_empty_data() {
+		test_parse(&[], Err(ParseError::EmptyInput));
+	}
+	#[test]
+	fn parse_single_tag() {
+		test_parse(
+			&[0x31, 0xC0],
+			Ok(RawEmvBlock {
+				nodes: vec![RawEmvNode {
+					tag:         RawEmvTag {
+						tag:              vec![0x03],
+						class:            TagClass::Universal,
+						data_object_type: DataObjectType::Primitive,
+						data:             EmvData::Normal(vec![0xC0]),
+					},
+					child_block: RawEmvBlock::default(),
+				}],
+			}),
+		);
+	}
+	#[test]
+	fn parse_multiple_tags() {
+		test_parse(
+			&[0x31, 0xC0, 0x73, 0x80, 0x21, 0x40, 0x50],
+			Ok(RawEmvBlock {
+				nodes: vec![
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x03],
+							class:            TagClass::Universal,
+							data_object_type: DataObjectType::Primitive,
+							data:             EmvData::Normal(vec![0xC0]),
+						},
+						child_block: RawEmvBlock::default(),
+					},
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x07],
+							class:            TagClass::Universal,
+							data_object_type: DataObjectType::Primitive,
+							data:             EmvData::Normal(vec![0x80, 0x21, 0x40]),
+						},
+						child_block: RawEmvBlock::default(),
+					},
+					RawEmvNode {
+						tag:         RawEmvTag {
+							tag:              vec![0x05],
+							class:            TagClass::Universal,
+							data_object_type: DataObjectType::Primitive,
+							data:             EmvData::Normal(vec![]),
+						},
+						child_block: RawEmvBlock::default(),
+					},
+				],
+			}),
+		);
+	}
+	#[test]
+	fn parse_length_exceeds_data() {
+		test_parse(
+			&[0x31, 0xC0, 0x73, 0x80],
+			Err(ParseError::LengthExceedsData {
+				tag:       vec![0x07],
+				offset:    2,
+				declared:  3,
+				available: 1,
+			}),
+		);
+	}
+}
@@ -0,0 +1,376 @@
+//! Consistency checks that span multiple tags in a block, which can't be done
+//! when parsing each tag on its own.
+
+// Uses
+use super::{EmvData, ProcessedEmvBlock};
+use crate::{
+	emv::{
+		Finding,
+		IssuerCodeTableIndex,
+		PosEntryMode,
+		PosEntryModeField,
+		Severity,
+		TerminalVerificationResults,
+		Track2EquivalentData,
+	},
+	non_emv::{format_minor_units, CurrencyCode, Technology},
+	util::bytes_to_str,
+};
+
+// Struct Implementation
+impl ProcessedEmvBlock {
+	/// Checks the tags in the block for consistency with each other.
+	///
+	/// Tags that are missing or masked are skipped, since there's nothing to
+	/// compare against.
+	pub fn cross_validate(&self) -> Vec<Finding> {
+		let mut findings = Vec::new();
+
+		let tvr = self.find_parsed::<TerminalVerificationResults>(&[0x95]);
+
+		self.check_application_version_numbers(tvr, &mut findings);
+		self.check_issuer_authentication(tvr, &mut findings);
+		self.check_expiry_dates(&mut findings);
+		self.check_service_code_technology(&mut findings);
+		self.check_amount(&mut findings);
+		self.check_application_preferred_name(&mut findings);
+
+		findings
+	}
+
+	/// Gets the data for the first tag with the provided name, if it's present
+	/// and unmasked.
+	pub(super) fn find_tag_data(&self, tag: &[u8]) -> Option<&[u8]> {
+		match &self.find_tag(tag)?.data {
+			EmvData::Normal(data) => Some(data.as_slice()),
+			EmvData::Masked => None,
+		}
+	}
+
+	/// The ICC (`0x9F08`) and terminal (`0x9F09`) application version numbers
+	/// should match, and when they don't the terminal sets the corresponding
+	/// TVR bit.
+	fn check_application_version_numbers(
+		&self,
+		tvr: Option<&TerminalVerificationResults>,
+		findings: &mut Vec<Finding>,
+	) {
+		let (Some(icc_version), Some(terminal_version)) = (
+			self.find_tag_data(&[0x9F, 0x08]),
+			self.find_tag_data(&[0x9F, 0x09]),
+		) else {
+			return;
+		};
+
+		if icc_version != terminal_version {
+			findings.push(Finding {
+				message:  format!(
+					"The ICC application version (v{}) differs from the terminal application \
+					 version (v{})",
+					bytes_to_str(icc_version),
+					bytes_to_str(terminal_version)
+				),
+				severity: Severity::Warning,
+			});
+		} else if tvr.is_some_and(|tvr| tvr.icc_terminal_version_mismatch) {
+			findings.push(Finding {
+				message:  "The TVR indicates that the ICC and terminal have different application \
+				           versions, but they match"
+					.to_owned(),
+				severity: Severity::Warning,
+			});
+		}
+	}
+
+	/// Issuer authentication can only fail if there was Issuer Authentication
+	/// Data (`0x91`) to authenticate.
+	fn check_issuer_authentication(
+		&self,
+		tvr: Option<&TerminalVerificationResults>,
+		findings: &mut Vec<Finding>,
+	) {
+		if tvr.is_some_and(|tvr| tvr.issuer_authentication_failed)
+			&& self.find_tag(&[0x91]).is_none()
+		{
+			findings.push(Finding {
+				message:  "The TVR indicates that issuer authentication failed, but there's no \
+				           Issuer Authentication Data (0x91)"
+					.to_owned(),
+				severity: Severity::Warning,
+			});
+		}
+	}
+
+	/// The Application Expiration Date (`0x5F24`) should match the expiry date
+	/// in the Track 2 Equivalent Data (`0x57`).
+	fn check_expiry_dates(&self, findings: &mut Vec<Finding>) {
+		let (Some(expiry_date), Some(track_2)) = (
+			self.find_tag_data(&[0x5F, 0x24]),
+			self.find_parsed::<Track2EquivalentData>(&[0x57]),
+		) else {
+			return;
+		};
+		// The expiry date is `YYMMDD`, but Track 2 only has `YYMM`
+		if expiry_date.len() < 2 {
+			return;
+		}
+
+		let expiry_year_month = bytes_to_str(&expiry_date[0..2]);
+		let track_2_year_month = format!("{:0>2}{:0>2}", track_2.expiry_year, track_2.expiry_month);
+		if expiry_year_month != track_2_year_month {
+			findings.push(Finding {
+				message:  format!(
+					"The application expiration date ({}/{}) differs from the Track 2 expiry date \
+					 ({}/{})",
+					&expiry_year_month[0..2],
+					&expiry_year_month[2..4],
+					&track_2_year_month[0..2],
+					&track_2_year_month[2..4]
+				),
+				severity: Severity::Error,
+			});
+		}
+	}
+
+	/// A chip card read with the magnetic stripe, without the POS Entry Mode
+	/// (`0x9F39`) indicating a fallback, can be a sign of a skimmed card.
+	fn check_service_code_technology(&self, findings: &mut Vec<Finding>) {
+		let (Some(pos_entry_mode), Some(service_code)) = (
+			self.find_parsed::<PosEntryModeField>(&[0x9F, 0x39])
+				.map(|field| field.entry_mode),
+			self.find_service_code(),
+		) else {
+			return;
+		};
+
+		let read_with_magnetic_stripe = matches!(
+			pos_entry_mode,
+			PosEntryMode::MagneticStripe | PosEntryMode::Track1 | PosEntryMode::MagneticStripeFull
+		);
+		if service_code.technology() == Technology::IntegratedCircuitCard
+			&& read_with_magnetic_stripe
+		{
+			findings.push(Finding {
+				message:  format!(
+					"The service code indicates a chip card, but the POS entry mode is \
+					 \"{pos_entry_mode}\" without indicating a fallback"
+				),
+				severity: Severity::Warning,
+			});
+		}
+	}
+
+	/// The Amount, Authorised (`0x9F02`) is in the minor unit of the currency,
+	/// so it's only readable once it's correlated with the currency exponent
+	/// (`0x5F36`, or `0x9F44` otherwise) and the Transaction Currency Code
+	/// (`0x5F2A`).
+	fn check_amount(&self, findings: &mut Vec<Finding>) {
+		let (Some(amount), Some(exponent)) = (
+			self.find_tag_data(&[0x9F, 0x02])
+				.and_then(|data| bytes_to_str(data).parse::<u64>().ok()),
+			self.find_tag_data(&[0x5F, 0x36])
+				.or_else(|| self.find_tag_data(&[0x9F, 0x44]))
+				.and_then(|data| bytes_to_str(data).parse::<u8>().ok()),
+		) else {
+			return;
+		};
+
+		let currency = self
+			.find_tag_data(&[0x5F, 0x2A])
+			.and_then(|data| CurrencyCode::try_from(data).ok());
+		let formatted_amount = match currency {
+			Some(currency) => currency.format_amount(amount, exponent),
+			None => format_minor_units(amount, exponent),
+		};
+
+		findings.push(Finding {
+			message:  format!("Amount: {formatted_amount}"),
+			severity: Severity::Normal,
+		});
+	}
+
+	/// The Application Preferred Name (`0x9F12`) is encoded with the ISO/IEC
+	/// 8859 part given by the Issuer Code Table Index (`0x9F11`), so it can
+	/// only be decoded properly when both are present.
+	fn check_application_preferred_name(&self, findings: &mut Vec<Finding>) {
+		let (Some(code_table_index), Some(preferred_name)) = (
+			self.find_parsed::<IssuerCodeTableIndex>(&[0x9F, 0x11]),
+			self.find_tag_data(&[0x9F, 0x12]),
+		) else {
+			return;
+		};
+
+		findings.push(Finding {
+			message:  format!(
+				"Application Preferred Name (ISO/IEC 8859-{}): {}",
+				code_table_index.index,
+				code_table_index.decode(preferred_name)
+			),
+			severity: Severity::Normal,
+		});
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use crate::{
+		emv::{Finding, MaskingSettings, Severity},
+		parse_ber_tlv,
+		ProcessedEmvBlock,
+	};
+
+	// Tests
+	fn cross_validate_bytes(bytes: &[u8]) -> Vec<Finding> {
+		ProcessedEmvBlock::try_from(
+			parse_ber_tlv(bytes, MaskingSettings::default(), true)
+				.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error")
+		.cross_validate()
+	}
+
+	#[test]
+	fn application_version_numbers_match() {
+		let expected = Vec::<Finding>::new();
+		let result =
+			cross_validate_bytes(&[0x9F, 0x08, 0x02, 0x00, 0x8C, 0x9F, 0x09, 0x02, 0x00, 0x8C]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn application_version_numbers_differ() {
+		let expected = vec![Finding {
+			message:  "The ICC application version (v0002) differs from the terminal application \
+			           version (v008C)"
+				.to_owned(),
+			severity: Severity::Warning,
+		}];
+		let result =
+			cross_validate_bytes(&[0x9F, 0x08, 0x02, 0x00, 0x02, 0x9F, 0x09, 0x02, 0x00, 0x8C]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn application_version_numbers_match_tvr_mismatch() {
+		let expected = vec![Finding {
+			message:  "The TVR indicates that the ICC and terminal have different application \
+			           versions, but they match"
+				.to_owned(),
+			severity: Severity::Warning,
+		}];
+		let result = cross_validate_bytes(&[
+			0x95, 0x05, 0x00, 0x80, 0x00, 0x00, 0x00, 0x9F, 0x08, 0x02, 0x00, 0x8C, 0x9F, 0x09,
+			0x02, 0x00, 0x8C,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn issuer_authentication_failed_without_data() {
+		let expected = vec![Finding {
+			message:  "The TVR indicates that issuer authentication failed, but there's no Issuer \
+			           Authentication Data (0x91)"
+				.to_owned(),
+			severity: Severity::Warning,
+		}];
+		let result = cross_validate_bytes(&[0x95, 0x05, 0x00, 0x00, 0x00, 0x00, 0x40]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn issuer_authentication_failed_with_data() {
+		let expected = Vec::<Finding>::new();
+		let result = cross_validate_bytes(&[
+			0x95, 0x05, 0x00, 0x00, 0x00, 0x00, 0x40, 0x91, 0x02, 0x12, 0x34,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn expiry_dates_differ() {
+		let expected = vec![Finding {
+			message:  "The application expiration date (26/12) differs from the Track 2 expiry \
+			           date (25/12)"
+				.to_owned(),
+			severity: Severity::Error,
+		}];
+		let result = cross_validate_bytes(&[
+			0x57, 0x0F, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x22, 0x01,
+			0x00, 0x00, 0x00, 0x5F, 0x24, 0x03, 0x26, 0x12, 0x31,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn chip_service_code_read_with_magnetic_stripe() {
+		let expected = vec![Finding {
+			message:  "The service code indicates a chip card, but the POS entry mode is \
+			           \"Magnetic Stripe Reader (MSR)\" without indicating a fallback"
+				.to_owned(),
+			severity: Severity::Warning,
+		}];
+		let result = cross_validate_bytes(&[0x5F, 0x30, 0x02, 0x02, 0x01, 0x9F, 0x39, 0x01, 0x02]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn chip_service_code_with_fallback() {
+		let expected = Vec::<Finding>::new();
+		let result = cross_validate_bytes(&[
+			0x57, 0x0F, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x22, 0x01,
+			0x00, 0x00, 0x00, 0x9F, 0x39, 0x01, 0x80,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn amount_with_currency() {
+		let expected = vec![Finding {
+			message:  "Amount: $123.45 USD".to_owned(),
+			severity: Severity::Normal,
+		}];
+		let result = cross_validate_bytes(&[
+			0x9F, 0x02, 0x06, 0x00, 0x00, 0x00, 0x01, 0x23, 0x45, 0x5F, 0x2A, 0x02, 0x08, 0x40,
+			0x5F, 0x36, 0x01, 0x02,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn amount_with_application_currency_exponent() {
+		let expected = vec![Finding {
+			message:  "Amount: 1234.5".to_owned(),
+			severity: Severity::Normal,
+		}];
+		let result = cross_validate_bytes(&[
+			0x9F, 0x02, 0x06, 0x00, 0x00, 0x00, 0x01, 0x23, 0x45, 0x9F, 0x44, 0x01, 0x01,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn amount_without_exponent() {
+		let expected = Vec::<Finding>::new();
+		let result = cross_validate_bytes(&[
+			0x9F, 0x02, 0x06, 0x00, 0x00, 0x00, 0x01, 0x23, 0x45, 0x5F, 0x2A, 0x02, 0x08, 0x40,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn application_preferred_name_decoded() {
+		let expected = vec![Finding {
+			message:  "Application Preferred Name (ISO/IEC 8859-7): \
+			           \u{39A}\u{3AC}\u{3C1}\u{3C4}\u{3B1}"
+				.to_owned(),
+			severity: Severity::Normal,
+		}];
+		let result = cross_validate_bytes(&[
+			0x9F, 0x11, 0x01, 0x07, 0x9F, 0x12, 0x05, 0xCA, 0xDC, 0xF1, 0xF4, 0xE1,
+		]);
+
+		assert_eq!(expected, result);
+	}
+}
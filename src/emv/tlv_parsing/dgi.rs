@@ -0,0 +1,293 @@
+//! The module for DGI (Data Grouping Identifier) parsing.
+//!
+//! Card personalisation data is grouped into DGIs, each of which is a 2-byte
+//! identifier followed by a length and the data. The length is a single byte,
+//! unless it's `0xFF`, in which case the length is in the 2 bytes that follow.
+//!
+//! Information for this can be found in the EMV Card Personalization
+//! Specification, under section `2.3`.
+
+// Uses
+use std::ops::RangeInclusive;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::{ber_tlv::parse_strict as parse_ber_tlv_strict, MaskingSettings, ProcessedEmvBlock};
+use crate::{emv::ShortFileIdentifier, error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::header_colour_spec,
+	util::{print_bytes, print_indentation},
+};
+
+// Constants
+/// The number of bytes in a DGI.
+const DGI_BYTES: usize = 2;
+/// The length byte that indicates the length is in the 2 bytes that follow.
+const EXTENDED_LENGTH: u8 = 0xFF;
+/// The range of DGIs that hold encrypted data, like keys and PINs, which can't
+/// be parsed as TLV data.
+const ENCRYPTED_DGI_RANGE: RangeInclusive<u16> = 0x8000..=0x8FFF;
+
+// Struct Implementation
+/// A block of DGI-formatted personalisation data.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct DgiBlock {
+	pub entries: Vec<DgiEntry>,
+}
+
+/// A single DGI and its data.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DgiEntry {
+	pub dgi:      u16,
+	pub data:     Vec<u8>,
+	/// The data parsed as BER-TLV, if it's in that format.
+	pub tlv_data: Option<ProcessedEmvBlock>,
+}
+
+impl DgiEntry {
+	/// Returns the SFI and record number if the DGI refers to a record, which
+	/// is the case when the first byte is a valid SFI.
+	pub fn record(&self) -> Option<(ShortFileIdentifier, u8)> {
+		let [sfi, record_number] = self.dgi.to_be_bytes();
+		if record_number == 0 {
+			return None;
+		}
+
+		ShortFileIdentifier::try_from(sfi)
+			.ok()
+			.map(|sfi| (sfi, record_number))
+	}
+}
+
+/// Parses a block of DGI-formatted data.
+///
+/// The data of each DGI is parsed as BER-TLV if it's entirely valid BER-TLV,
+/// except for DGIs in the range `8000` to `8FFF`, which hold encrypted data.
+pub fn parse(bytes: &[u8], masking: MaskingSettings) -> Result<DgiBlock, ParseError> {
+	if bytes.is_empty() {
+		return Err(ParseError::EmptyInput);
+	}
+
+	let bytes_len = bytes.len();
+	let mut entries = Vec::new();
+	let mut index = 0;
+	while index < bytes_len {
+		// The DGI itself, which has to be followed by at least one length byte
+		if index + DGI_BYTES >= bytes_len {
+			return Err(ParseError::NonCompliant);
+		}
+		let dgi_index = index;
+		let dgi = u16::from_be_bytes([bytes[index], bytes[index + 1]]);
+		index += DGI_BYTES;
+
+		// The length is next
+		let length = if bytes[index] == EXTENDED_LENGTH {
+			index += 3;
+			if index > bytes_len {
+				return Err(ParseError::NonCompliant);
+			}
+			usize::from(u16::from_be_bytes([bytes[index - 2], bytes[index - 1]]))
+		} else {
+			index += 1;
+			usize::from(bytes[index - 1])
+		};
+		if index + length > bytes_len {
+			return Err(ParseError::LengthExceedsData {
+				tag:       dgi.to_be_bytes().to_vec(),
+				offset:    dgi_index,
+				declared:  length,
+				available: bytes_len - index,
+			});
+		}
+
+		// The data, which may be TLV-encoded
+		let data = &bytes[index..(index + length)];
+		let tlv_data = if data.is_empty() || ENCRYPTED_DGI_RANGE.contains(&dgi) {
+			None
+		} else {
+			parse_ber_tlv_strict(data, masking, false)
+				.and_then(ProcessedEmvBlock::try_from)
+				.ok()
+		};
+		entries.push(DgiEntry {
+			dgi,
+			data: data.to_vec(),
+			tlv_data,
+		});
+
+		index += length;
+	}
+
+	Ok(DgiBlock { entries })
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for DgiBlock {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let mut first = true;
+		for entry in &self.entries {
+			if first {
+				first = false;
+			} else {
+				writeln!(stdout).ok();
+			}
+			entry.display_breakdown(stdout, indentation, show_severity_colours);
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::Array(
+			self.entries
+				.iter()
+				.map(DisplayBreakdown::to_json_value)
+				.collect(),
+		)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for DgiEntry {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let header_colour_spec = header_colour_spec();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "DGI:").ok();
+		stdout.reset().ok();
+		write!(stdout, " {:04X} - {} bytes", self.dgi, self.data.len()).ok();
+		match self.record() {
+			Some((sfi, record_number)) => {
+				writeln!(stdout, " - Record {record_number}, SFI {}", sfi.sfi).ok()
+			}
+			None => writeln!(stdout).ok(),
+		};
+
+		if !self.data.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Data:").ok();
+			stdout.reset().ok();
+			print_bytes(stdout, self.data.as_slice(), 16, indentation + 1);
+		}
+		if let Some(tlv_data) = &self.tlv_data {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Parsed Data:").ok();
+			stdout.reset().ok();
+			tlv_data.display_breakdown(stdout, indentation + 1, show_severity_colours);
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"dgi": format!("{:04X}", self.dgi),
+			"record": self.record().map(|(sfi, record_number)| json!({
+				"sfi": sfi.sfi,
+				"record_number": record_number,
+			})),
+			"data": bytes_to_str(self.data.as_slice()),
+			"tlv_data": self.tlv_data.as_ref().map(DisplayBreakdown::to_json_value),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{parse, DgiBlock, DgiEntry};
+	use crate::{emv::MaskingSettings, error::ParseError};
+
+	// Tests
+	fn parse_bytes(bytes: &[u8]) -> Result<DgiBlock, ParseError> {
+		parse(bytes, MaskingSettings::default())
+	}
+
+	#[test]
+	fn parse_empty_data() {
+		let expected = Err(ParseError::EmptyInput);
+		let result = parse_bytes(&[]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_record_with_tlv_data() {
+		let expected = Ok((0x0102, 7, true));
+		let result = parse_bytes(&[0x01, 0x02, 0x07, 0x70, 0x05, 0x5F, 0x25, 0x02, 0x25, 0x01])
+			.map(|block| {
+				let entry = &block.entries[0];
+				(entry.dgi, entry.data.len(), entry.tlv_data.is_some())
+			});
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_encrypted_data_not_parsed_as_tlv() {
+		let expected = Ok(DgiBlock {
+			entries: vec![DgiEntry {
+				dgi:      0x8000,
+				data:     vec![0x9F, 0x36, 0x02, 0x00, 0x01],
+				tlv_data: None,
+			}],
+		});
+		let result = parse_bytes(&[0x80, 0x00, 0x05, 0x9F, 0x36, 0x02, 0x00, 0x01]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_extended_length() {
+		let expected = Ok(vec![(0x9102, 3), (0x0202, 0)]);
+		let result = parse_bytes(&[
+			0x91, 0x02, 0xFF, 0x00, 0x03, 0x01, 0x02, 0x03, 0x02, 0x02, 0x00,
+		])
+		.map(|block| {
+			block
+				.entries
+				.iter()
+				.map(|entry| (entry.dgi, entry.data.len()))
+				.collect::<Vec<_>>()
+		});
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_length_exceeds_data() {
+		let expected = Err(ParseError::LengthExceedsData {
+			tag:       vec![0x01, 0x01],
+			offset:    0,
+			declared:  16,
+			available: 2,
+		});
+		let result = parse_bytes(&[0x01, 0x01, 0x10, 0x70, 0x00]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn record_reference() {
+		let expected = Some((2, 3));
+		let result = DgiEntry {
+			dgi:      0x0203,
+			data:     vec![],
+			tlv_data: None,
+		}
+		.record()
+		.map(|(sfi, record_number)| (sfi.sfi, record_number));
+
+		assert_eq!(expected, result);
+	}
+}
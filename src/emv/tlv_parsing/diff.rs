@@ -0,0 +1,343 @@
+//! Compares two blocks of TLV data, to find which tags were added, removed, or
+//! changed between them.
+//!
+//! Tags are matched by their path, which includes the tags of any constructed
+//! tags they're nested inside. If a tag appears multiple times at the same
+//! path, the occurrences are matched up in the order they appear.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::{EmvData, RawEmvBlock, RawEmvNode};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{added_colour_spec, changed_colour_spec, removed_colour_spec},
+	util::print_indentation,
+};
+use crate::{util::bytes_to_str, DisplayBreakdown};
+
+// Struct Implementation
+/// The differences between two blocks of TLV data.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct BlockDiff {
+	pub changes: Vec<TagChange>,
+}
+
+/// A single difference between two blocks of TLV data.
+///
+/// The `path` is the list of tag names leading to the tag, with the tag itself
+/// last.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum TagChange {
+	Added {
+		path: Vec<Vec<u8>>,
+		data: EmvData,
+	},
+	Removed {
+		path: Vec<Vec<u8>>,
+		data: EmvData,
+	},
+	Changed {
+		path: Vec<Vec<u8>>,
+		old:  EmvData,
+		new:  EmvData,
+	},
+}
+
+impl BlockDiff {
+	/// Returns whether the two blocks were identical.
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+}
+
+impl TagChange {
+	pub fn path(&self) -> &[Vec<u8>] {
+		match self {
+			TagChange::Added { path, .. }
+			| TagChange::Removed { path, .. }
+			| TagChange::Changed { path, .. } => path.as_slice(),
+		}
+	}
+}
+
+/// Compares two blocks of TLV data.
+///
+/// Changes are listed in the order of the old block, with any added tags after
+/// the rest.
+pub fn compare(old: &RawEmvBlock, new: &RawEmvBlock) -> BlockDiff {
+	let mut changes = Vec::new();
+	compare_level(old, new, &mut Vec::new(), &mut changes);
+
+	BlockDiff { changes }
+}
+
+/// Compares a single level of nesting, recursing into constructed tags that are
+/// present in both blocks.
+fn compare_level(
+	old: &RawEmvBlock,
+	new: &RawEmvBlock,
+	parent_path: &mut Vec<Vec<u8>>,
+	changes: &mut Vec<TagChange>,
+) {
+	let mut matched_new = vec![false; new.nodes.len()];
+
+	for (old_index, old_node) in old.nodes.iter().enumerate() {
+		let tag = &old_node.tag.tag;
+		let occurrence = occurrence_index(old.nodes.as_slice(), old_index);
+		let new_index = new
+			.nodes
+			.iter()
+			.enumerate()
+			.filter(|(_, new_node)| new_node.tag.tag == *tag)
+			.nth(occurrence)
+			.map(|(new_index, _)| new_index);
+
+		parent_path.push(tag.clone());
+		match new_index {
+			Some(new_index) => {
+				matched_new[new_index] = true;
+				let new_node = &new.nodes[new_index];
+				if old_node.child_block.nodes.is_empty() || new_node.child_block.nodes.is_empty() {
+					if old_node.tag.data != new_node.tag.data {
+						changes.push(TagChange::Changed {
+							path: parent_path.clone(),
+							old:  old_node.tag.data.clone(),
+							new:  new_node.tag.data.clone(),
+						});
+					}
+				} else {
+					compare_level(
+						&old_node.child_block,
+						&new_node.child_block,
+						parent_path,
+						changes,
+					);
+				}
+			}
+			None => changes.push(TagChange::Removed {
+				path: parent_path.clone(),
+				data: old_node.tag.data.clone(),
+			}),
+		}
+		parent_path.pop();
+	}
+
+	for (new_node, _) in new
+		.nodes
+		.iter()
+		.zip(matched_new)
+		.filter(|(_, matched)| !matched)
+	{
+		let mut path = parent_path.clone();
+		path.push(new_node.tag.tag.clone());
+		changes.push(TagChange::Added {
+			path,
+			data: new_node.tag.data.clone(),
+		});
+	}
+}
+
+/// Finds how many nodes with the same tag come before the node at `index`.
+fn occurrence_index(nodes: &[RawEmvNode], index: usize) -> usize {
+	nodes[..index]
+		.iter()
+		.filter(|node| node.tag.tag == nodes[index].tag.tag)
+		.count()
+}
+
+fn path_to_str(path: &[Vec<u8>]) -> String {
+	path.iter()
+		.map(|tag| bytes_to_str(tag.as_slice()))
+		.collect::<Vec<_>>()
+		.join(" > ")
+}
+
+fn data_to_json(data: &EmvData) -> JsonValue {
+	match data {
+		EmvData::Normal(data) => JsonValue::String(bytes_to_str(data.as_slice())),
+		EmvData::Masked => JsonValue::Null,
+	}
+}
+
+#[cfg(feature = "display")]
+fn print_data(stdout: &mut dyn WriteColor, data: &EmvData) {
+	match data {
+		EmvData::Normal(data) => writeln!(stdout, "{}", bytes_to_str(data.as_slice())).ok(),
+		EmvData::Masked => writeln!(stdout, "* Masked *").ok(),
+	};
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for BlockDiff {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		if self.changes.is_empty() {
+			print_indentation(stdout, indentation);
+			writeln!(stdout, "No differences").ok();
+			return;
+		}
+
+		for change in &self.changes {
+			change.display_breakdown(stdout, indentation, show_severity_colours);
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::Array(
+			self.changes
+				.iter()
+				.map(DisplayBreakdown::to_json_value)
+				.collect(),
+		)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for TagChange {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let path_str = path_to_str(self.path());
+
+		print_indentation(stdout, indentation);
+		match self {
+			TagChange::Added { data, .. } => {
+				stdout
+					.set_color(&added_colour_spec(show_severity_colours))
+					.ok();
+				write!(stdout, "+ {path_str}: ").ok();
+				print_data(stdout, data);
+				stdout.reset().ok();
+			}
+			TagChange::Removed { data, .. } => {
+				stdout
+					.set_color(&removed_colour_spec(show_severity_colours))
+					.ok();
+				write!(stdout, "- {path_str}: ").ok();
+				print_data(stdout, data);
+				stdout.reset().ok();
+			}
+			TagChange::Changed { old, new, .. } => {
+				stdout
+					.set_color(&changed_colour_spec(show_severity_colours))
+					.ok();
+				writeln!(stdout, "~ {path_str}").ok();
+				stdout.reset().ok();
+				print_indentation(stdout, indentation + 1);
+				write!(stdout, "Old: ").ok();
+				print_data(stdout, old);
+				print_indentation(stdout, indentation + 1);
+				write!(stdout, "New: ").ok();
+				print_data(stdout, new);
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		let path = self
+			.path()
+			.iter()
+			.map(|tag| bytes_to_str(tag.as_slice()))
+			.collect::<Vec<_>>();
+		match self {
+			TagChange::Added { data, .. } => json!({
+				"change": "added",
+				"path": path,
+				"data": data_to_json(data),
+			}),
+			TagChange::Removed { data, .. } => json!({
+				"change": "removed",
+				"path": path,
+				"data": data_to_json(data),
+			}),
+			TagChange::Changed { old, new, .. } => json!({
+				"change": "changed",
+				"path": path,
+				"old": data_to_json(old),
+				"new": data_to_json(new),
+			}),
+		}
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{compare, BlockDiff, TagChange};
+	use crate::{
+		emv::{EmvData, MaskingSettings},
+		parse_ber_tlv,
+	};
+
+	// Tests
+	#[test]
+	fn added_removed_and_changed() {
+		let expected = BlockDiff {
+			changes: vec![
+				TagChange::Changed {
+					path: vec![vec![0x70], vec![0x95]],
+					old:  EmvData::Normal(vec![0x00, 0x80, 0x00, 0x80, 0x00]),
+					new:  EmvData::Normal(vec![0x00, 0x00, 0x00, 0x80, 0x00]),
+				},
+				TagChange::Removed {
+					path: vec![vec![0x9F, 0x09]],
+					data: EmvData::Normal(vec![0x00, 0x8C]),
+				},
+				TagChange::Added {
+					path: vec![vec![0x5F, 0x2A]],
+					data: EmvData::Normal(vec![0x01, 0x24]),
+				},
+			],
+		};
+		let old = parse_ber_tlv(
+			[
+				0x70, 0x07, 0x95, 0x05, 0x00, 0x80, 0x00, 0x80, 0x00, 0x9F, 0x09, 0x02, 0x00, 0x8C,
+				0x82, 0x02, 0x19, 0x80,
+			]
+			.as_slice(),
+			MaskingSettings::default(),
+			true,
+		)
+		.expect("any errors should already be tested by the BER-TLV testing");
+		let new = parse_ber_tlv(
+			[
+				0x70, 0x07, 0x95, 0x05, 0x00, 0x00, 0x00, 0x80, 0x00, 0x82, 0x02, 0x19, 0x80, 0x5F,
+				0x2A, 0x02, 0x01, 0x24,
+			]
+			.as_slice(),
+			MaskingSettings::default(),
+			true,
+		)
+		.expect("any errors should already be tested by the BER-TLV testing");
+		let result = compare(&old, &new);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn identical_blocks() {
+		let block = parse_ber_tlv(
+			[0x9F, 0x09, 0x02, 0x00, 0x8C, 0x9F, 0x09, 0x02, 0x00, 0x8D].as_slice(),
+			MaskingSettings::default(),
+			true,
+		)
+		.expect("any errors should already be tested by the BER-TLV testing");
+		let result = compare(&block, &block);
+
+		assert!(result.is_empty());
+	}
+}
@@ -5,13 +5,14 @@ use super::{
 	ber_tlv::{get_child_block, parse_tag_metadata},
 	is_masked_str,
 	EmvData,
+	MaskingSettings,
 	RawEmvBlock,
 	RawEmvNode,
 	RawEmvTag,
 };
 use crate::{
 	error::ParseError,
-	util::{byte_slice_to_u32, parse_hex_str_strict, trim_leading_0_bytes, BYTES_PER_32_BITS},
+	util::{byte_slice_to_u64, parse_hex_str_strict, trim_leading_0_bytes},
 };
 
 // Constants
@@ -25,7 +26,11 @@ const TAG_TYPE_EMV: char = 'T';
 /// Note: this function expects the field separator (FS) between tags to already
 /// be replaced by another character, though it doesn't matter what that
 /// character is.
-pub fn parse(data: &str, masking_characters: &[char]) -> Result<RawEmvBlock, ParseError> {
+pub fn parse(data: &str, masking: MaskingSettings) -> Result<RawEmvBlock, ParseError> {
+	if data.trim().is_empty() {
+		return Err(ParseError::EmptyInput);
+	}
+
 	// The input data should only be ASCII - no Unicode data is expected.
 	if !data.is_ascii() {
 		return Err(ParseError::NonCompliant);
@@ -64,14 +69,12 @@ pub fn parse(data: &str, masking_characters: &[char]) -> Result<RawEmvBlock, Par
 			None => return Err(ParseError::NonCompliant),
 		};
 		let length_str = &data[index..colon_index];
-		// Tag lengths greater than the maximum unsigned 32-bit integer value are
-		// unsupported
+		// Tag lengths greater than the maximum unsigned 64-bit integer value, or that
+		// don't fit in memory on this platform, are unsupported
 		let length_bytes =
 			parse_hex_str_strict(length_str).map_err(|_| ParseError::NonCompliant)?;
-		if length_bytes.len() > BYTES_PER_32_BITS {
-			return Err(ParseError::Unsupported);
-		}
-		let length = byte_slice_to_u32(length_bytes.as_slice()) as usize;
+		let length = usize::try_from(byte_slice_to_u64(length_bytes.as_slice())?)
+			.map_err(|_| ParseError::Unsupported)?;
 		index = colon_index + 1;
 		if index >= data_len {
 			return Err(ParseError::NonCompliant);
@@ -82,12 +85,12 @@ pub fn parse(data: &str, masking_characters: &[char]) -> Result<RawEmvBlock, Par
 		let tag_data = match data_format {
 			DATA_FORMAT_ASCII => {
 				index += 1;
-				if index + length > data_len {
+				if length > data_len - index {
 					return Err(ParseError::NonCompliant);
 				}
 				let tag_data_str = &data[index..(index + length)];
 				index += length;
-				if is_masked_str(tag_data_str, masking_characters) {
+				if is_masked_str(tag_data_str, masking.characters, masking.mode) {
 					EmvData::Masked
 				} else {
 					EmvData::Normal(tag_data_str.as_bytes().to_vec())
@@ -97,14 +100,14 @@ pub fn parse(data: &str, masking_characters: &[char]) -> Result<RawEmvBlock, Par
 				// The length value we got above is the number of actual bytes of data, but the
 				// ASCII hex representation is twice that. (2 characters to represent 1 byte in
 				// hex)
-				let char_length = length * 2;
+				let char_length = length.checked_mul(2).ok_or(ParseError::NonCompliant)?;
 				index += 1;
-				if index + char_length > data_len {
+				if char_length > data_len - index {
 					return Err(ParseError::NonCompliant);
 				}
 				let tag_data_str = &data[index..(index + char_length)];
 				index += char_length;
-				if is_masked_str(tag_data_str, masking_characters) {
+				if is_masked_str(tag_data_str, masking.characters, masking.mode) {
 					EmvData::Masked
 				} else {
 					EmvData::Normal(
@@ -125,7 +128,7 @@ pub fn parse(data: &str, masking_characters: &[char]) -> Result<RawEmvBlock, Par
 			continue;
 		}
 		nodes.push(RawEmvNode {
-			child_block: get_child_block(data_object_type, &tag_data, masking_characters),
+			child_block: get_child_block(data_object_type, &tag_data, masking, true, false)?,
 			tag:         RawEmvTag {
 				tag: tag_id_bytes,
 				class,
@@ -143,7 +146,15 @@ pub fn parse(data: &str, masking_characters: &[char]) -> Result<RawEmvBlock, Par
 mod tests {
 	// Uses
 	use super::{
-		super::{DataObjectType, EmvData, RawEmvBlock, RawEmvNode, RawEmvTag, TagClass},
+		super::{
+			DataObjectType,
+			EmvData,
+			MaskingSettings,
+			RawEmvBlock,
+			RawEmvNode,
+			RawEmvTag,
+			TagClass,
+		},
 		parse,
 	};
 	use crate::error::ParseError;
@@ -151,13 +162,13 @@ mod tests {
 	// Tests
 	#[allow(clippy::needless_pass_by_value)]
 	fn test_parse(data: &str, expected: Result<RawEmvBlock, ParseError>) {
-		let result = parse(data, ['*'].as_slice());
+		let result = parse(data, MaskingSettings::default());
 		assert_eq!(expected, result);
 	}
 
 	#[test]
 	fn parse_empty_data() {
-		test_parse("", Ok(RawEmvBlock { nodes: vec![] }));
+		test_parse("", Err(ParseError::EmptyInput));
 	}
 	#[test]
 	fn parse_single_byte_primitive_unmasked_hex_tag() {
@@ -550,7 +561,7 @@ mod tests {
 	fn parse_too_long_tag_data() {
 		// This input data is actually invalid, but here we're just testing that the
 		// number of bytes indicated is correctly identified as unsupported
-		test_parse("T91:FFFFFFFFFF:h", Err(ParseError::Unsupported));
+		test_parse("T91:FFFFFFFFFFFFFFFFFF:h", Err(ParseError::Unsupported));
 	}
 	/// Only ASCII input data is supported for this function.
 	#[test]
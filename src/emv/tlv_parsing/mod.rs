@@ -6,26 +6,75 @@
 // Modules
 pub mod auto_tlv;
 pub mod ber_tlv;
+mod channel_summary;
+pub mod compact_tlv;
+mod cross_validation;
+pub mod dgi;
+pub mod diff;
 pub mod ingenico_tlv;
 mod process_emv_tag;
 
 // Uses
 use std::{
+	any::Any,
 	cmp::Ordering,
 	fmt::{Display, Formatter, Result as FormatResult},
 };
 
-use termcolor::{ColorSpec, StandardStream, WriteColor};
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::{ColorSpec, WriteColor};
 
-pub use self::process_emv_tag::identify_tag;
 use self::process_emv_tag::process_emv_tag;
+pub use self::process_emv_tag::{
+	all_tag_definitions,
+	find_tag_definition,
+	has_dedicated_parser,
+	identify_tag,
+	set_custom_tags,
+	CustomTag,
+	ParserKind,
+	TagDefinition,
+	KNOWN_TAGS,
+};
 use crate::{
+	emv::{SeverityCounts, Track2EquivalentData},
 	enum_repr_fallible,
 	error::ParseError,
-	output_colours::{bold_colour_spec, header_colour_spec},
-	util::{print_bytes_pretty, print_bytes_small, print_indentation},
+	non_emv::ServiceCode,
+	util::bytes_to_str,
 	DisplayBreakdown,
 };
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{bold_colour_spec, header_colour_spec, severity_colour_spec},
+	util::{print_bytes_pretty, print_bytes_small, print_indentation},
+};
+
+/// The settings for displaying processed tags, beyond what
+/// [`DisplayBreakdown::display_breakdown`] supports.
+#[cfg(feature = "display")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DisplaySettings<'a> {
+	/// Whether to show the raw value of each tag.
+	pub show_raw_values: bool,
+	/// Whether to show the offset of each line of bytes in the raw values.
+	pub show_offsets:    bool,
+	/// If provided, each tag is shown with the path of tags it's nested inside,
+	/// like `6F > A5 > 88`. It should be empty for the top-level block.
+	pub ancestors:       Option<&'a str>,
+}
+
+#[cfg(feature = "display")]
+impl Default for DisplaySettings<'_> {
+	fn default() -> Self {
+		Self {
+			show_raw_values: true,
+			show_offsets:    false,
+			ancestors:       None,
+		}
+	}
+}
 
 /// A processed block of EMV data with annotations and parsing results.
 #[derive(Debug, Eq, PartialEq)]
@@ -54,34 +103,220 @@ impl Default for ProcessedEmvBlock {
 }
 
 impl ProcessedEmvBlock {
+	/// Sorts the nodes by their tag names, including the nodes inside
+	/// constructed tags.
+	///
+	/// The sort is stable, so duplicate tags keep the order they were provided
+	/// in.
 	pub fn sort_nodes(&mut self) {
 		self.nodes.sort();
 		for node in &mut self.nodes {
 			node.child_block.sort_nodes();
 		}
 	}
+
+	/// Prunes the block down to only the nodes with one of the provided tags.
+	///
+	/// Nodes that don't match but have a matching descendant are kept so that
+	/// the nested matches still have context. Matching constructed nodes keep
+	/// all of their children.
+	pub fn retain_tags(&mut self, tags: &[Vec<u8>]) {
+		self.nodes.retain_mut(|node| {
+			if tags.contains(&node.tag.get_raw_tag().tag) {
+				return true;
+			}
+			node.child_block.retain_tags(tags);
+			!node.child_block.nodes.is_empty()
+		});
+	}
+
+	/// Counts the notable bits of every parsed tag, including the tags inside
+	/// constructed tags.
+	pub fn count_notable_bits(&self) -> SeverityCounts {
+		let mut counts = SeverityCounts::default();
+		self.tally_notable_bits(&mut counts);
+		counts
+	}
+
+	fn tally_notable_bits(&self, counts: &mut SeverityCounts) {
+		for node in &self.nodes {
+			if let ProcessedEmvTag::Parsed { parsed, .. } = &node.tag {
+				for notable_bit in parsed.get_notable_bits() {
+					counts.add(notable_bit.severity);
+				}
+			}
+			node.child_block.tally_notable_bits(counts);
+		}
+	}
+
+	/// Finds the first tag with the provided name, searching nested blocks
+	/// depth-first.
+	pub fn find_tag(&self, tag: &[u8]) -> Option<&RawEmvTag> {
+		self.nodes.iter().find_map(|node| {
+			let raw_tag = node.tag.get_raw_tag();
+			if raw_tag.tag == tag {
+				Some(raw_tag)
+			} else {
+				node.child_block.find_tag(tag)
+			}
+		})
+	}
+
+	/// Finds the parsed value of the first tag with the provided name,
+	/// searching nested blocks depth-first.
+	///
+	/// Returns `None` if the tag isn't present, or if its value wasn't parsed
+	/// into a `T`, like if it's masked.
+	pub fn find_parsed<T>(&self, tag: &[u8]) -> Option<&T>
+	where
+		T: DisplayBreakdown,
+	{
+		self.nodes.iter().find_map(|node| {
+			if node.tag.get_raw_tag().tag != tag {
+				return node.child_block.find_parsed(tag);
+			}
+			if let ProcessedEmvTag::Parsed { parsed, .. } = &node.tag {
+				(parsed.as_ref() as &dyn Any).downcast_ref::<T>()
+			} else {
+				None
+			}
+		})
+	}
+
+	/// Finds the parsed service code, from `0x5F30` if it's present or the
+	/// Track 2 Equivalent Data (`0x57`) otherwise.
+	fn find_service_code(&self) -> Option<&ServiceCode> {
+		self.find_parsed::<ServiceCode>(&[0x5F, 0x30]).or_else(|| {
+			self.find_parsed::<Track2EquivalentData>(&[0x57])
+				.map(|track_2| &track_2.service_code)
+		})
+	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl DisplayBreakdown for ProcessedEmvBlock {
-	fn display_breakdown(
+impl ProcessedEmvBlock {
+	/// The same as [`DisplayBreakdown::display_breakdown`], but with the
+	/// options in [`DisplaySettings`].
+	#[cfg(feature = "display")]
+	pub fn display_breakdown_showing_raw(
 		&self,
-		stdout: &mut StandardStream,
+		stdout: &mut dyn WriteColor,
 		indentation: u8,
 		show_severity_colours: bool,
+		settings: DisplaySettings,
 	) {
 		let mut first = true;
 		for node in &self.nodes {
 			if first {
 				first = false;
 			} else {
-				println!();
+				writeln!(stdout).ok();
 			}
-			node.display_breakdown(stdout, indentation, show_severity_colours);
+			node.display_breakdown_showing_raw(
+				stdout,
+				indentation,
+				show_severity_colours,
+				settings,
+			);
 		}
 	}
 }
 
+#[cfg(feature = "display")]
+impl ProcessedEmvBlock {
+	/// Displays only the tags with notable bits, along with those bits.
+	///
+	/// Tags inside constructed tags are displayed at the same level as the
+	/// rest, since the point is to quickly see what went wrong.
+	pub fn display_notable_bits(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		self.display_notable_bits_inner(stdout, indentation, show_severity_colours, &mut true);
+	}
+
+	fn display_notable_bits_inner(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+		first: &mut bool,
+	) {
+		for node in &self.nodes {
+			if let ProcessedEmvTag::Parsed {
+				name,
+				parsed,
+				raw_tag,
+			} = &node.tag
+			{
+				let notable_bits = parsed.get_notable_bits();
+				if !notable_bits.is_empty() {
+					if *first {
+						*first = false;
+					} else {
+						writeln!(stdout).ok();
+					}
+					print_tag_name(
+						stdout,
+						indentation,
+						&header_colour_spec(),
+						raw_tag.tag.as_slice(),
+						raw_tag.data.len(),
+						Some(name),
+						None,
+					);
+					for notable_bit in notable_bits {
+						print_indentation(stdout, indentation + 1);
+						stdout
+							.set_color(&severity_colour_spec(
+								notable_bit.severity,
+								show_severity_colours,
+							))
+							.ok();
+						writeln!(stdout, "{}: {}", notable_bit.severity, notable_bit.message).ok();
+						stdout.reset().ok();
+					}
+				}
+			}
+			node.child_block.display_notable_bits_inner(
+				stdout,
+				indentation,
+				show_severity_colours,
+				first,
+			);
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ProcessedEmvBlock {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		self.display_breakdown_showing_raw(
+			stdout,
+			indentation,
+			show_severity_colours,
+			DisplaySettings::default(),
+		);
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::Array(
+			self.nodes
+				.iter()
+				.map(DisplayBreakdown::to_json_value)
+				.collect(),
+		)
+	}
+}
+
 impl TryFrom<RawEmvBlock> for ProcessedEmvBlock {
 	type Error = ParseError;
 
@@ -116,32 +351,83 @@ impl PartialOrd for ProcessedEmvNode {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl DisplayBreakdown for ProcessedEmvNode {
-	fn display_breakdown(
+impl ProcessedEmvNode {
+	/// The same as [`DisplayBreakdown::display_breakdown`], but with the
+	/// options in [`DisplaySettings`].
+	#[cfg(feature = "display")]
+	pub fn display_breakdown_showing_raw(
 		&self,
-		stdout: &mut StandardStream,
+		stdout: &mut dyn WriteColor,
 		indentation: u8,
 		show_severity_colours: bool,
+		settings: DisplaySettings,
 	) {
-		// Display the tag
-		self.tag
-			.display_breakdown(stdout, indentation, show_severity_colours);
+		// Display the tag, leaving out the raw value of constructed tags with child
+		// tags since the children already show all of it
+		self.tag.display_breakdown_showing_raw(
+			stdout,
+			indentation,
+			show_severity_colours,
+			DisplaySettings {
+				show_raw_values: settings.show_raw_values && self.child_block.nodes.is_empty(),
+				..settings
+			},
+		);
 
 		// Display the child tags (if any)
 		if !self.child_block.nodes.is_empty() {
 			let header_colour_spec = header_colour_spec();
 
-			print_indentation(indentation);
+			print_indentation(stdout, indentation);
 			stdout.set_color(&header_colour_spec).ok();
-			println!("Constructed Data Object's Child Tags:");
+			writeln!(stdout, "Constructed Data Object's Child Tags:").ok();
 			stdout.reset().ok();
 
-			self.child_block
-				.display_breakdown(stdout, indentation + 1, show_severity_colours);
+			let path = settings.ancestors.map(|ancestors| {
+				let tag = bytes_to_str(self.tag.get_raw_tag().tag.as_slice());
+				if ancestors.is_empty() {
+					tag
+				} else {
+					format!("{ancestors} > {tag}")
+				}
+			});
+			self.child_block.display_breakdown_showing_raw(
+				stdout,
+				indentation + 1,
+				show_severity_colours,
+				DisplaySettings {
+					ancestors: path.as_deref(),
+					..settings
+				},
+			);
 		}
 	}
 }
 
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ProcessedEmvNode {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		self.display_breakdown_showing_raw(
+			stdout,
+			indentation,
+			show_severity_colours,
+			DisplaySettings::default(),
+		);
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		let mut value = self.tag.to_json_value();
+		value["children"] = self.child_block.to_json_value();
+		value
+	}
+}
+
 impl TryFrom<RawEmvNode> for ProcessedEmvNode {
 	type Error = ParseError;
 
@@ -273,45 +559,17 @@ impl ProcessedEmvTag {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl DisplayBreakdown for ProcessedEmvTag {
-	fn display_breakdown(
+impl ProcessedEmvTag {
+	/// The same as [`DisplayBreakdown::display_breakdown`], but with the
+	/// options in [`DisplaySettings`].
+	#[cfg(feature = "display")]
+	pub fn display_breakdown_showing_raw(
 		&self,
-		stdout: &mut StandardStream,
+		stdout: &mut dyn WriteColor,
 		indentation: u8,
 		show_severity_colours: bool,
+		settings: DisplaySettings,
 	) {
-		fn print_tag_name(
-			stdout: &mut StandardStream,
-			indentation: u8,
-			header_colour_spec: &ColorSpec,
-			tag: &[u8],
-			length: Option<usize>,
-			name_option: Option<&str>,
-		) {
-			let bold_colour_spec = bold_colour_spec();
-
-			let name = name_option.unwrap_or("<Unknown>");
-
-			print_indentation(indentation);
-			stdout.set_color(header_colour_spec).ok();
-			print!("Tag:");
-			stdout.reset().ok();
-			print!(" 0x");
-			stdout.set_color(&bold_colour_spec).ok();
-			print_bytes_small(tag);
-			stdout.reset().ok();
-			if let Some(len) = length {
-				println!(
-					" - {} byte{} - {}",
-					len,
-					if len == 1 { "" } else { "s" },
-					name
-				);
-			} else {
-				println!(" - ?? bytes - {name}");
-			}
-		}
-
 		let header_colour_spec = header_colour_spec();
 
 		match self {
@@ -324,10 +582,17 @@ impl DisplayBreakdown for ProcessedEmvTag {
 					raw_tag.tag.as_slice(),
 					raw_tag.data.len(),
 					None,
+					settings.ancestors,
 				);
 
 				// Display the raw value
-				raw_tag.display_breakdown(stdout, indentation, show_severity_colours);
+				if settings.show_raw_values {
+					raw_tag.display_breakdown_with_offsets(
+						stdout,
+						indentation,
+						settings.show_offsets,
+					);
+				}
 			}
 			ProcessedEmvTag::Annotated { name, raw_tag } => {
 				// Display the tag name
@@ -338,10 +603,17 @@ impl DisplayBreakdown for ProcessedEmvTag {
 					raw_tag.tag.as_slice(),
 					raw_tag.data.len(),
 					Some(name),
+					settings.ancestors,
 				);
 
 				// Display the raw value
-				raw_tag.display_breakdown(stdout, indentation, show_severity_colours);
+				if settings.show_raw_values {
+					raw_tag.display_breakdown_with_offsets(
+						stdout,
+						indentation,
+						settings.show_offsets,
+					);
+				}
 			}
 			ProcessedEmvTag::Parsed {
 				name,
@@ -356,15 +628,22 @@ impl DisplayBreakdown for ProcessedEmvTag {
 					raw_tag.tag.as_slice(),
 					raw_tag.data.len(),
 					Some(name),
+					settings.ancestors,
 				);
 
 				// Display the raw value
-				raw_tag.display_breakdown(stdout, indentation, show_severity_colours);
+				if settings.show_raw_values {
+					raw_tag.display_breakdown_with_offsets(
+						stdout,
+						indentation,
+						settings.show_offsets,
+					);
+				}
 
 				// Display the parsed value
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				println!("Parsed:");
+				writeln!(stdout, "Parsed:").ok();
 				stdout.reset().ok();
 				parsed.display_breakdown(stdout, indentation + 1, show_severity_colours);
 			}
@@ -372,6 +651,41 @@ impl DisplayBreakdown for ProcessedEmvTag {
 	}
 }
 
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ProcessedEmvTag {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		self.display_breakdown_showing_raw(
+			stdout,
+			indentation,
+			show_severity_colours,
+			DisplaySettings::default(),
+		);
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		let mut value = self.get_raw_tag().to_json_value();
+		match self {
+			ProcessedEmvTag::Raw { .. } => {
+				value["name"] = JsonValue::Null;
+			}
+			ProcessedEmvTag::Annotated { name, .. } => {
+				value["name"] = json!(name);
+			}
+			ProcessedEmvTag::Parsed { name, parsed, .. } => {
+				value["name"] = json!(name);
+				value["parsed"] = parsed.to_json_value();
+			}
+		}
+		value
+	}
+}
+
 impl TryFrom<RawEmvTag> for ProcessedEmvTag {
 	type Error = ParseError;
 
@@ -380,7 +694,54 @@ impl TryFrom<RawEmvTag> for ProcessedEmvTag {
 	}
 }
 
+/// Displays the header line for a tag, with its name and length.
+///
+/// If `ancestors` is provided, the tag is shown at the end of the path of tags
+/// it's nested inside.
+#[cfg(not(tarpaulin_include))]
+#[cfg(feature = "display")]
+fn print_tag_name(
+	stdout: &mut dyn WriteColor,
+	indentation: u8,
+	header_colour_spec: &ColorSpec,
+	tag: &[u8],
+	length: Option<usize>,
+	name_option: Option<&str>,
+	ancestors: Option<&str>,
+) {
+	let bold_colour_spec = bold_colour_spec();
+
+	let name = name_option.unwrap_or("<Unknown>");
+
+	print_indentation(stdout, indentation);
+	stdout.set_color(header_colour_spec).ok();
+	write!(stdout, "Tag:").ok();
+	stdout.reset().ok();
+	// With the path shown, the tag is the last part of it
+	match ancestors {
+		Some("") => write!(stdout, " ").ok(),
+		Some(ancestors) => write!(stdout, " {ancestors} > ").ok(),
+		None => write!(stdout, " 0x").ok(),
+	};
+	stdout.set_color(&bold_colour_spec).ok();
+	print_bytes_small(stdout, tag);
+	stdout.reset().ok();
+	if let Some(len) = length {
+		writeln!(
+			stdout,
+			" - {} byte{} - {}",
+			len,
+			if len == 1 { "" } else { "s" },
+			name
+		)
+		.ok();
+	} else {
+		writeln!(stdout, " - ?? bytes - {name}").ok();
+	}
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct RawEmvBlock {
 	pub nodes: Vec<RawEmvNode>,
 }
@@ -406,6 +767,7 @@ impl Default for RawEmvBlock {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct RawEmvNode {
 	pub tag:         RawEmvTag,
 	pub child_block: RawEmvBlock,
@@ -415,6 +777,7 @@ pub struct RawEmvNode {
 ///
 /// This can be further parsed based on the tag value.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct RawEmvTag {
 	pub tag:              Vec<u8>,
 	pub class:            TagClass,
@@ -437,8 +800,16 @@ impl PartialOrd for RawEmvTag {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl DisplayBreakdown for RawEmvTag {
-	fn display_breakdown(&self, stdout: &mut StandardStream, indentation: u8, _: bool) {
+impl RawEmvTag {
+	/// The same as [`DisplayBreakdown::display_breakdown`], but with the option
+	/// to show the offset of each line of bytes in the value.
+	#[cfg(feature = "display")]
+	pub fn display_breakdown_with_offsets(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_offsets: bool,
+	) {
 		let header_colour_spec = header_colour_spec();
 		match &self.data {
 			EmvData::Normal(data) => {
@@ -447,22 +818,44 @@ impl DisplayBreakdown for RawEmvTag {
 				}
 
 				// Display the tag value
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				println!("Raw:");
+				writeln!(stdout, "Raw:").ok();
 				stdout.reset().ok();
-				print_bytes_pretty(data.as_slice(), 16, indentation + 1);
+				print_bytes_pretty(stdout, data.as_slice(), 16, indentation + 1, show_offsets);
 			}
 			EmvData::Masked => {
-				print_indentation(indentation);
+				print_indentation(stdout, indentation);
 				stdout.set_color(&header_colour_spec).ok();
-				println!("* Masked *");
+				writeln!(stdout, "* Masked *").ok();
 				stdout.reset().ok();
 			}
 		}
 	}
 }
 
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for RawEmvTag {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		self.display_breakdown_with_offsets(stdout, indentation, false);
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"tag": bytes_to_str(self.tag.as_slice()),
+			"class": self.class.to_string(),
+			"data_object_type": self.data_object_type.to_string(),
+			"length": self.data.len(),
+			"masked": self.data == EmvData::Masked,
+			"data": match &self.data {
+				EmvData::Normal(data) => Some(bytes_to_str(data.as_slice())),
+				EmvData::Masked => None,
+			},
+		})
+	}
+}
+
 enum_repr_fallible! {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TagClass: u8, ParseError, { |_| ParseError::NonCompliant } {
@@ -474,6 +867,7 @@ pub enum TagClass: u8, ParseError, { |_| ParseError::NonCompliant } {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum DataObjectType {
 	Primitive,
 	Constructed,
@@ -492,6 +886,7 @@ impl Display for DataObjectType {
 /// EMV data, encoding the ability for data to be masked and therefore
 /// inaccessible.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum EmvData {
 	Normal(Vec<u8>),
 	Masked,
@@ -507,8 +902,14 @@ impl EmvData {
 		}
 	}
 
-	pub fn from_u8_check_for_masked(data: Vec<u8>, masking_characters: &[char]) -> Self {
-		if is_masked_u8(data.as_slice(), masking_characters) {
+	/// Returns whether the data is empty, or `None` if unknown.
+	#[cfg(not(tarpaulin_include))]
+	pub fn is_empty(&self) -> Option<bool> {
+		self.len().map(|len| len == 0)
+	}
+
+	pub fn from_u8_check_for_masked(data: Vec<u8>, masking: MaskingSettings) -> Self {
+		if is_masked_u8(data.as_slice(), masking) {
 			Self::Masked
 		} else {
 			Self::Normal(data)
@@ -518,38 +919,117 @@ impl EmvData {
 
 pub const MASKING_CHAR_MINIMUM: usize = 2;
 
-pub fn is_masked_u8(data: &[u8], masking_characters: &[char]) -> bool {
-	if data.len() < MASKING_CHAR_MINIMUM {
-		return false;
-	}
+/// How strictly data has to match the masking characters to be considered
+/// masked.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum MaskingMode {
+	/// The data is masked if every character is the same masking character.
+	#[default]
+	AllSame,
+	/// The data is masked if it contains a run of masking characters, in any
+	/// combination. This recognises partially-masked data, like a PAN that
+	/// keeps its first and last digits.
+	AnyConfiguredChar,
+}
 
-	for masking_char in masking_characters {
-		if data.iter().all(|byte| *byte as char == *masking_char) {
-			return true;
+/// The settings that decide whether data is treated as masked.
+///
+/// The default is to treat data as masked if it's entirely made up of `*`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MaskingSettings<'a> {
+	/// The characters that masked data is made up of.
+	pub characters:       &'a [char],
+	/// How strictly data has to match `characters` to be considered masked.
+	pub mode:             MaskingMode,
+	/// Whether a value that's only 1 byte long can be considered masked. See
+	/// [`is_masked_u8`] for the trade-off.
+	pub mask_single_byte: bool,
+}
+
+impl Default for MaskingSettings<'_> {
+	fn default() -> Self {
+		Self {
+			characters:       &['*'],
+			mode:             MaskingMode::default(),
+			mask_single_byte: false,
 		}
 	}
+}
+
+impl MaskingMode {
+	/// Checks whether the characters of some data are masked, with `minimum`
+	/// being the shortest run of masking characters that counts.
+	fn is_masked<I>(self, data: I, masking_characters: &[char], minimum: usize) -> bool
+	where
+		I: Iterator<Item = char> + Clone,
+	{
+		match self {
+			Self::AllSame => masking_characters
+				.iter()
+				.any(|masking_char| data.clone().all(|c| c == *masking_char)),
+			Self::AnyConfiguredChar => {
+				let mut run_length = 0;
+				for c in data {
+					if masking_characters.contains(&c) {
+						run_length += 1;
+						if run_length >= minimum {
+							return true;
+						}
+					} else {
+						run_length = 0;
+					}
+				}
 
-	false
+				false
+			}
+		}
+	}
 }
 
-pub fn is_masked_str(data: &str, masking_characters: &[char]) -> bool {
-	if data.len() < MASKING_CHAR_MINIMUM {
+/// Checks whether some binary data is masked.
+///
+/// Each byte is compared as the character with the same value, so masking
+/// bytes that aren't printable, like `0xFF`, can be provided with
+/// [`char::from`].
+///
+/// By default it takes [`MASKING_CHAR_MINIMUM`] bytes, since a single byte
+/// like `0x2A` is just as likely to be genuine data as a masked `*`. If
+/// [`MaskingSettings::mask_single_byte`] is set, a value that's only 1 byte
+/// long is considered masked if it matches a masking character, at the cost of
+/// hiding genuine 1-byte values that happen to match one. Longer values aren't
+/// affected.
+pub fn is_masked_u8(data: &[u8], masking: MaskingSettings) -> bool {
+	let minimum = if masking.mask_single_byte && data.len() == 1 {
+		1
+	} else {
+		MASKING_CHAR_MINIMUM
+	};
+	if data.len() < minimum {
 		return false;
 	}
 
-	for masking_char in masking_characters {
-		if data.chars().all(|c| c == *masking_char) {
-			return true;
-		}
+	masking.mode.is_masked(
+		data.iter().map(|byte| *byte as char),
+		masking.characters,
+		minimum,
+	)
+}
+
+pub fn is_masked_str(data: &str, masking_characters: &[char], masking_mode: MaskingMode) -> bool {
+	if data.len() < MASKING_CHAR_MINIMUM {
+		return false;
 	}
 
-	false
+	masking_mode.is_masked(data.chars(), masking_characters, MASKING_CHAR_MINIMUM)
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum TlvFormat {
 	BerTlv,
 	Ingenico,
+	Compact,
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -558,6 +1038,7 @@ impl Display for TlvFormat {
 		f.write_str(match self {
 			TlvFormat::BerTlv => "BER-TLV",
 			TlvFormat::Ingenico => "Ingenico",
+			TlvFormat::Compact => "Compact-TLV",
 		})
 	}
 }
@@ -566,24 +1047,209 @@ impl Display for TlvFormat {
 #[cfg(test)]
 mod tests {
 	// Uses
+	#[cfg(feature = "display")]
+	use super::DisplaySettings;
 	use super::{
 		ber_tlv::parse as parse_ber_tlv,
 		is_masked_str,
 		is_masked_u8,
 		DataObjectType,
 		EmvData,
+		MaskingMode,
+		MaskingSettings,
 		ProcessedEmvBlock,
 		ProcessedEmvTag,
 		RawEmvTag,
 		TagClass,
 	};
 	use crate::{
-		emv::{AuthorisationResponseCode, TransactionStatusInformation},
+		display_breakdown_matches,
+		emv::{AuthorisationResponseCode, SeverityCounts, TransactionStatusInformation},
 		error::ParseError,
 		DisplayBreakdown,
 	};
 
 	// Tests
+	display_breakdown_matches!(
+		display_breakdown_nested_block,
+		ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[
+					0x70, 0x0C, 0x95, 0x05, 0x00, 0x80, 0x00, 0x80, 0x00, 0x9F, 0x36, 0x02, 0x00,
+					0x1F, 0x5F, 0x2A, 0x02, 0x01, 0x24,
+				]
+				.as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error"),
+		concat!(
+			"Tag: 0x70 - 12 bytes - READ RECORD Response Message Template\n",
+			"Constructed Data Object's Child Tags:\n",
+			"\tTag: 0x95 - 5 bytes - Terminal Verification Results (TVR)\n",
+			"\tRaw:\n",
+			"\t\t00 80 00 80 00                                   .....\n",
+			"\tParsed:\n",
+			"\t\t00000000 10000000 00000000 10000000 00000000\n",
+			"\t\t         │                 └ Transaction exceeds floor limit\n",
+			"\t\t         └ ICC and terminal have different application versions\n",
+			"\n",
+			"\tTag: 0x9F36 - 2 bytes - Application Transaction Counter (ATC)\n",
+			"\tRaw:\n",
+			"\t\t00 1F                                            ..\n",
+			"\n",
+			"Tag: 0x5F2A - 2 bytes - Transaction Currency Code\n",
+			"Raw:\n",
+			"\t01 24                                            .$\n",
+		)
+	);
+
+	#[cfg(feature = "display")]
+	#[test]
+	fn display_raw_value_with_offsets() {
+		let raw_tag = RawEmvTag {
+			tag:              vec![0x9F, 0x10],
+			class:            TagClass::ContextSpecific,
+			data_object_type: DataObjectType::Primitive,
+			data:             EmvData::Normal((0x30..=0x41).collect()),
+		};
+		let mut buffer = termcolor::Buffer::no_color();
+		raw_tag.display_breakdown_with_offsets(&mut buffer, 0, true);
+
+		let expected = concat!(
+			"Raw:\n",
+			"\t0000  30 31 32 33 34 35 36 37 38 39 3A 3B 3C 3D 3E 3F  0123456789:;<=>?\n",
+			"\t0010  40 41                                            @A\n",
+		);
+		let result = String::from_utf8_lossy(buffer.as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[cfg(feature = "display")]
+	#[test]
+	fn display_breakdown_with_path() {
+		let block = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[
+					0x6F, 0x11, 0x84, 0x05, 0xA0, 0x00, 0x00, 0x00, 0x03, 0xA5, 0x08, 0xBF, 0x0C,
+					0x05, 0x9F, 0x4D, 0x02, 0x0B, 0x0A,
+				]
+				.as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error");
+		let mut buffer = termcolor::Buffer::no_color();
+		block.display_breakdown_showing_raw(
+			&mut buffer,
+			0,
+			false,
+			DisplaySettings {
+				show_raw_values: false,
+				show_offsets:    false,
+				ancestors:       Some(""),
+			},
+		);
+
+		let expected = concat!(
+			"Tag: 6F - 17 bytes - File Control Information (FCI) Template\n",
+			"Constructed Data Object's Child Tags:\n",
+			"\tTag: 6F > 84 - 5 bytes - Dedicated File (DF) Name\n",
+			"\tParsed:\n",
+			"\t\tRID: A000000003 - Visa\n",
+			"\n",
+			"\tTag: 6F > A5 - 8 bytes - File Control Information (FCI) Proprietary Template\n",
+			"\tConstructed Data Object's Child Tags:\n",
+			"\t\tTag: 6F > A5 > BF0C - 5 bytes - File Control Information (FCI) Issuer \
+			 Discretionary Data\n",
+			"\t\tConstructed Data Object's Child Tags:\n",
+			"\t\t\tTag: 6F > A5 > BF0C > 9F4D - 2 bytes - Log Entry\n",
+			"\t\t\tParsed:\n",
+			"\t\t\t\tSFI: 11\n",
+			"\t\t\t\tMaximum Records: 10\n",
+		);
+		let result = String::from_utf8_lossy(buffer.as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	/// Processes BER-TLV data and displays it in the same way as `--quiet`,
+	/// with colours disabled.
+	#[cfg(feature = "display")]
+	fn display_notable_bits_of(bytes: &[u8]) -> String {
+		let block = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(bytes, MaskingSettings::default(), true)
+				.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error");
+
+		let mut buffer = termcolor::Buffer::no_color();
+		block.display_notable_bits(&mut buffer, 0, false);
+
+		String::from_utf8_lossy(buffer.as_slice()).into_owned()
+	}
+
+	#[cfg(feature = "display")]
+	#[test]
+	fn display_notable_bits_normal_only() {
+		let expected = "";
+		let result = display_notable_bits_of(&[0x95, 0x05, 0x80, 0x00, 0x00, 0x80, 0x00]);
+
+		assert_eq!(expected, result);
+	}
+	#[cfg(feature = "display")]
+	#[test]
+	fn display_notable_bits_with_error() {
+		let expected = concat!(
+			"Tag: 0x95 - 5 bytes - Terminal Verification Results (TVR)\n",
+			"\tError: SDA (Static Data Authentication) failed\n",
+		);
+		let result = display_notable_bits_of(&[0x95, 0x05, 0xC0, 0x00, 0x00, 0x80, 0x00]);
+
+		assert_eq!(expected, result);
+	}
+	#[cfg(feature = "display")]
+	#[test]
+	fn display_notable_bits_nested() {
+		let expected = concat!(
+			"Tag: 0x95 - 5 bytes - Terminal Verification Results (TVR)\n",
+			"\tWarning: ICC and terminal have different application versions\n",
+		);
+		let result = display_notable_bits_of(&[
+			0x70, 0x0C, 0x95, 0x05, 0x00, 0x80, 0x00, 0x80, 0x00, 0x9F, 0x36, 0x02, 0x00, 0x1F,
+		]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn count_notable_bits() {
+		let expected = SeverityCounts {
+			warnings: 1,
+			errors:   1,
+		};
+		let result = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[
+					0x70, 0x07, 0x95, 0x05, 0x40, 0x80, 0x00, 0x80, 0x00, 0x95, 0x05, 0x00, 0x00,
+					0x00, 0x80, 0x00,
+				]
+				.as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error")
+		.count_notable_bits();
+
+		assert_eq!(expected, result);
+	}
+
 	#[test]
 	fn sort_nodes() {
 		// This test just tests the sorting, so we don't care about manually defining
@@ -595,7 +1261,8 @@ mod tests {
 					0x09, 0x02, 0x00, 0x8C,
 				]
 				.as_slice(),
-				['*'].as_slice(),
+				MaskingSettings::default(),
+				true,
 			)
 			.expect("any errors should already be tested by the BER-TLV testing"),
 		)
@@ -607,7 +1274,8 @@ mod tests {
 					0x03, 0x2A, 0x2A, 0x2A,
 				]
 				.as_slice(),
-				['*'].as_slice(),
+				MaskingSettings::default(),
+				true,
 			)
 			.expect("any errors should already be tested by the BER-TLV testing"),
 		)
@@ -617,6 +1285,74 @@ mod tests {
 		assert_eq!(expected, result);
 	}
 	#[test]
+	fn sort_nodes_nested_and_duplicates() {
+		fn collect_tags(block: &ProcessedEmvBlock, tags: &mut Vec<(Vec<u8>, Option<usize>)>) {
+			for node in &block.nodes {
+				let raw_tag = node.tag.get_raw_tag();
+				tags.push((raw_tag.tag.clone(), raw_tag.data.len()));
+				collect_tags(&node.child_block, tags);
+			}
+		}
+
+		// The duplicate `0xDF01` tags are told apart by their lengths
+		let expected = vec![
+			(vec![0x70], Some(6)),
+			(vec![0xC1], Some(1)),
+			(vec![0xC2], Some(1)),
+			(vec![0xDF, 0x01], Some(2)),
+			(vec![0xDF, 0x01], Some(1)),
+		];
+		let mut block = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[
+					0xDF, 0x01, 0x02, 0x00, 0x8C, 0xDF, 0x01, 0x01, 0x02, 0x70, 0x06, 0xC2, 0x01,
+					0x00, 0xC1, 0x01, 0x47,
+				]
+				.as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error");
+		block.sort_nodes();
+		let mut result = Vec::new();
+		collect_tags(&block, &mut result);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn retain_tags() {
+		fn collect_tags(block: &ProcessedEmvBlock, depth: u8, tags: &mut Vec<(u8, Vec<u8>)>) {
+			for node in &block.nodes {
+				tags.push((depth, node.tag.get_raw_tag().tag.clone()));
+				collect_tags(&node.child_block, depth + 1, tags);
+			}
+		}
+
+		// The matching primitive tag inside the template keeps the template, but the
+		// empty constructed tag beside it is pruned
+		let expected = vec![(0, vec![0x70]), (1, vec![0x95]), (0, vec![0x9F, 0x09])];
+		let mut block = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[
+					0x70, 0x09, 0x95, 0x05, 0x00, 0x80, 0x00, 0x80, 0x00, 0xA5, 0x00, 0x9F, 0x09,
+					0x02, 0x00, 0x8C, 0x9F, 0x08, 0x02, 0x00, 0x8C,
+				]
+				.as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.expect("the testing value should be able to be processed without error");
+		block.retain_tags([vec![0x95], vec![0x9F, 0x09]].as_slice());
+		let mut result = Vec::new();
+		collect_tags(&block, 0, &mut result);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
 	fn processed_emv_tag_parse_raw_normal() {
 		let expected = &EmvData::Normal(vec![0xFC, 0x00]);
 		let intermediate_result = ProcessedEmvTag::parse_raw(
@@ -658,6 +1394,95 @@ mod tests {
 
 		assert_eq!(expected, result);
 	}
+	/// A masked PAN can't be parsed or Luhn-checked, so it should only be
+	/// annotated with its name instead of failing.
+	#[test]
+	fn process_masked_pan() {
+		let expected = Ok(true);
+		let result = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[0x5A, 0x08, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A, 0x2A].as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.map(|block| matches!(block.nodes[0].tag, ProcessedEmvTag::Annotated { .. }));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn process_visa_issuer_application_data() {
+		let expected = Ok("Issuer Application Data (Visa)");
+		let result = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[0x9F, 0x10, 0x07, 0x06, 0x01, 0x0A, 0x03, 0xA0, 0x00, 0x00].as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.map(|block| match block.nodes[0].tag {
+			ProcessedEmvTag::Annotated { name, .. } | ProcessedEmvTag::Parsed { name, .. } => name,
+			ProcessedEmvTag::Raw { .. } => panic!("the testing value couldn't be parsed"),
+		});
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn process_mastercard_issuer_application_data() {
+		let expected = Ok("Issuer Application Data (Mastercard)");
+		let result = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[
+					0x9F, 0x10, 0x12, 0x01, 0x10, 0xA0, 0x00, 0x03, 0x22, 0x00, 0x00, 0x12, 0x34,
+					0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00,
+				]
+				.as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.map(|block| match block.nodes[0].tag {
+			ProcessedEmvTag::Annotated { name, .. } | ProcessedEmvTag::Parsed { name, .. } => name,
+			ProcessedEmvTag::Raw { .. } => panic!("the testing value couldn't be parsed"),
+		});
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn process_masked_cardholder_name() {
+		let expected = Ok(true);
+		let result = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[0x5F, 0x20, 0x04, 0x2A, 0x2A, 0x2A, 0x2A].as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.map(|block| matches!(block.nodes[0].tag, ProcessedEmvTag::Annotated { .. }));
+
+		assert_eq!(expected, result);
+	}
+	/// Sensitive tags like Track 2 Equivalent Data are frequently masked in
+	/// logs, and shouldn't cause the whole block to fail to process.
+	#[test]
+	fn process_masked_track_2_equivalent_data() {
+		let expected = Ok(true);
+		let result = ProcessedEmvBlock::try_from(
+			parse_ber_tlv(
+				[0x57, 0x04, 0x2A, 0x2A, 0x2A, 0x2A].as_slice(),
+				MaskingSettings::default(),
+				true,
+			)
+			.expect("any errors should already be tested by the BER-TLV testing"),
+		)
+		.map(|block| matches!(block.nodes[0].tag, ProcessedEmvTag::Annotated { .. }));
+
+		assert_eq!(expected, result);
+	}
 	#[test]
 	fn processed_emv_tag_parse_raw_unrecognised_normal_recognised() {
 		let expected = "Authorisation Response Code";
@@ -758,42 +1583,140 @@ mod tests {
 	#[test]
 	fn is_masked_u8_masked() {
 		let expected = true;
-		let result = is_masked_u8([0x2A, 0x2A, 0x2A].as_slice(), ['*'].as_slice());
+		let result = is_masked_u8([0x2A, 0x2A, 0x2A].as_slice(), MaskingSettings::default());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn is_masked_u8_unmasked() {
 		let expected = false;
-		let result = is_masked_u8([0x23, 0x12, 0x31].as_slice(), ['*'].as_slice());
+		let result = is_masked_u8([0x23, 0x12, 0x31].as_slice(), MaskingSettings::default());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_u8_masking_byte() {
+		let expected = true;
+		let result = is_masked_u8(
+			[0xFF, 0xFF, 0xFF].as_slice(),
+			MaskingSettings {
+				characters: ['*', char::from(0xFF)].as_slice(),
+				..MaskingSettings::default()
+			},
+		);
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn is_masked_u8_too_short_to_be_sure() {
 		let expected = false;
-		let result = is_masked_u8([0x2A].as_slice(), ['*'].as_slice());
+		let result = is_masked_u8([0x2A].as_slice(), MaskingSettings::default());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_u8_single_byte_masked_when_enabled() {
+		let expected = (true, true, false);
+		let result = (
+			is_masked_u8(
+				[0x2A].as_slice(),
+				MaskingSettings {
+					mask_single_byte: true,
+					..MaskingSettings::default()
+				},
+			),
+			is_masked_u8(
+				[0x2A].as_slice(),
+				MaskingSettings {
+					mode: MaskingMode::AnyConfiguredChar,
+					mask_single_byte: true,
+					..MaskingSettings::default()
+				},
+			),
+			is_masked_u8(
+				[0x31].as_slice(),
+				MaskingSettings {
+					mask_single_byte: true,
+					..MaskingSettings::default()
+				},
+			),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_u8_single_byte_setting_ignores_longer_values() {
+		let expected = false;
+		let result = is_masked_u8(
+			[0x11, 0x2A, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88].as_slice(),
+			MaskingSettings {
+				mode: MaskingMode::AnyConfiguredChar,
+				mask_single_byte: true,
+				..MaskingSettings::default()
+			},
+		);
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn is_masked_str_masked() {
 		let expected = true;
-		let result = is_masked_str("******", ['*'].as_slice());
+		let result = is_masked_str("******", ['*'].as_slice(), MaskingMode::AllSame);
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn is_masked_str_unmasked() {
 		let expected = false;
-		let result = is_masked_str("231231", ['*'].as_slice());
+		let result = is_masked_str("231231", ['*'].as_slice(), MaskingMode::AllSame);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_u8_partially_masked_all_same() {
+		let expected = false;
+		let result = is_masked_u8(
+			[0x2A, 0x2A, 0x31, 0x32, 0x2A, 0x2A].as_slice(),
+			MaskingSettings::default(),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_u8_partially_masked_any_configured_char() {
+		let expected = true;
+		let result = is_masked_u8(
+			[0x2A, 0x2A, 0x31, 0x32, 0x2A, 0x2A].as_slice(),
+			MaskingSettings {
+				mode: MaskingMode::AnyConfiguredChar,
+				..MaskingSettings::default()
+			},
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_str_mixed_characters_any_configured_char() {
+		let expected = true;
+		let result = is_masked_str(
+			"4111#*#*1111",
+			['*', '#'].as_slice(),
+			MaskingMode::AnyConfiguredChar,
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_masked_str_no_run_any_configured_char() {
+		let expected = false;
+		let result = is_masked_str("4*1*1*1", ['*'].as_slice(), MaskingMode::AnyConfiguredChar);
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn is_masked_str_too_short_to_be_sure() {
 		let expected = false;
-		let result = is_masked_str("*", ['*'].as_slice());
+		let result = is_masked_str("*", ['*'].as_slice(), MaskingMode::AllSame);
 
 		assert_eq!(expected, result);
 	}
@@ -2,157 +2,1241 @@
 //! its own spot because it's very long.
 
 // Uses
+use std::sync::{PoisonError, RwLock};
+
 use crate::{
 	emv::{
 		ccd::IssuerApplicationData,
+		mastercard::MastercardIssuerApplicationData,
+		visa::VisaIssuerApplicationData,
+		AccountType,
 		AdditionalTerminalCapabilities,
+		ApplicationCryptogram,
+		ApplicationIdentifier,
+		ApplicationVersionNumber,
 		AuthorisationResponseCode,
+		CardTransactionQualifiers,
+		CardholderName,
 		CardholderVerificationMethodList,
 		CardholderVerificationMethodResults,
+		DataFormat,
+		DataObjectList,
+		EmvData,
 		IssuerActionCodeDefault,
 		IssuerActionCodeDenial,
 		IssuerActionCodeOnline,
-		PosEntryMode,
+		IssuerCodeTableIndex,
+		LanguagePreference,
+		LogEntry,
+		MerchantNameAndLocation,
+		PosEntryModeField,
 		ProcessedEmvTag,
 		RawEmvTag,
+		ShortFileIdentifier,
+		TagLength,
 		TerminalCapabilities,
+		TerminalFloorLimit,
+		TerminalRiskManagementData,
+		TerminalTransactionQualifiers,
 		TerminalType,
 		TerminalVerificationResults,
+		Track2EquivalentData,
 		TransactionStatusInformation,
 		TransactionType,
 	},
 	error::ParseError,
-	non_emv::ServiceCode,
+	non_emv::{MerchantCategoryCode, PrimaryAccountNumber, ServiceCode},
 	DisplayBreakdown,
 };
 
+// Constants
+const EMV_BOOK_3: &str = "EMV Book 3, Annex A1";
+const EMV_CONTACTLESS_BOOK_C3: &str = "EMV Contactless Book C-3, Annex A";
+
+/// The parser used to break down the value of a known tag.
+///
+/// Most of these are named after the type the value is parsed into.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParserKind {
+	/// The tag is only annotated with its name.
+	None,
+	AccountType,
+	ApplicationCryptogram,
+	ApplicationIdentifier,
+	Track2EquivalentData,
+	PrimaryAccountNumber,
+	CardholderName,
+	LanguagePreference,
+	ServiceCode,
+	ShortFileIdentifier,
+	AuthorisationResponseCode,
+	CardholderVerificationMethodList,
+	DataObjectList,
+	TerminalVerificationResults,
+	TransactionStatusInformation,
+	TransactionType,
+	ApplicationVersionNumber,
+	IssuerActionCodeDefault,
+	IssuerActionCodeDenial,
+	IssuerActionCodeOnline,
+	IssuerApplicationData,
+	IssuerCodeTableIndex,
+	LogEntry,
+	MerchantCategoryCode,
+	MerchantNameAndLocation,
+	TerminalRiskManagementData,
+	TerminalCapabilities,
+	TerminalFloorLimit,
+	CardholderVerificationMethodResults,
+	TerminalType,
+	PosEntryMode,
+	AdditionalTerminalCapabilities,
+	TerminalTransactionQualifiers,
+	CardTransactionQualifiers,
+}
+
+/// A known tag, its name, the parser used for its value, and the format and
+/// length of the value according to the specification.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TagDefinition {
+	pub tag:    &'static [u8],
+	pub name:   &'static str,
+	pub parser: ParserKind,
+	pub format: DataFormat,
+	pub length: TagLength,
+	/// The specification and section the tag is documented in.
+	///
+	/// This is `None` for custom tags that don't override a known tag.
+	pub source: Option<&'static str>,
+}
+
+/// Every tag that's known by name, sorted by tag so it can be binary searched.
+///
+/// Adding support for a tag only requires adding it here, and adding a
+/// [`ParserKind`] if it has a new dedicated parser.
+pub static KNOWN_TAGS: &[TagDefinition] = &[
+	TagDefinition {
+		tag:    &[0x42],
+		name:   "Issuer Identification Number (IIN)",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x4F],
+		name:   "Application Dedicated File (ADF) Name",
+		parser: ParserKind::ApplicationIdentifier,
+		format: DataFormat::Binary,
+		length: TagLength::Between(5, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x50],
+		name:   "Application Label",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Between(1, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x57],
+		name:   "Track 2 Equivalent Data",
+		parser: ParserKind::Track2EquivalentData,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(19),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5A],
+		name:   "Application Primary Account Number (PAN)",
+		parser: ParserKind::PrimaryAccountNumber,
+		format: DataFormat::CompressedNumeric,
+		length: TagLength::UpTo(10),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x20],
+		name:   "Cardholder Name",
+		parser: ParserKind::CardholderName,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Between(2, 26),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x24],
+		name:   "Application Expiration Date",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x25],
+		name:   "Application Effective Date",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x28],
+		name:   "Issuer Country Code",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x2A],
+		name:   "Transaction Currency Code",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x2D],
+		name:   "Language Preference",
+		parser: ParserKind::LanguagePreference,
+		format: DataFormat::Alphanumeric,
+		length: TagLength::Between(2, 8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x30],
+		name:   "Service Code",
+		parser: ParserKind::ServiceCode,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x34],
+		name:   "Application Primary Account Number (PAN) Sequence Number",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x36],
+		name:   "Transaction Currency Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x50],
+		name:   "Issuer URL",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x53],
+		name:   "International Bank Account Number (IBAN)",
+		parser: ParserKind::None,
+		format: DataFormat::Variable,
+		length: TagLength::UpTo(34),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x54],
+		name:   "Bank Identifier Code (BIC)",
+		parser: ParserKind::None,
+		format: DataFormat::Alphanumeric,
+		length: TagLength::Between(8, 11),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x55],
+		name:   "Issuer Country Code (alpha2 format)",
+		parser: ParserKind::None,
+		format: DataFormat::Alphabetic,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x56],
+		name:   "Issuer Country Code (alpha3 format)",
+		parser: ParserKind::None,
+		format: DataFormat::Alphabetic,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x5F, 0x57],
+		name:   "Account Type",
+		parser: ParserKind::AccountType,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x61],
+		name:   "Application Template",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x6F],
+		name:   "File Control Information (FCI) Template",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x70],
+		name:   "READ RECORD Response Message Template",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x71],
+		name:   "Issuer Script Template 1",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x72],
+		name:   "Issuer Script Template 2",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x73],
+		name:   "Directory Discretionary Template",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x77],
+		name:   "Response Message Template Format 2",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x80],
+		name:   "Response Message Template Format 1",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x81],
+		name:   "Amount, Authorised (Binary)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x82],
+		name:   "Application Interchange Profile",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x83],
+		name:   "Command Template",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x84],
+		name:   "Dedicated File (DF) Name",
+		parser: ParserKind::ApplicationIdentifier,
+		format: DataFormat::Binary,
+		length: TagLength::Between(5, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x86],
+		name:   "Issuer Script Command",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(261),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x87],
+		name:   "Application Priority Indicator",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x88],
+		name:   "Short File Identifier (SFI)",
+		parser: ParserKind::ShortFileIdentifier,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x89],
+		name:   "Authorisation Code",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Exactly(6),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x8A],
+		name:   "Authorisation Response Code",
+		parser: ParserKind::AuthorisationResponseCode,
+		format: DataFormat::Alphanumeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x8C],
+		name:   "Card Risk Management Data Object List 1 (CDOL1)",
+		parser: ParserKind::DataObjectList,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x8D],
+		name:   "Card Risk Management Data Object List 2 (CDOL2)",
+		parser: ParserKind::DataObjectList,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x8E],
+		name:   "CVM List",
+		parser: ParserKind::CardholderVerificationMethodList,
+		format: DataFormat::Binary,
+		length: TagLength::Between(10, 252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x8F],
+		name:   "Certification Authority Public Key Index (ICC)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x90],
+		name:   "Issuer Public Key Certificate",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x91],
+		name:   "Issuer Authentication Data",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(8, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x92],
+		name:   "Issuer Public Key Remainder",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x93],
+		name:   "Signed Static Application Data",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x94],
+		name:   "Application File Locator (AFL)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x95],
+		name:   "Terminal Verification Results (TVR)",
+		parser: ParserKind::TerminalVerificationResults,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(5),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x97],
+		name:   "Transaction Certificate Data Object List (TDOL)",
+		parser: ParserKind::DataObjectList,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x98],
+		name:   "Transaction Certificate (TC) Hash Value",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(20),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x99],
+		name:   "Transaction PIN Data",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9A],
+		name:   "Transaction Date",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9B],
+		name:   "Transaction Status Information (TSI)",
+		parser: ParserKind::TransactionStatusInformation,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9C],
+		name:   "Transaction Type",
+		parser: ParserKind::TransactionType,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9D],
+		name:   "Directory Definition File (DDF) Name",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(5, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x01],
+		name:   "Acquirer Identifier",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(6),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x02],
+		name:   "Amount, Authorised (Numeric)",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(6),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x03],
+		name:   "Amount, Other (Numeric)",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(6),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x04],
+		name:   "Amount, Other (Binary)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x05],
+		name:   "Application Discretionary Data",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(1, 32),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x06],
+		name:   "Application Identifier (AID)",
+		parser: ParserKind::ApplicationIdentifier,
+		format: DataFormat::Binary,
+		length: TagLength::Between(5, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x07],
+		name:   "Application Usage Control",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x08],
+		name:   "Application Version Number (ICC)",
+		parser: ParserKind::ApplicationVersionNumber,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x09],
+		name:   "Application Version Number (Terminal)",
+		parser: ParserKind::ApplicationVersionNumber,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x0B],
+		name:   "Cardholder Name Extended",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Between(27, 45),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x0D],
+		name:   "Issuer Action Code - Default",
+		parser: ParserKind::IssuerActionCodeDefault,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(5),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x0E],
+		name:   "Issuer Action Code - Denial",
+		parser: ParserKind::IssuerActionCodeDenial,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(5),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x0F],
+		name:   "Issuer Action Code - Online",
+		parser: ParserKind::IssuerActionCodeOnline,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(5),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x10],
+		name:   "Issuer Application Data (CCD-Compliant)",
+		parser: ParserKind::IssuerApplicationData,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(32),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x11],
+		name:   "Issuer Code Table Index",
+		parser: ParserKind::IssuerCodeTableIndex,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x12],
+		name:   "Application Preferred Name",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Between(1, 16),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x13],
+		name:   "Last Online Application Transaction Counter (ATC) Register",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x14],
+		name:   "Lower Consecutive Offline Limit",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x15],
+		name:   "Merchant Category Code",
+		parser: ParserKind::MerchantCategoryCode,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x16],
+		name:   "Merchant Identifier",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Exactly(15),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x17],
+		name:   "PIN Try Counter",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x18],
+		name:   "Issuer Script Identifier",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x1A],
+		name:   "Terminal Country Code",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x1B],
+		name:   "Terminal Floor Limit",
+		parser: ParserKind::TerminalFloorLimit,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x1C],
+		name:   "Terminal Identification",
+		parser: ParserKind::None,
+		format: DataFormat::Alphanumeric,
+		length: TagLength::Exactly(8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x1D],
+		name:   "Terminal Risk Management Data",
+		parser: ParserKind::TerminalRiskManagementData,
+		format: DataFormat::Binary,
+		length: TagLength::Between(1, 8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x1E],
+		name:   "Interface Device (IFD/Terminal) Serial Number",
+		parser: ParserKind::None,
+		format: DataFormat::Alphanumeric,
+		length: TagLength::Exactly(8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x1F],
+		name:   "Track 1 Discretionary Data",
+		parser: ParserKind::None,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x20],
+		name:   "Track 2 Discretionary Data",
+		parser: ParserKind::None,
+		format: DataFormat::CompressedNumeric,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x21],
+		name:   "Transaction Time",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x22],
+		name:   "Certification Authority Public Key Index (Terminal)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x23],
+		name:   "Upper Consecutive Offline Limit",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x26],
+		name:   "Application Cryptogram",
+		parser: ParserKind::ApplicationCryptogram,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x27],
+		name:   "Cryptogram Information Data (CID)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x2D],
+		name:   "ICC PIN Encipherment Public Key Certificate",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x2E],
+		name:   "ICC PIN Encipherment Public Key Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(1, 3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x2F],
+		name:   "ICC PIN Encipherment Public Key Remainder",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x32],
+		name:   "Issuer Public Key Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(1, 3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x33],
+		name:   "Terminal Capabilities",
+		parser: ParserKind::TerminalCapabilities,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x34],
+		name:   "CVM Results",
+		parser: ParserKind::CardholderVerificationMethodResults,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x35],
+		name:   "Terminal Type",
+		parser: ParserKind::TerminalType,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x36],
+		name:   "Application Transaction Counter (ATC)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x37],
+		name:   "Unpredictable Number",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x38],
+		name:   "Processing Options Data Object List (PDOL)",
+		parser: ParserKind::DataObjectList,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x39],
+		name:   "POS Entry Mode",
+		parser: ParserKind::PosEntryMode,
+		format: DataFormat::Numeric,
+		length: TagLength::Between(1, 2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x3A],
+		name:   "Amount, Reference Currency (Binary)",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x3B],
+		name:   "Application Reference Currency",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Between(2, 8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x3C],
+		name:   "Transaction Reference Currency Code",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x3D],
+		name:   "Transaction Reference Currency Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x40],
+		name:   "Additional Terminal Capabilities",
+		parser: ParserKind::AdditionalTerminalCapabilities,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(5),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x41],
+		name:   "Transaction Sequence Counter",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Between(2, 4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x42],
+		name:   "Application Currency Code",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x43],
+		name:   "Application Reference Currency Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Between(1, 4),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x44],
+		name:   "Application Currency Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Numeric,
+		length: TagLength::Exactly(1),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x45],
+		name:   "Data Authentication Code",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x46],
+		name:   "ICC Public Key Certificate",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x47],
+		name:   "ICC Public Key Exponent",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(1, 3),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x48],
+		name:   "ICC Public Key Remainder",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x49],
+		name:   "Dynamic Data Authentication Data Object List (DDOL)",
+		parser: ParserKind::DataObjectList,
+		format: DataFormat::Binary,
+		length: TagLength::UpTo(252),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x4A],
+		name:   "Static Data Authentication Tag List",
+		parser: ParserKind::None,
+		format: DataFormat::Variable,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x4B],
+		name:   "Signed Dynamic Application Data",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x4C],
+		name:   "ICC Dynamic Number",
+		parser: ParserKind::None,
+		format: DataFormat::Binary,
+		length: TagLength::Between(2, 8),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x4D],
+		name:   "Log Entry",
+		parser: ParserKind::LogEntry,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x4E],
+		name:   "Merchant Name and Location",
+		parser: ParserKind::MerchantNameAndLocation,
+		format: DataFormat::AlphanumericSpecial,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x4F],
+		name:   "Log Format",
+		parser: ParserKind::DataObjectList,
+		format: DataFormat::Binary,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x66],
+		name:   "Terminal Transaction Qualifiers (TTQ)",
+		parser: ParserKind::TerminalTransactionQualifiers,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(4),
+		source: Some(EMV_CONTACTLESS_BOOK_C3),
+	},
+	TagDefinition {
+		tag:    &[0x9F, 0x6C],
+		name:   "Card Transaction Qualifiers (CTQ)",
+		parser: ParserKind::CardTransactionQualifiers,
+		format: DataFormat::Binary,
+		length: TagLength::Exactly(2),
+		source: Some(EMV_CONTACTLESS_BOOK_C3),
+	},
+	TagDefinition {
+		tag:    &[0xA5],
+		name:   "File Control Information (FCI) Proprietary Template",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::Variable,
+		source: Some(EMV_BOOK_3),
+	},
+	TagDefinition {
+		tag:    &[0xBF, 0x0C],
+		name:   "File Control Information (FCI) Issuer Discretionary Data",
+		parser: ParserKind::None,
+		format: DataFormat::Template,
+		length: TagLength::UpTo(222),
+		source: Some(EMV_BOOK_3),
+	},
+];
+
+/// Tags supplied by the user, which take precedence over [`KNOWN_TAGS`].
+static CUSTOM_TAGS: RwLock<Vec<TagDefinition>> = RwLock::new(Vec::new());
+
+/// The suffix of the known Issuer Application Data name, which is replaced
+/// when the data has a different layout.
+const CCD_COMPLIANT_SUFFIX: &str = " (CCD-Compliant)";
+
+/// Issuer Application Data layout names that have already been derived, as
+/// `(tag name, layout, derived name)`.
+static LAYOUT_NAMES: RwLock<Vec<(&str, &str, &str)>> = RwLock::new(Vec::new());
+
+/// A user-supplied tag definition, which adds a tag that isn't in
+/// [`KNOWN_TAGS`] or overrides the name of one that is.
+///
+/// This is mostly useful for proprietary tags used by acquirers and
+/// processors.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CustomTag {
+	pub tag:      Vec<u8>,
+	pub name:     String,
+	/// A known tag whose parser should be used for the value, as a hint for how
+	/// it's formatted.
+	///
+	/// If not provided, a tag that's already known keeps its parser, and a new
+	/// tag is only annotated with its name.
+	pub parse_as: Option<Vec<u8>>,
+}
+
+impl CustomTag {
+	/// Resolves the custom tag into a [`TagDefinition`].
+	///
+	/// The tag and name are leaked, since processed tags expect their names to
+	/// live for the rest of the program. Custom tags are only expected to be
+	/// loaded once, at startup.
+	///
+	/// Returns [`ParseError::Unrecognised`] if `parse_as` isn't a known tag.
+	pub fn into_definition(self) -> Result<TagDefinition, ParseError> {
+		let known_definition = find_known_tag_definition(self.tag.as_slice());
+		let hint_definition = match &self.parse_as {
+			Some(parse_as) => Some(
+				find_known_tag_definition(parse_as.as_slice()).ok_or(ParseError::Unrecognised)?,
+			),
+			None => known_definition,
+		};
+
+		Ok(TagDefinition {
+			tag:    Box::leak(self.tag.into_boxed_slice()),
+			name:   Box::leak(self.name.into_boxed_str()),
+			parser: hint_definition.map_or(ParserKind::None, |definition| definition.parser),
+			format: hint_definition.map_or(DataFormat::Variable, |definition| definition.format),
+			length: hint_definition.map_or(TagLength::Variable, |definition| definition.length),
+			source: known_definition.and_then(|definition| definition.source),
+		})
+	}
+}
+
+/// Sets the custom tags used when identifying and processing tags, replacing
+/// any that were set before.
+///
+/// If a tag is provided multiple times, the last definition is used.
+pub fn set_custom_tags(custom_tags: Vec<CustomTag>) -> Result<(), ParseError> {
+	let definitions = custom_tags
+		.into_iter()
+		.map(CustomTag::into_definition)
+		.collect::<Result<Vec<_>, _>>()?;
+
+	*CUSTOM_TAGS.write().unwrap_or_else(PoisonError::into_inner) = definitions;
+
+	Ok(())
+}
+
+/// Looks up the definition of a tag, checking the custom tags before
+/// [`KNOWN_TAGS`].
+pub fn find_tag_definition(tag: &[u8]) -> Option<TagDefinition> {
+	CUSTOM_TAGS
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.iter()
+		.rev()
+		.find(|definition| definition.tag == tag)
+		.copied()
+		.or_else(|| find_known_tag_definition(tag).copied())
+}
+
+/// Returns every tag that can be identified, including the custom tags, sorted
+/// by tag.
+pub fn all_tag_definitions() -> Vec<TagDefinition> {
+	let custom_tags = CUSTOM_TAGS.read().unwrap_or_else(PoisonError::into_inner);
+	let mut definitions = KNOWN_TAGS
+		.iter()
+		.filter(|known_definition| {
+			!custom_tags
+				.iter()
+				.any(|custom_definition| custom_definition.tag == known_definition.tag)
+		})
+		.copied()
+		.collect::<Vec<_>>();
+	for custom_definition in custom_tags.iter().rev() {
+		if !definitions
+			.iter()
+			.any(|definition| definition.tag == custom_definition.tag)
+		{
+			definitions.push(*custom_definition);
+		}
+	}
+	definitions.sort_by_key(|definition| definition.tag);
+
+	definitions
+}
+
+/// Looks up the definition of a tag in [`KNOWN_TAGS`] only.
+fn find_known_tag_definition(tag: &[u8]) -> Option<&'static TagDefinition> {
+	KNOWN_TAGS
+		.binary_search_by(|definition| definition.tag.cmp(tag))
+		.ok()
+		.map(|index| &KNOWN_TAGS[index])
+}
+
 /// This is the first step of tag identification, and can be used on its own.
-#[cfg(not(tarpaulin_include))]
 pub fn identify_tag(tag: &[u8]) -> Option<&'static str> {
-	match tag {
-		[0x42] => Some("Issuer Identification Number (IIN)"),
-		[0x4F] => Some("Application Dedicated File (ADF) Name"),
-		[0x50] => Some("Application Label"),
-		[0x57] => Some("Track 2 Equivalent Data"),
-		[0x5A] => Some("Application Primary Account Number (PAN)"),
-		[0x5F, 0x20] => Some("Cardholder Name"),
-		[0x5F, 0x24] => Some("Application Expiration Date"),
-		[0x5F, 0x25] => Some("Application Effective Date"),
-		[0x5F, 0x28] => Some("Issuer Country Code"),
-		[0x5F, 0x2A] => Some("Transaction Currency Code"),
-		[0x5F, 0x2D] => Some("Language Preference"),
-		[0x5F, 0x30] => Some("Service Code"),
-		[0x5F, 0x34] => Some("Application Primary Account Number (PAN) Sequence Number"),
-		[0x5F, 0x36] => Some("Transaction Currency Exponent"),
-		[0x5F, 0x50] => Some("Issuer URL"),
-		[0x5F, 0x53] => Some("International Bank Account Number (IBAN)"),
-		[0x5F, 0x54] => Some("Bank Identifier Code (BIC)"),
-		[0x5F, 0x55] => Some("Issuer Country Code (alpha2 format)"),
-		[0x5F, 0x56] => Some("Issuer Country Code (alpha3 format)"),
-		[0x5F, 0x57] => Some("Account Type"),
-		[0x61] => Some("Application Template"),
-		[0x6F] => Some("File Control Information (FCI) Template"),
-		[0x70] => Some("READ RECORD Response Message Template"),
-		[0x71] => Some("Issuer Script Template 1"),
-		[0x72] => Some("Issuer Script Template 2"),
-		[0x73] => Some("Directory Discretionary Template"),
-		[0x77] => Some("Response Message Template Format 2"),
-		[0x80] => Some("Response Message Template Format 1"),
-		[0x81] => Some("Amount, Authorised (Binary)"),
-		[0x82] => Some("Application Interchange Profile"),
-		[0x83] => Some("Command Template"),
-		[0x84] => Some("Dedicated File (DF) Name"),
-		[0x86] => Some("Issuer Script Command"),
-		[0x87] => Some("Application Priority Indicator"),
-		[0x88] => Some("Short File Identifier (SFI)"),
-		[0x89] => Some("Authorisation Code"),
-		[0x8A] => Some("Authorisation Response Code"),
-		[0x8C] => Some("Card Risk Management Data Object List 1 (CDOL1)"),
-		[0x8D] => Some("Card Risk Management Data Object List 2 (CDOL2)"),
-		[0x8E] => Some("CVM List"),
-		[0x8F] => Some("Certification Authority Public Key Index (ICC)"),
-		[0x90] => Some("Issuer Public Key Certificate"),
-		[0x91] => Some("Issuer Authentication Data"),
-		[0x92] => Some("Issuer Public Key Remainder"),
-		[0x93] => Some("Signed Static Application Data"),
-		[0x94] => Some("Application File Locator (AFL)"),
-		[0x95] => Some("Terminal Verification Results (TVR)"),
-		[0x97] => Some("Transaction Certificate Data Object List (TDOL)"),
-		[0x98] => Some("Transaction Certificate (TC) Hash Value"),
-		[0x99] => Some("Transaction PIN Data"),
-		[0x9A] => Some("Transaction Date"),
-		[0x9B] => Some("Transaction Status Information (TSI)"),
-		[0x9C] => Some("Transaction Type"),
-		[0x9D] => Some("Directory Definition File (DDF) Name"),
-		[0x9F, 0x01] => Some("Acquirer Identifier"),
-		[0x9F, 0x02] => Some("Amount, Authorised (Numeric)"),
-		[0x9F, 0x03] => Some("Amount, Other (Numeric)"),
-		[0x9F, 0x04] => Some("Amount, Other (Binary)"),
-		[0x9F, 0x05] => Some("Application Discretionary Data"),
-		[0x9F, 0x06] => Some("Application Identifier (AID)"),
-		[0x9F, 0x07] => Some("Application Usage Control"),
-		[0x9F, 0x08] => Some("Application Version Number (ICC)"),
-		[0x9F, 0x09] => Some("Application Version Number (Terminal)"),
-		[0x9F, 0x0B] => Some("Cardholder Name Extended"),
-		[0x9F, 0x0D] => Some("Issuer Action Code - Default"),
-		[0x9F, 0x0E] => Some("Issuer Action Code - Denial"),
-		[0x9F, 0x0F] => Some("Issuer Action Code - Online"),
-		[0x9F, 0x10] => Some("Issuer Application Data (CCD-Compliant)"),
-		[0x9F, 0x11] => Some("Issuer Code Table Index"),
-		[0x9F, 0x12] => Some("Application Preferred Name"),
-		[0x9F, 0x13] => Some("Last Online Application Transaction Counter (ATC) Register"),
-		[0x9F, 0x14] => Some("Lower Consecutive Offline Limit"),
-		[0x9F, 0x15] => Some("Merchant Category Code"),
-		[0x9F, 0x16] => Some("Merchant Identifier"),
-		[0x9F, 0x17] => Some("PIN Try Counter"),
-		[0x9F, 0x18] => Some("Issuer Script Identifier"),
-		[0x9F, 0x1A] => Some("Terminal Country Code"),
-		[0x9F, 0x1B] => Some("Terminal Floor Limit"),
-		[0x9F, 0x1C] => Some("Terminal Identification"),
-		[0x9F, 0x1D] => Some("Terminal Risk Management Data"),
-		[0x9F, 0x1E] => Some("Interface Device (IFD/Terminal) Serial Number"),
-		[0x9F, 0x1F] => Some("Track 1 Discretionary Data"),
-		[0x9F, 0x20] => Some("Track 2 Discretionary Data"),
-		[0x9F, 0x21] => Some("Transaction Time"),
-		[0x9F, 0x22] => Some("Certification Authority Public Key Index (Terminal)"),
-		[0x9F, 0x23] => Some("Upper Consecutive Offline Limit"),
-		[0x9F, 0x26] => Some("Application Cryptogram"),
-		[0x9F, 0x27] => Some("Cryptogram Information Data (CID)"),
-		[0x9F, 0x2D] => Some("ICC PIN Encipherment Public Key Certificate"),
-		[0x9F, 0x2E] => Some("ICC PIN Encipherment Public Key Exponent"),
-		[0x9F, 0x2F] => Some("ICC PIN Encipherment Public Key Remainder"),
-		[0x9F, 0x32] => Some("Issuer Public Key Exponent"),
-		[0x9F, 0x33] => Some("Terminal Capabilities"),
-		[0x9F, 0x34] => Some("CVM Results"),
-		[0x9F, 0x35] => Some("Terminal Type"),
-		[0x9F, 0x36] => Some("Application Transaction Counter (ATC)"),
-		[0x9F, 0x37] => Some("Unpredictable Number"),
-		[0x9F, 0x38] => Some("Processing Options Data Object List (PDOL)"),
-		[0x9F, 0x39] => Some("POS Entry Mode"),
-		[0x9F, 0x3A] => Some("Amount, Reference Currency (Binary)"),
-		[0x9F, 0x3B] => Some("Application Reference Currency"),
-		[0x9F, 0x3C] => Some("Transaction Reference Currency Code"),
-		[0x9F, 0x3D] => Some("Transaction Reference Currency Exponent"),
-		[0x9F, 0x40] => Some("Additional Terminal Capabilities"),
-		[0x9F, 0x41] => Some("Transaction Sequence Counter"),
-		[0x9F, 0x42] => Some("Application Currency Code"),
-		[0x9F, 0x43] => Some("Application Reference Currency Exponent"),
-		[0x9F, 0x44] => Some("Application Currency Exponent"),
-		[0x9F, 0x45] => Some("Data Authentication Code"),
-		[0x9F, 0x46] => Some("ICC Public Key Certificate"),
-		[0x9F, 0x47] => Some("ICC Public Key Exponent"),
-		[0x9F, 0x48] => Some("ICC Public Key Remainder"),
-		[0x9F, 0x49] => Some("Dynamic Data Authentication Data Object List (DDOL)"),
-		[0x9F, 0x4A] => Some("Static Data Authentication Tag List"),
-		[0x9F, 0x4B] => Some("Signed Dynamic Application Data"),
-		[0x9F, 0x4C] => Some("ICC Dynamic Number"),
-		[0x9F, 0x4D] => Some("Log Entry"),
-		[0x9F, 0x4E] => Some("Merchant Name and Location"),
-		[0x9F, 0x4F] => Some("Log Format"),
-		[0xA5] => Some("File Control Information (FCI) Proprietary Template"),
-		[0xBF, 0x0C] => Some("File Control Information (FCI) Issuer Discretionary Data"),
-		_ => None,
-	}
+	find_tag_definition(tag).map(|definition| definition.name)
+}
+
+/// Returns whether [`process_emv_tag`] has a dedicated parser for the tag,
+/// which breaks down its value instead of only annotating it with a name.
+pub fn has_dedicated_parser(tag: &[u8]) -> bool {
+	find_tag_definition(tag).is_some_and(|definition| definition.parser != ParserKind::None)
+}
+
+/// Parses a tag value into a boxed [`DisplayBreakdown`], for use with
+/// [`ProcessedEmvTag::parse_raw`].
+fn parse_as<T>(data: &[u8]) -> Result<Box<dyn DisplayBreakdown>, ParseError>
+where
+	T: for<'a> TryFrom<&'a [u8], Error = ParseError> + DisplayBreakdown + 'static,
+{
+	T::try_from(data).map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
 }
 
 /// Process a [`RawEmvTag`] into a [`ProcessedEmvTag`].
@@ -163,89 +1247,445 @@ pub fn identify_tag(tag: &[u8]) -> Option<&'static str> {
 /// tested.
 #[cfg(not(tarpaulin_include))]
 pub fn process_emv_tag(raw_tag: RawEmvTag) -> Result<ProcessedEmvTag, ParseError> {
-	// Parseable tags
-	Ok(
-		match identify_tag(raw_tag.tag.as_slice()).map(|name| (raw_tag.tag.as_slice(), name)) {
-			Some(([0x5F, 0x30], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				ServiceCode::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x8A], name)) => ProcessedEmvTag::parse_raw_unrecognised(
-				name,
-				"Authorisation Response Code (Unrecognised - likely payment system-specific)",
-				raw_tag,
-				|data| {
-					AuthorisationResponseCode::try_from(data)
-						.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-				},
-				|error| matches!(error, ParseError::Unrecognised),
-			)?,
-			Some(([0x8E], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				CardholderVerificationMethodList::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x95], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				TerminalVerificationResults::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9B], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				TransactionStatusInformation::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9C], name)) => ProcessedEmvTag::parse_raw_unrecognised(
-				name,
-				"Transaction Type (Unrecognised - likely payment system-specific)",
-				raw_tag,
-				|data| {
-					TransactionType::try_from(data)
-						.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-				},
-				|error| matches!(error, ParseError::Unrecognised),
-			)?,
-			Some(([0x9F, 0x0D], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				IssuerActionCodeDefault::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x0E], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				IssuerActionCodeDenial::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x0F], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				IssuerActionCodeOnline::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x10], _)) => ProcessedEmvTag::parse_raw_unrecognised(
-				"Issuer Application Data (CCD-Compliant)",
-				"Issuer Application Data (Not CCD-Compliant)",
-				raw_tag,
-				|data| {
-					IssuerApplicationData::try_from(data)
-						.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-				},
-				|error| matches!(error, ParseError::NonCcdCompliant),
-			)?,
-			Some(([0x9F, 0x33], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				TerminalCapabilities::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x34], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				CardholderVerificationMethodResults::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x35], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				TerminalType::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x39], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				PosEntryMode::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some(([0x9F, 0x40], name)) => ProcessedEmvTag::parse_raw(name, raw_tag, |data| {
-				AdditionalTerminalCapabilities::try_from(data)
-					.map(|parsed| Box::new(parsed) as Box<dyn DisplayBreakdown>)
-			})?,
-			Some((_, name)) => ProcessedEmvTag::Annotated { name, raw_tag },
-			_ => ProcessedEmvTag::Raw { raw_tag },
-		},
+	let Some(TagDefinition { name, parser, .. }) = find_tag_definition(raw_tag.tag.as_slice())
+	else {
+		return Ok(ProcessedEmvTag::Raw { raw_tag });
+	};
+
+	Ok(match parser {
+		ParserKind::None => ProcessedEmvTag::Annotated { name, raw_tag },
+		ParserKind::AccountType => ProcessedEmvTag::parse_raw_unrecognised(
+			name,
+			"Account Type (Unrecognised)",
+			raw_tag,
+			parse_as::<AccountType>,
+			|error| matches!(error, ParseError::Unrecognised),
+		)?,
+		ParserKind::ApplicationCryptogram => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<ApplicationCryptogram>)?
+		}
+		ParserKind::ApplicationIdentifier => ProcessedEmvTag::parse_raw_unrecognised(
+			name,
+			name,
+			raw_tag,
+			parse_as::<ApplicationIdentifier>,
+			|error| matches!(error, ParseError::Unrecognised),
+		)?,
+		ParserKind::Track2EquivalentData => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<Track2EquivalentData>)?
+		}
+		ParserKind::PrimaryAccountNumber => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<PrimaryAccountNumber>)?
+		}
+		ParserKind::CardholderName => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<CardholderName>)?
+		}
+		ParserKind::LanguagePreference => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<LanguagePreference>)?
+		}
+		ParserKind::ServiceCode => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<ServiceCode>)?
+		}
+		ParserKind::ShortFileIdentifier => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<ShortFileIdentifier>)?
+		}
+		ParserKind::AuthorisationResponseCode => ProcessedEmvTag::parse_raw_unrecognised(
+			name,
+			"Authorisation Response Code (Unrecognised - likely payment system-specific)",
+			raw_tag,
+			parse_as::<AuthorisationResponseCode>,
+			|error| matches!(error, ParseError::Unrecognised),
+		)?,
+		ParserKind::CardholderVerificationMethodList => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<CardholderVerificationMethodList>)?
+		}
+		ParserKind::DataObjectList => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<DataObjectList>)?
+		}
+		ParserKind::TerminalVerificationResults => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TerminalVerificationResults>)?
+		}
+		ParserKind::TransactionStatusInformation => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TransactionStatusInformation>)?
+		}
+		ParserKind::TransactionType => ProcessedEmvTag::parse_raw_unrecognised(
+			name,
+			"Transaction Type (Unrecognised - likely payment system-specific)",
+			raw_tag,
+			parse_as::<TransactionType>,
+			|error| matches!(error, ParseError::Unrecognised),
+		)?,
+		ParserKind::ApplicationVersionNumber => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<ApplicationVersionNumber>)?
+		}
+		ParserKind::IssuerActionCodeDefault => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<IssuerActionCodeDefault>)?
+		}
+		ParserKind::IssuerActionCodeDenial => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<IssuerActionCodeDenial>)?
+		}
+		ParserKind::IssuerActionCodeOnline => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<IssuerActionCodeOnline>)?
+		}
+		ParserKind::IssuerApplicationData => process_issuer_application_data(name, raw_tag)?,
+		ParserKind::IssuerCodeTableIndex => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<IssuerCodeTableIndex>)?
+		}
+		ParserKind::LogEntry => ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<LogEntry>)?,
+		ParserKind::MerchantCategoryCode => ProcessedEmvTag::parse_raw_unrecognised(
+			name,
+			"Merchant Category Code (Unrecognised)",
+			raw_tag,
+			parse_as::<MerchantCategoryCode>,
+			|error| matches!(error, ParseError::Unrecognised),
+		)?,
+		ParserKind::MerchantNameAndLocation => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<MerchantNameAndLocation>)?
+		}
+		ParserKind::TerminalRiskManagementData => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TerminalRiskManagementData>)?
+		}
+		ParserKind::TerminalFloorLimit => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TerminalFloorLimit>)?
+		}
+		ParserKind::TerminalCapabilities => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TerminalCapabilities>)?
+		}
+		ParserKind::CardholderVerificationMethodResults => ProcessedEmvTag::parse_raw(
+			name,
+			raw_tag,
+			parse_as::<CardholderVerificationMethodResults>,
+		)?,
+		ParserKind::TerminalType => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TerminalType>)?
+		}
+		ParserKind::PosEntryMode => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<PosEntryModeField>)?
+		}
+		ParserKind::AdditionalTerminalCapabilities => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<AdditionalTerminalCapabilities>)?
+		}
+		ParserKind::TerminalTransactionQualifiers => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<TerminalTransactionQualifiers>)?
+		}
+		ParserKind::CardTransactionQualifiers => {
+			ProcessedEmvTag::parse_raw(name, raw_tag, parse_as::<CardTransactionQualifiers>)?
+		}
+	})
+}
+
+/// Processes the Issuer Application Data, which has a layout that depends on
+/// the payment system.
+///
+/// The CCD-compliant layout is preferred, but if the data isn't CCD-compliant
+/// then the payment system-specific layouts are tried before giving up.
+fn process_issuer_application_data(
+	name: &'static str,
+	raw_tag: RawEmvTag,
+) -> Result<ProcessedEmvTag, ParseError> {
+	// If the IAD isn't CCD-compliant, try the proprietary layouts before giving up
+	if let EmvData::Normal(data) = &raw_tag.data {
+		if IssuerApplicationData::try_from(data.as_slice()) == Err(ParseError::NonCcdCompliant) {
+			if VisaIssuerApplicationData::try_from(data.as_slice()).is_ok() {
+				return ProcessedEmvTag::parse_raw(
+					issuer_application_data_layout_name(name, "Visa"),
+					raw_tag,
+					parse_as::<VisaIssuerApplicationData>,
+				);
+			}
+			if MastercardIssuerApplicationData::try_from(data.as_slice()).is_ok() {
+				return ProcessedEmvTag::parse_raw(
+					issuer_application_data_layout_name(name, "Mastercard"),
+					raw_tag,
+					parse_as::<MastercardIssuerApplicationData>,
+				);
+			}
+		}
+	}
+
+	ProcessedEmvTag::parse_raw_unrecognised(
+		name,
+		issuer_application_data_layout_name(name, "Not CCD-Compliant"),
+		raw_tag,
+		parse_as::<IssuerApplicationData>,
+		|error| matches!(error, ParseError::NonCcdCompliant),
 	)
 }
+
+/// Derives the name of an Issuer Application Data layout from the tag's name,
+/// which may have been overridden by a custom tag.
+///
+/// The layout replaces the CCD-compliant suffix of the known name, or is
+/// appended to a custom name. Derived names are leaked like custom tag names,
+/// so they're cached to only be leaked once.
+fn issuer_application_data_layout_name(name: &'static str, layout: &'static str) -> &'static str {
+	let cached_name = LAYOUT_NAMES
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.iter()
+		.find(|(cached_base, cached_layout, _)| *cached_base == name && *cached_layout == layout)
+		.map(|(_, _, cached_name)| *cached_name);
+	if let Some(cached_name) = cached_name {
+		return cached_name;
+	}
+
+	let base = name.strip_suffix(CCD_COMPLIANT_SUFFIX).unwrap_or(name);
+	let derived_name: &'static str = Box::leak(format!("{base} ({layout})").into_boxed_str());
+	LAYOUT_NAMES
+		.write()
+		.unwrap_or_else(PoisonError::into_inner)
+		.push((name, layout, derived_name));
+
+	derived_name
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{
+		super::{DataObjectType, EmvData, ProcessedEmvTag, RawEmvTag, TagClass},
+		find_tag_definition,
+		has_dedicated_parser,
+		identify_tag,
+		issuer_application_data_layout_name,
+		process_emv_tag,
+		set_custom_tags,
+		CustomTag,
+		ParserKind,
+		TagDefinition,
+		EMV_BOOK_3,
+		KNOWN_TAGS,
+	};
+	use crate::{
+		emv::{DataFormat, TagLength},
+		error::ParseError,
+	};
+
+	// Tests
+	#[test]
+	fn known_tags_sorted_and_unique() {
+		for pair in KNOWN_TAGS.windows(2) {
+			assert!(
+				pair[0].tag < pair[1].tag,
+				"tags {:02X?} and {:02X?} are out of order or duplicated",
+				pair[0].tag,
+				pair[1].tag
+			);
+		}
+	}
+	#[test]
+	fn identify_known_tags() {
+		let expected = vec![
+			Some("Issuer Identification Number (IIN)"),
+			Some("Cardholder Name"),
+			Some("Terminal Verification Results (TVR)"),
+			Some("Application Version Number (Terminal)"),
+			Some("Card Transaction Qualifiers (CTQ)"),
+			Some("File Control Information (FCI) Issuer Discretionary Data"),
+			None,
+			None,
+		];
+		let result = [
+			[0x42].as_slice(),
+			[0x5F, 0x20].as_slice(),
+			[0x95].as_slice(),
+			[0x9F, 0x09].as_slice(),
+			[0x9F, 0x6C].as_slice(),
+			[0xBF, 0x0C].as_slice(),
+			[0x9F].as_slice(),
+			[0xDF, 0x01].as_slice(),
+		]
+		.into_iter()
+		.map(identify_tag)
+		.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parser_kinds() {
+		let expected = vec![
+			Some(ParserKind::Track2EquivalentData),
+			Some(ParserKind::ApplicationVersionNumber),
+			Some(ParserKind::ApplicationVersionNumber),
+			Some(ParserKind::IssuerApplicationData),
+			Some(ParserKind::None),
+			None,
+		];
+		let result = [
+			[0x57].as_slice(),
+			[0x9F, 0x08].as_slice(),
+			[0x9F, 0x09].as_slice(),
+			[0x9F, 0x10].as_slice(),
+			[0x9F, 0x02].as_slice(),
+			[0xDF, 0x01].as_slice(),
+		]
+		.into_iter()
+		.map(|tag| find_tag_definition(tag).map(|definition| definition.parser))
+		.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn pos_entry_mode_length_matches_parser() {
+		let expected = Some(TagLength::Between(1, 2));
+		let result = find_tag_definition(&[0x9F, 0x39]).map(|definition| definition.length);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn dedicated_parsers() {
+		assert!(has_dedicated_parser(&[0x9F, 0x66]));
+		assert!(!has_dedicated_parser(&[0x9F, 0x02]));
+		assert!(!has_dedicated_parser(&[0xDF, 0x01]));
+	}
+	#[test]
+	fn issuer_application_data_layouts() {
+		let expected = vec![
+			Some("Issuer Application Data (Visa)"),
+			Some("Issuer Application Data (Not CCD-Compliant)"),
+		];
+		let result = [
+			vec![0x06, 0x01, 0x0A, 0x03, 0xA0, 0x00, 0x00],
+			vec![0x01, 0x02, 0x03],
+		]
+		.into_iter()
+		.map(|data| {
+			match process_emv_tag(RawEmvTag {
+				tag:              vec![0x9F, 0x10],
+				class:            TagClass::ContextSpecific,
+				data_object_type: DataObjectType::Primitive,
+				data:             EmvData::Normal(data),
+			}) {
+				Ok(
+					ProcessedEmvTag::Parsed { name, .. } | ProcessedEmvTag::Annotated { name, .. },
+				) => Some(name),
+				_ => None,
+			}
+		})
+		.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn application_identifier_unknown_rid() {
+		let result = process_emv_tag(RawEmvTag {
+			tag:              vec![0x84],
+			class:            TagClass::ContextSpecific,
+			data_object_type: DataObjectType::Primitive,
+			data:             EmvData::Normal(b"1PAY.SYS.DDF01".to_vec()),
+		});
+
+		assert!(matches!(
+			result,
+			Ok(ProcessedEmvTag::Annotated {
+				name: "Dedicated File (DF) Name",
+				..
+			})
+		));
+	}
+	#[test]
+	fn issuer_application_data_layout_names() {
+		let expected = [
+			"Issuer Application Data (Visa)",
+			"Issuer Application Data (Not CCD-Compliant)",
+			"IAD (Mastercard)",
+			"IAD (Mastercard)",
+		];
+		let result = [
+			("Issuer Application Data (CCD-Compliant)", "Visa"),
+			(
+				"Issuer Application Data (CCD-Compliant)",
+				"Not CCD-Compliant",
+			),
+			("IAD", "Mastercard"),
+			("IAD", "Mastercard"),
+		]
+		.map(|(name, layout)| issuer_application_data_layout_name(name, layout));
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn custom_tag_new() {
+		let expected = Ok(TagDefinition {
+			tag:    [0xDF, 0x81, 0x01].as_slice(),
+			name:   "Acquirer Reference",
+			parser: ParserKind::None,
+			format: DataFormat::Variable,
+			length: TagLength::Variable,
+			source: None,
+		});
+		let result = CustomTag {
+			tag:      vec![0xDF, 0x81, 0x01],
+			name:     "Acquirer Reference".to_owned(),
+			parse_as: None,
+		}
+		.into_definition();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn custom_tag_override_keeps_parser() {
+		let expected = Ok(TagDefinition {
+			tag:    [0x95].as_slice(),
+			name:   "TVR",
+			parser: ParserKind::TerminalVerificationResults,
+			format: DataFormat::Binary,
+			length: TagLength::Exactly(5),
+			source: Some(EMV_BOOK_3),
+		});
+		let result = CustomTag {
+			tag:      vec![0x95],
+			name:     "TVR".to_owned(),
+			parse_as: None,
+		}
+		.into_definition();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn custom_tag_parse_as() {
+		let expected = Ok(TagDefinition {
+			tag:    [0xDF, 0x81, 0x02].as_slice(),
+			name:   "Proprietary TTQ",
+			parser: ParserKind::TerminalTransactionQualifiers,
+			format: DataFormat::Binary,
+			length: TagLength::Exactly(4),
+			source: None,
+		});
+		let result = CustomTag {
+			tag:      vec![0xDF, 0x81, 0x02],
+			name:     "Proprietary TTQ".to_owned(),
+			parse_as: Some(vec![0x9F, 0x66]),
+		}
+		.into_definition();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn custom_tag_parse_as_unknown() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = CustomTag {
+			tag:      vec![0xDF, 0x81, 0x03],
+			name:     "Unknown".to_owned(),
+			parse_as: Some(vec![0xDF, 0x81, 0x04]),
+		}
+		.into_definition();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn custom_tags_identified() {
+		// Only tags that aren't used by other tests are set here, since the custom
+		// tags are shared by all of them
+		let expected = Ok(Some("Custom Tag"));
+		let result = set_custom_tags(vec![CustomTag {
+			tag:      vec![0xDF, 0x7F, 0x7F],
+			name:     "Custom Tag".to_owned(),
+			parse_as: None,
+		}])
+		.map(|()| identify_tag(&[0xDF, 0x7F, 0x7F]));
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,288 @@
+//! The Track 2 Equivalent Data value, typically from EMV tag `0x57`.
+//!
+//! Information for this can be found in EMV Book 3, under section `A1`, and the
+//! layout of the track itself comes from [ISO/IEC 7813](https://www.iso.org/standard/43317.html).
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{
+	emv::{Finding, Severity},
+	error::ParseError,
+	non_emv::{PrimaryAccountNumber, ServiceCode},
+	util::{bcd_with_separator_to_string, parse_str_to_u16},
+	DisplayBreakdown,
+};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+
+// Constants
+const SEPARATOR: char = 'D';
+const EXPIRY_DATE_DIGITS: usize = 4;
+const SERVICE_CODE_DIGITS: usize = 3;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Track2EquivalentData {
+	pub primary_account_number: PrimaryAccountNumber,
+	pub expiry_year:            u8,
+	pub expiry_month:           u8,
+	pub service_code:           ServiceCode,
+	pub discretionary_data:     String,
+}
+
+impl Track2EquivalentData {
+	/// Builds the value from the textual contents of the track, with
+	/// `separator` being the character that separates the PAN from the rest of
+	/// the data.
+	pub(crate) fn from_track_str(track: &str, separator: char) -> Result<Self, ParseError> {
+		let (pan_str, remainder) = track
+			.split_once(separator)
+			.ok_or(ParseError::NonCompliant)?;
+
+		if remainder.len() < EXPIRY_DATE_DIGITS + SERVICE_CODE_DIGITS
+			|| !remainder.chars().all(|c| c.is_ascii_digit())
+		{
+			return Err(ParseError::NonCompliant);
+		}
+		let (expiry_str, remainder) = remainder.split_at(EXPIRY_DATE_DIGITS);
+		let (service_code_str, discretionary_data) = remainder.split_at(SERVICE_CODE_DIGITS);
+
+		Ok(Self {
+			primary_account_number: PrimaryAccountNumber::try_from(pan_str)?,
+			expiry_year:            expiry_str[0..2]
+				.parse()
+				.map_err(|_| ParseError::InvalidNumber)?,
+			expiry_month:           expiry_str[2..4]
+				.parse()
+				.map_err(|_| ParseError::InvalidNumber)?,
+			service_code:           parse_str_to_u16(service_code_str)
+				.and_then(ServiceCode::try_from)?,
+			discretionary_data:     discretionary_data.to_owned(),
+		})
+	}
+}
+
+impl TryFrom<&[u8]> for Track2EquivalentData {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const MAX_BYTES: usize = 19;
+
+		if raw_bytes.len() > MAX_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Less,
+				expected: MAX_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Self::from_track_str(
+			bcd_with_separator_to_string(raw_bytes, SEPARATOR)?.as_str(),
+			SEPARATOR,
+		)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for Track2EquivalentData {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let bold_colour_spec = bold_colour_spec();
+
+		// PAN
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		writeln!(stdout, "PAN:").ok();
+		stdout.reset().ok();
+		self.primary_account_number.display_breakdown(
+			stdout,
+			indentation + 1,
+			show_severity_colours,
+		);
+
+		// Expiry Date
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		write!(stdout, "Expiry Date (YY/MM):").ok();
+		stdout.reset().ok();
+		write!(
+			stdout,
+			" {:0>2}/{:0>2}",
+			self.expiry_year, self.expiry_month
+		)
+		.ok();
+		if (1..=12).contains(&self.expiry_month) {
+			writeln!(stdout).ok();
+		} else {
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Warning,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(stdout, " (invalid month)").ok();
+			stdout.reset().ok();
+		}
+
+		// Service Code
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		writeln!(stdout, "Service Code:").ok();
+		stdout.reset().ok();
+		self.service_code
+			.display_breakdown(stdout, indentation + 1, show_severity_colours);
+
+		// Discretionary Data
+		if !self.discretionary_data.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&bold_colour_spec).ok();
+			write!(stdout, "Discretionary Data:").ok();
+			stdout.reset().ok();
+			writeln!(stdout, " {}", self.discretionary_data).ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"primary_account_number": self.primary_account_number.to_json_value(),
+			"expiry_year": self.expiry_year,
+			"expiry_month": self.expiry_month,
+			"service_code": self.service_code.to_json_value(),
+			"discretionary_data": self.discretionary_data,
+		})
+	}
+
+	fn get_notable_bits(&self) -> Vec<Finding> {
+		let mut notable_bits = self.primary_account_number.get_notable_bits();
+		if !(1..=12).contains(&self.expiry_month) {
+			notable_bits.push(Finding {
+				message:  format!("Invalid expiry month: {:0>2}", self.expiry_month),
+				severity: Severity::Warning,
+			});
+		}
+
+		notable_bits
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::Track2EquivalentData;
+	use crate::{
+		emv::Severity,
+		error::ParseError,
+		non_emv::{PrimaryAccountNumber, ServiceCode},
+		DisplayBreakdown,
+	};
+
+	// Tests
+	#[test]
+	fn parse_even_length() {
+		let expected = Ok(Track2EquivalentData {
+			primary_account_number: PrimaryAccountNumber::try_from("4111111111111111").unwrap(),
+			expiry_year:            25,
+			expiry_month:           12,
+			service_code:           ServiceCode::try_from(201).unwrap(),
+			discretionary_data:     "000000".to_owned(),
+		});
+		let result = Track2EquivalentData::try_from(
+			[
+				0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x22, 0x01, 0x00, 0x00,
+				0x00,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_with_padding() {
+		let expected = Ok(Track2EquivalentData {
+			primary_account_number: PrimaryAccountNumber::try_from("374245455400126").unwrap(),
+			expiry_year:            27,
+			expiry_month:           5,
+			service_code:           ServiceCode::try_from(101).unwrap(),
+			discretionary_data:     "12".to_owned(),
+		});
+		let result = Track2EquivalentData::try_from(
+			[
+				0x37, 0x42, 0x45, 0x45, 0x54, 0x00, 0x12, 0x6D, 0x27, 0x05, 0x10, 0x11, 0x2F,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_invalid_nibble() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = Track2EquivalentData::try_from(
+			[0x41, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x22, 0x0A].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_missing_separator() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = Track2EquivalentData::try_from(
+			[
+				0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x25, 0x12, 0x20, 0x1F,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_truncated_after_separator() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = Track2EquivalentData::try_from(
+			[
+				0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x2F,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn invalid_expiry_month_is_notable() {
+		let expected = Ok(vec![Severity::Warning]);
+		let result = Track2EquivalentData::try_from(
+			[
+				0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x32, 0x01,
+			]
+			.as_slice(),
+		)
+		.map(|track_2| {
+			track_2
+				.get_notable_bits()
+				.into_iter()
+				.map(|notable_bit| notable_bit.severity)
+				.collect::<Vec<_>>()
+		});
+
+		assert_eq!(expected, result);
+	}
+}
@@ -1,30 +1,48 @@
 //! The transaction type value, typically from EMV tag `0x9C`.
 //!
 //! The possible values come from the first two digits of the ISO 8583:1987
-//! Processing Code.
+//! Processing Code, encoded as BCD.
 //!
-//! This could be incomplete - it's difficult to find a complete list of values
-//! online.
+//! Payment systems can define their own values in the ranges that ISO 8583
+//! leaves reserved, so anything outside of this list is treated as
+//! unrecognised rather than invalid.
 
 // Uses
 use std::cmp::Ordering;
 
-use termcolor::StandardStream;
+use serde_json::Value as JsonValue;
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
-use crate::{enum_repr_fallible, error::ParseError, util::print_indentation, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::util::print_indentation;
+use crate::{enum_repr_fallible, error::ParseError, DisplayBreakdown};
 
 // Enum Implementation
 enum_repr_fallible! {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TransactionType: u8, ParseError, { |_| ParseError::Unrecognised } {
-	Purchase         = 0x00 => "Purchase",
-	CashAdvance      = 0x01 => "Cash Advance",
-	Void             = 0x02 => "Void",
-	CashbackPurchase = 0x09 => "Purchase With Cashback",
-	Refund           = 0x20 => "Refund",
-	BalanceInquiry   = 0x31 => "Balance Inquiry",
-	MiniStatement    = 0x38 => "Mini Statement",
-	FundTransfer     = 0x40 => "Fund Transfer",
+	Purchase                 = 0x00 => "Purchase",
+	CashAdvance              = 0x01 => "Cash Advance",
+	Void                     = 0x02 => "Void",
+	ChequeGuarantee          = 0x03 => "Cheque Guarantee",
+	ChequeVerification       = 0x04 => "Cheque Verification",
+	Eurocheque               = 0x05 => "Eurocheque",
+	TravellersCheque         = 0x06 => "Traveller's Cheque",
+	LetterOfCredit           = 0x07 => "Letter of Credit",
+	Giro                     = 0x08 => "Giro (Postal Banking)",
+	CashbackPurchase         = 0x09 => "Purchase With Cashback",
+	NonCashFinancialPurchase = 0x10 => "Non-Cash Financial Instrument Purchase",
+	QuasiCash                = 0x11 => "Quasi-Cash",
+	Refund                   = 0x20 => "Refund",
+	Deposit                  = 0x21 => "Deposit",
+	CreditAdjustment         = 0x22 => "Credit Adjustment",
+	ChequeDepositGuarantee   = 0x23 => "Cheque Deposit Guarantee",
+	ChequeDeposit            = 0x24 => "Cheque Deposit",
+	AvailableFundsInquiry    = 0x30 => "Available Funds Inquiry",
+	BalanceInquiry           = 0x31 => "Balance Inquiry",
+	MiniStatement            = 0x38 => "Mini Statement",
+	FundTransfer             = 0x40 => "Fund Transfer",
 }
 }
 
@@ -48,9 +66,14 @@ impl TryFrom<&[u8]> for TransactionType {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for TransactionType {
-	fn display_breakdown(&self, _: &mut StandardStream, indentation: u8, _: bool) {
-		print_indentation(indentation);
-		println!("{self}");
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		writeln!(stdout, "{self}").ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		JsonValue::String(self.to_string())
 	}
 }
 
@@ -58,7 +81,12 @@ impl DisplayBreakdown for TransactionType {
 #[cfg(test)]
 mod tests {
 	// Uses
-	use crate::{enum_byte_slice_result_matches_true_value_result, wrong_byte_count};
+	use super::TransactionType;
+	use crate::{
+		enum_byte_slice_result_matches_true_value_result,
+		error::ParseError,
+		wrong_byte_count,
+	};
 
 	// Tests
 	wrong_byte_count!(super::TransactionType, 1);
@@ -68,4 +96,27 @@ mod tests {
 		0x20,
 		[0x20].as_slice()
 	);
+
+	#[test]
+	fn parse_iso_8583_values() {
+		let expected = Ok(vec![
+			TransactionType::Purchase,
+			TransactionType::CashbackPurchase,
+			TransactionType::Deposit,
+			TransactionType::AvailableFundsInquiry,
+		]);
+		let result = [0x00, 0x09, 0x21, 0x30]
+			.into_iter()
+			.map(TransactionType::try_from)
+			.collect::<Result<Vec<_>, _>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_unrecognised() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = TransactionType::try_from(0x99);
+
+		assert_eq!(expected, result);
+	}
 }
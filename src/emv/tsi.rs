@@ -27,10 +27,27 @@ pub struct TransactionStatusInformation: 2 {
 #[cfg(test)]
 mod tests {
 	// Uses
-	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+	use crate::{
+		bitflag_display_bits,
+		bitflag_unique_values,
+		display_breakdown_matches,
+		wrong_byte_count,
+	};
 
 	// Tests
 	wrong_byte_count!(super::TransactionStatusInformation, 2);
 	bitflag_unique_values!(super::TransactionStatusInformation, 2);
 	bitflag_display_bits!(super::TransactionStatusInformation, 2);
+	display_breakdown_matches!(
+		display_breakdown,
+		super::TransactionStatusInformation::try_from([0xE8, 0x00].as_slice())
+			.expect("the TSI is valid"),
+		concat!(
+			"11101000 00000000\n",
+			"│││ └ Terminal risk management was performed\n",
+			"││└ Card risk management was performed\n",
+			"│└ Cardholder verification was performed\n",
+			"└ Offline data authentication was performed\n",
+		)
+	);
 }
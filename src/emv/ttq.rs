@@ -0,0 +1,63 @@
+//! Everything for handling Terminal Transaction Qualifiers (TTQ) values,
+//! typically from EMV tag `0x9F66`.
+//!
+//! The TTQ is used by contactless kernels to indicate the terminal's
+//! capabilities and requirements for the transaction.
+//!
+//! Information for this can be found in EMV Contactless Book C-3, under
+//! section `A.2`.
+
+// Uses
+use std::cmp::Ordering;
+
+use crate::{bitflag_value, error::ParseError};
+
+// Struct Implementation
+bitflag_value! {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TerminalTransactionQualifiers: 4 {
+	0 {
+		pub msd_supported: bool =                           0b1000_0000
+			=> "Mag-stripe mode (MSD) supported",
+		pub qvsdc_supported: bool =                         0b0010_0000
+			=> "EMV mode (qVSDC) supported",
+		pub emv_contact_chip_supported: bool =              0b0001_0000
+			=> "EMV contact chip supported",
+		pub offline_only_reader: bool =                     0b0000_1000
+			=> "Offline-only reader",
+		pub online_pin_supported: bool =                    0b0000_0100
+			=> "Online PIN supported",
+		pub signature_supported: bool =                     0b0000_0010
+			=> "Signature supported",
+		pub oda_for_online_authorisations_supported: bool = 0b0000_0001
+			=> "Offline data authentication for online authorisations supported",
+	}
+	1 {
+		pub online_cryptogram_required: bool =              0b1000_0000
+			=> "Online cryptogram required",
+		pub cvm_required: bool =                            0b0100_0000
+			=> "CVM required",
+		pub contact_chip_offline_pin_supported: bool =      0b0010_0000
+			=> "(Contact chip) Offline PIN supported",
+	}
+	2 {
+		pub issuer_update_processing_supported: bool =      0b1000_0000
+			=> "Issuer update processing supported",
+		pub consumer_device_cvm_supported: bool =           0b0100_0000
+			=> "Consumer device CVM supported",
+	}
+	3 {}
+}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::TerminalTransactionQualifiers, 4);
+	bitflag_unique_values!(super::TerminalTransactionQualifiers, 4);
+	bitflag_display_bits!(super::TerminalTransactionQualifiers, 4);
+}
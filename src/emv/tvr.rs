@@ -81,10 +81,45 @@ pub struct TerminalVerificationResults: 5 {
 #[cfg(test)]
 mod tests {
 	// Uses
-	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+	use crate::{
+		bitflag_display_bits,
+		bitflag_unique_values,
+		display_breakdown_matches,
+		wrong_byte_count,
+	};
 
 	// Tests
 	wrong_byte_count!(super::TerminalVerificationResults, 5);
 	bitflag_unique_values!(super::TerminalVerificationResults, 5);
 	bitflag_display_bits!(super::TerminalVerificationResults, 5);
+	display_breakdown_matches!(
+		display_breakdown,
+		super::TerminalVerificationResults::try_from([0x80, 0x00, 0x80, 0x08, 0x00].as_slice())
+			.expect("the TVR is valid"),
+		concat!(
+			"10000000 00000000 10000000 00001000 00000000\n",
+			"│                 │            └ Merchant forced transaction online\n",
+			"│                 └ Cardholder verification was not successful\n",
+			"└ Offline data authentication was not performed\n",
+		)
+	);
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serialise() {
+		let expected = Some((true, false));
+		let result = super::TerminalVerificationResults::try_from(
+			[0b0100_0000, 0x00, 0x00, 0x00, 0x00].as_slice(),
+		)
+		.ok()
+		.and_then(|tvr| serde_json::to_value(tvr).ok())
+		.and_then(|value| {
+			Some((
+				value["sda_failed"].as_bool()?,
+				value["icc_data_missing"].as_bool()?,
+			))
+		});
+
+		assert_eq!(expected, result);
+	}
 }
@@ -0,0 +1,76 @@
+//! Everything for handling Visa Card Verification Results (CVR) values.
+//!
+//! Information for this can be found in the Visa Integrated Circuit Card
+//! Specification (VIS), under section `A.13`.
+
+// Uses
+use std::{cmp::Ordering, fmt::Debug};
+
+use crate::{
+	bitflag_value,
+	emv::ccd::{GenAc1ApplicationCryptogramType, GenAc2ApplicationCryptogramType},
+	error::ParseError,
+};
+
+// Struct Implementation
+bitflag_value! {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct VisaCardVerificationResults: 3 {
+	0 {
+		pub gen_ac_2_application_cryptogram_type: GenAc2ApplicationCryptogramType = (0b1100_0000 >> 6)
+			=> (Normal, "Application cryptogram type returned in 2nd GENERATE AC: {}"),
+		pub gen_ac_1_application_cryptogram_type: GenAc1ApplicationCryptogramType = (0b0011_0000 >> 4)
+			=> (Normal, "Application cryptogram type returned in 1st GENERATE AC: {}"),
+		pub issuer_authentication_failed: bool =                                     0b0000_1000
+			=> (Error, "Issuer authentication performed and failed"),
+		pub offline_pin_verification_performed: bool =                               0b0000_0100
+			=> "Offline PIN verification performed",
+		pub offline_pin_verification_failed: bool =                                  0b0000_0010
+			=> (Error, "Offline PIN verification performed and PIN not successfully verified"),
+		pub unable_to_go_online: bool =                                              0b0000_0001
+			=> (Warning, "Unable to go online"),
+	}
+	1 {
+		pub last_online_transaction_not_completed: bool =                            0b1000_0000
+			=> (Warning, "Last online transaction not completed"),
+		pub pin_try_limit_exceeded: bool =                                           0b0100_0000
+			=> (Error, "PIN try limit exceeded"),
+		pub velocity_checking_counters_exceeded: bool =                              0b0010_0000
+			=> (Warning, "Exceeded velocity checking counters"),
+		pub new_card: bool =                                                         0b0001_0000
+			=> "New card",
+		pub issuer_authentication_failed_on_last_online_transaction: bool =          0b0000_1000
+			=> (Warning, "Issuer authentication failure on last online transaction"),
+		pub issuer_authentication_not_performed_after_online_authorisation: bool =   0b0000_0100
+			=> (Warning, "Issuer authentication not performed after online authorisation"),
+		pub application_blocked_pin_try_limit_exceeded: bool =                       0b0000_0010
+			=> (Error, "Application blocked by card because PIN try limit exceeded"),
+		pub offline_sda_failed_on_last_transaction: bool =                           0b0000_0001
+			=> (Warning, "Offline static data authentication failed on last transaction and \
+				 transaction declined offline"),
+	}
+	2 {
+		pub issuer_script_commands_with_secure_messaging: u8 =                      (0b1111_0000 >> 4)
+			=> (Normal, "Number of issuer script commands containing secure messaging processed: {}"),
+		pub issuer_script_processing_failed: bool =                                  0b0000_1000
+			=> (Error, "Issuer script processing failed"),
+		pub offline_dda_failed_on_last_transaction: bool =                           0b0000_0100
+			=> (Warning, "Offline dynamic data authentication failed on last transaction and \
+				 transaction declined offline"),
+		pub offline_dda_performed: bool =                                            0b0000_0010
+			=> "Offline dynamic data authentication performed",
+	}
+}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use crate::{bitflag_display_bits, bitflag_unique_values, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::VisaCardVerificationResults, 3);
+	bitflag_unique_values!(super::VisaCardVerificationResults, 3);
+	bitflag_display_bits!(super::VisaCardVerificationResults, 3);
+}
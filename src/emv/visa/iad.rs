@@ -0,0 +1,249 @@
+//! Everything for handling Visa Issuer Application Data (IAD).
+//!
+//! Information for this can be found in the Visa Integrated Circuit Card
+//! Specification (VIS), under section `A.15`. This handles IAD formats 0, 1,
+//! and 3, which share the same layout for the Visa Discretionary Data.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::VisaCardVerificationResults;
+use crate::{error::ParseError, util::bytes_to_str, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::header_colour_spec,
+	util::{print_bytes, print_indentation},
+};
+
+// Constants
+/// The length of the Visa Discretionary Data, not including the length byte
+/// itself.
+const VISA_DISCRETIONARY_DATA_LENGTH: u8 = 0x06;
+/// The length of the CVR, not including the length byte itself.
+const CVR_LENGTH: u8 = 0x03;
+/// The number of bytes in the Visa Discretionary Data, including the length
+/// byte.
+const VISA_DISCRETIONARY_DATA_BYTES: usize = 1 + VISA_DISCRETIONARY_DATA_LENGTH as usize;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct VisaIssuerApplicationData {
+	/// The Derivation Key Index (DKI).
+	pub dki: u8,
+	/// The Cryptogram Version Number (CVN).
+	pub cryptogram_version_number: u8,
+	pub cvr: VisaCardVerificationResults,
+	pub issuer_discretionary_data: Option<Vec<u8>>,
+}
+
+impl TryFrom<&[u8]> for VisaIssuerApplicationData {
+	type Error = ParseError;
+
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		// Byte 0 is the length of the Visa Discretionary Data
+		// Byte 3 is the length of the CVR
+		if bytes.len() < VISA_DISCRETIONARY_DATA_BYTES
+			|| bytes[0] != VISA_DISCRETIONARY_DATA_LENGTH
+			|| bytes[3] != CVR_LENGTH
+		{
+			return Err(ParseError::Unrecognised);
+		}
+
+		let dki = bytes[1];
+		let cryptogram_version_number = bytes[2];
+		let cvr = VisaCardVerificationResults::try_from(&bytes[4..VISA_DISCRETIONARY_DATA_BYTES])?;
+
+		// The Issuer Discretionary Data is optional, but if it's present it has its own
+		// length byte
+		let issuer_discretionary_data = match &bytes[VISA_DISCRETIONARY_DATA_BYTES..] {
+			[] => None,
+			[length, data @ ..] if usize::from(*length) == data.len() => Some(data.to_vec()),
+			_ => return Err(ParseError::NonCompliant),
+		};
+
+		Ok(Self {
+			dki,
+			cryptogram_version_number,
+			cvr,
+			issuer_discretionary_data,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for VisaIssuerApplicationData {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let header_colour_spec = header_colour_spec();
+
+		// Print the DKI
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Derivation Key Index:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {:#04X}", self.dki).ok();
+
+		// Print the CVN
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Cryptogram Version Number:").ok();
+		stdout.reset().ok();
+		writeln!(
+			stdout,
+			" {} ({:#04X})",
+			self.cryptogram_version_number, self.cryptogram_version_number
+		)
+		.ok();
+
+		// Print the CVR
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(stdout, "Card Verification Results:").ok();
+		stdout.reset().ok();
+		self.cvr
+			.display_breakdown(stdout, indentation + 1, show_severity_colours);
+
+		// Print the issuer-discretionary data
+		if let Some(issuer_discretionary_data) = &self.issuer_discretionary_data {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Issuer-Discretionary Data").ok();
+			stdout.reset().ok();
+			print_bytes(
+				stdout,
+				issuer_discretionary_data.as_slice(),
+				16,
+				indentation + 1,
+			);
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"dki": self.dki,
+			"cryptogram_version_number": self.cryptogram_version_number,
+			"cvr": self.cvr.to_json_value(),
+			"issuer_discretionary_data": self
+				.issuer_discretionary_data
+				.as_ref()
+				.map(|data| bytes_to_str(data.as_slice())),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{super::VisaCardVerificationResults, VisaIssuerApplicationData};
+	use crate::{
+		emv::ccd::{GenAc1ApplicationCryptogramType, GenAc2ApplicationCryptogramType},
+		error::ParseError,
+	};
+
+	// Tests
+	#[test]
+	fn cvn_10() {
+		let expected = Ok(VisaIssuerApplicationData {
+			dki: 0x01,
+			cryptogram_version_number: 0x0A,
+			cvr: VisaCardVerificationResults {
+				gen_ac_2_application_cryptogram_type:
+					GenAc2ApplicationCryptogramType::SecondGenAcNotRequested,
+				gen_ac_1_application_cryptogram_type: GenAc1ApplicationCryptogramType::Arqc,
+				issuer_authentication_failed: false,
+				offline_pin_verification_performed: false,
+				offline_pin_verification_failed: false,
+				unable_to_go_online: false,
+				last_online_transaction_not_completed: false,
+				pin_try_limit_exceeded: false,
+				velocity_checking_counters_exceeded: false,
+				new_card: false,
+				issuer_authentication_failed_on_last_online_transaction: false,
+				issuer_authentication_not_performed_after_online_authorisation: false,
+				application_blocked_pin_try_limit_exceeded: false,
+				offline_sda_failed_on_last_transaction: false,
+				issuer_script_commands_with_secure_messaging: 0,
+				issuer_script_processing_failed: false,
+				offline_dda_failed_on_last_transaction: false,
+				offline_dda_performed: false,
+			},
+			issuer_discretionary_data: None,
+		});
+		let result = VisaIssuerApplicationData::try_from(
+			[0x06, 0x01, 0x0A, 0x03, 0xA0, 0x00, 0x00].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn cvn_18_with_issuer_discretionary_data() {
+		let expected = Ok(VisaIssuerApplicationData {
+			dki: 0x01,
+			cryptogram_version_number: 0x12,
+			cvr: VisaCardVerificationResults {
+				gen_ac_2_application_cryptogram_type:
+					GenAc2ApplicationCryptogramType::SecondGenAcNotRequested,
+				gen_ac_1_application_cryptogram_type: GenAc1ApplicationCryptogramType::Arqc,
+				issuer_authentication_failed: false,
+				offline_pin_verification_performed: false,
+				offline_pin_verification_failed: false,
+				unable_to_go_online: false,
+				last_online_transaction_not_completed: false,
+				pin_try_limit_exceeded: false,
+				velocity_checking_counters_exceeded: true,
+				new_card: false,
+				issuer_authentication_failed_on_last_online_transaction: false,
+				issuer_authentication_not_performed_after_online_authorisation: false,
+				application_blocked_pin_try_limit_exceeded: false,
+				offline_sda_failed_on_last_transaction: false,
+				issuer_script_commands_with_secure_messaging: 0,
+				issuer_script_processing_failed: false,
+				offline_dda_failed_on_last_transaction: false,
+				offline_dda_performed: true,
+			},
+			issuer_discretionary_data: Some(vec![
+				0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+				0x0F,
+			]),
+		});
+		let result = VisaIssuerApplicationData::try_from(
+			[
+				0x06, 0x01, 0x12, 0x03, 0xA0, 0x20, 0x02, 0x0F, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+				0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn not_visa_layout() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = VisaIssuerApplicationData::try_from(
+			[0x0F, 0xA5, 0x01, 0xA2, 0x30, 0x30, 0x10].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn issuer_discretionary_data_wrong_length() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = VisaIssuerApplicationData::try_from(
+			[0x06, 0x01, 0x0A, 0x03, 0xA0, 0x00, 0x00, 0x0F, 0x01].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,12 @@
+//! Visa-specific values. Visa cards don't follow the EMV Common Core
+//! Definitions (CCD), so their proprietary layouts are handled here.
+//!
+//! Information for this can be found in the Visa Integrated Circuit Card
+//! Specification (VIS), under `Appendix A`.
+
+// Modules
+mod cvr;
+mod iad;
+
+// Public Exports
+pub use self::{cvr::*, iad::*};
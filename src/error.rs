@@ -4,9 +4,17 @@
 use std::{
 	cmp::Ordering,
 	convert::Infallible,
+	error::Error,
 	fmt::{Display, Formatter, Result as FmtResult},
 };
 
+use crate::util::bytes_to_str;
+
+// Constants
+/// How many times larger than the available data a declared length has to be
+/// before it's more likely that the data is misaligned than truncated.
+const IMPLAUSIBLE_LENGTH_FACTOR: usize = 4;
+
 /// An error that occurred during parsing.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum ParseError {
@@ -17,6 +25,21 @@ pub enum ParseError {
 		found:    usize,
 	},
 	ByteCountNotDivisibleIntoComponents,
+	/// A tag declared a length longer than the data that's actually available.
+	LengthExceedsData {
+		tag:       Vec<u8>,
+		/// The offset of the start of the tag in the data.
+		offset:    usize,
+		declared:  usize,
+		available: usize,
+	},
+	/// No input was provided, or there was no data left in it once it was
+	/// cleaned up (like if it was only whitespace or non-hex characters).
+	EmptyInput,
+	/// Constructed tags were nested deeper than the parser allows.
+	NestingTooDeep {
+		max_depth: usize,
+	},
 	/// The bytes provided are not valid.
 	InvalidBytes,
 	/// The string provided couldn't be parsed as a number.
@@ -53,13 +76,14 @@ impl Display for ParseError {
 			} => write!(
 				f,
 				"The wrong number of bytes were provided for the value. Perhaps you provided the \
-				 wrong value? Expected: {} {}, Found: {}",
+				 wrong value? Expected {} {} byte{}, found {}.",
 				match r#type {
-					Ordering::Less => "less than or equal to",
+					Ordering::Less => "at most",
 					Ordering::Equal => "exactly",
-					Ordering::Greater => "greater than or equal to",
+					Ordering::Greater => "at least",
 				},
 				expected,
+				if *expected == 1 { "" } else { "s" },
 				found
 			),
 			Self::ByteCountNotDivisibleIntoComponents => write!(
@@ -67,6 +91,50 @@ impl Display for ParseError {
 				"The number of bytes provided is not divisible into the components of the value. \
 				 Please check the format of the input data."
 			),
+			Self::LengthExceedsData {
+				tag,
+				offset,
+				declared,
+				available,
+			} => {
+				write!(
+					f,
+					"The tag {} at offset {} declares a length of {} byte{}, but only {} byte{} \
+					 available ({} missing). ",
+					bytes_to_str(tag.as_slice()),
+					offset,
+					declared,
+					if *declared == 1 { "" } else { "s" },
+					available,
+					if *available == 1 { " is" } else { "s are" },
+					declared - available
+				)?;
+				// A length that's far larger than the rest of the data is more likely to be
+				// some other byte being read as the length
+				if *declared
+					> (*available)
+						.max(1)
+						.saturating_mul(IMPLAUSIBLE_LENGTH_FACTOR)
+				{
+					write!(
+						f,
+						"That's far more than the remaining data, so the data may be misaligned. \
+						 Perhaps a byte was dropped or added before this tag?"
+					)
+				} else {
+					write!(f, "Perhaps the data was truncated?")
+				}
+			}
+			Self::EmptyInput => write!(
+				f,
+				"No data was found in the value. Please check that the input isn't empty, and \
+				 that it's in the expected format."
+			),
+			Self::NestingTooDeep { max_depth } => write!(
+				f,
+				"The data has constructed tags nested more than {max_depth} levels deep. It's \
+				 likely not BER-TLV data, or it's been corrupted."
+			),
 			Self::InvalidBytes => write!(f, "The bytes provided are not valid."),
 			Self::InvalidNumber => write!(
 				f,
@@ -97,3 +165,113 @@ impl Display for ParseError {
 		}
 	}
 }
+
+impl Error for ParseError {}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::{cmp::Ordering, error::Error};
+
+	use super::ParseError;
+
+	// Tests
+	#[test]
+	fn display_messages() {
+		let expected = vec![
+			"The wrong number of bytes were provided for the value. Perhaps you provided the \
+			 wrong value? Expected exactly 1 byte, found 2.",
+			"The wrong number of bytes were provided for the value. Perhaps you provided the \
+			 wrong value? Expected at least 4 bytes, found 2.",
+			"The number of bytes provided is not divisible into the components of the value. \
+			 Please check the format of the input data.",
+			"The tag 5A at offset 3 declares a length of 8 bytes, but only 2 bytes are available \
+			 (6 missing). Perhaps the data was truncated?",
+			"The tag 5A at offset 3 declares a length of 127 bytes, but only 2 bytes are \
+			 available (125 missing). That's far more than the remaining data, so the data may be \
+			 misaligned. Perhaps a byte was dropped or added before this tag?",
+			"No data was found in the value. Please check that the input isn't empty, and that \
+			 it's in the expected format.",
+			"The data has constructed tags nested more than 32 levels deep. It's likely not \
+			 BER-TLV data, or it's been corrupted.",
+			"The bytes provided are not valid.",
+			"The value provided is not a valid number, or is too large.",
+			"The value provided isn't compliant with the specifications in some way, or this tool \
+			 is out of date. If you have reason to believe it's the latter, please make sure \
+			 you're using the latest version then open an issue on GitHub.",
+			"The value provided isn't compliant with the EMV Common Core Definitions (CCD) in \
+			 some way. This isn't a problem necessarily, but it does mean that the value can't be \
+			 parsed.",
+			"The value provided is in some way unsupported. If you have genuine need for the \
+			 unsupported feature, please open an issue on GitHub.",
+			"The value provided is in some way unrecognised. This isn't a problem necessarily, \
+			 but it does mean that the value can't be parsed.",
+		];
+		let result = [
+			ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: 1,
+				found:    2,
+			},
+			ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Greater,
+				expected: 4,
+				found:    2,
+			},
+			ParseError::ByteCountNotDivisibleIntoComponents,
+			ParseError::LengthExceedsData {
+				tag:       vec![0x5A],
+				offset:    3,
+				declared:  8,
+				available: 2,
+			},
+			ParseError::LengthExceedsData {
+				tag:       vec![0x5A],
+				offset:    3,
+				declared:  127,
+				available: 2,
+			},
+			ParseError::EmptyInput,
+			ParseError::NestingTooDeep { max_depth: 32 },
+			ParseError::InvalidBytes,
+			ParseError::InvalidNumber,
+			ParseError::NonCompliant,
+			ParseError::NonCcdCompliant,
+			ParseError::Unsupported,
+			ParseError::Unrecognised,
+		]
+		.iter()
+		.map(ToString::to_string)
+		.collect::<Vec<_>>();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn length_exceeds_data_single_byte() {
+		let expected = [
+			"The tag 5A at offset 0 declares a length of 1 byte, but only 0 bytes are available \
+			 (1 missing). Perhaps the data was truncated?",
+			"The tag 5A at offset 0 declares a length of 2 bytes, but only 1 byte is available (1 \
+			 missing). Perhaps the data was truncated?",
+		];
+		let result = [(1, 0), (2, 1)].map(|(declared, available)| {
+			ParseError::LengthExceedsData {
+				tag: vec![0x5A],
+				offset: 0,
+				declared,
+				available,
+			}
+			.to_string()
+		});
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn usable_as_error() {
+		let expected = "The bytes provided are not valid.".to_owned();
+		let result = Box::<dyn Error>::from(ParseError::InvalidBytes).to_string();
+
+		assert_eq!(expected, result);
+	}
+}
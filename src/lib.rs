@@ -0,0 +1,228 @@
+//! A library for parsing EMV-related data.
+//!
+//! The main entry points are the TLV parsers ([`parse_ber_tlv`],
+//! [`parse_ingenico_tlv`], and [`parse_auto_tlv`]), whose results can be
+//! processed into a [`ProcessedEmvBlock`] for annotation and parsing of the
+//! individual tags. The individual value types can also be parsed on their own.
+
+// Linting Rules
+#![warn(
+	clippy::complexity,
+	clippy::correctness,
+	clippy::pedantic,
+	clippy::perf,
+	clippy::style,
+	clippy::suspicious,
+	clippy::clone_on_ref_ptr,
+	clippy::dbg_macro,
+	clippy::decimal_literal_representation,
+	clippy::exit,
+	clippy::filetype_is_file,
+	clippy::if_then_some_else_none,
+	clippy::non_ascii_literal,
+	clippy::self_named_module_files,
+	clippy::str_to_string,
+	clippy::undocumented_unsafe_blocks,
+	clippy::wildcard_enum_match_arm
+)]
+#![allow(
+	clippy::cast_possible_truncation,
+	clippy::cast_possible_wrap,
+	clippy::cast_precision_loss,
+	clippy::cast_sign_loss,
+	clippy::doc_markdown,
+	clippy::missing_errors_doc,
+	clippy::missing_panics_doc,
+	clippy::module_name_repetitions,
+	clippy::must_use_candidate,
+	clippy::similar_names,
+	clippy::struct_excessive_bools,
+	clippy::too_many_lines,
+	clippy::unnecessary_wraps,
+	dead_code,
+	unused_macros
+)]
+
+// Modules
+pub mod emv;
+pub mod error;
+mod macros;
+pub mod non_emv;
+#[cfg(feature = "display")]
+pub mod output_colours;
+mod testing_macros;
+pub mod util;
+
+// Uses
+use std::{any::Any, fmt::Debug};
+
+use serde_json::Value as JsonValue;
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::emv::Finding;
+// Public Exports
+pub use crate::{
+	emv::{
+		auto_tlv::parse as parse_auto_tlv,
+		ber_tlv::{parse as parse_ber_tlv, parse_strict as parse_ber_tlv_strict},
+		ccd::{CardVerificationResults, CommonCoreIdentifier},
+		compact_tlv::parse as parse_compact_tlv,
+		ingenico_tlv::parse as parse_ingenico_tlv,
+		AdditionalTerminalCapabilities,
+		BitflagValue,
+		CardholderVerificationMethodResults,
+		CardholderVerificationRule,
+		ProcessedEmvBlock,
+		TerminalCapabilities,
+		TerminalVerificationResults,
+		TransactionStatusInformation,
+	},
+	error::ParseError,
+	non_emv::ServiceCode,
+};
+
+// Constants
+pub const BITS_PER_BYTE: u8 = 8;
+
+// Traits
+/// A simple trait for displaying a comprehensive breakdown of the value.
+///
+/// Separate from [`Display`] because it represents a more significant operation
+/// than simply printing a small value, and because it can handle coloured
+/// output.
+///
+/// It requires [`Any`] so that parsed values can be downcast back into their
+/// concrete types.
+///
+/// [`Display`]: core::fmt::Display
+#[cfg(not(tarpaulin_include))]
+pub trait DisplayBreakdown: Any + Debug {
+	/// Displays a pretty breakdown of the value and every part's meaning.
+	///
+	/// The output is written to `stdout`, which is usually the terminal but can
+	/// be any coloured writer, like a [`termcolor::Buffer`].
+	///
+	/// The indentation should be applied to every line. It's used to allow the
+	/// display of nested values.
+	///
+	/// Only available with the `display` feature.
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	);
+
+	/// Builds a machine-readable representation of the value and every part's
+	/// meaning, for use with the JSON output format.
+	fn to_json_value(&self) -> JsonValue;
+
+	/// Fetches the parts of the value that are worth drawing attention to,
+	/// meaning the enabled bits with a severity other than
+	/// [`Severity::Normal`].
+	///
+	/// Most values that aren't bitflags have nothing to draw attention to, so
+	/// the default is empty. Those that can be invalid, like a PAN that fails
+	/// the Luhn check, report the problem as a [`Finding`] instead.
+	///
+	/// [`Severity::Normal`]: crate::emv::Severity::Normal
+	fn get_notable_bits(&self) -> Vec<Finding> {
+		Vec::new()
+	}
+}
+
+// Unit Tests
+/// This is because Tarpaulin currently recognises every enum variant line
+/// as uncovered because the [`Display`] impl was never called in testing.
+///
+/// It also marks each fallible enum's error function as uncovered, so that's
+/// also handled here.
+///
+/// `#[cfg(not(tarpaulin_include))]` doesn't work here unfortunately, and
+/// the number of 'uncovered' lines is too large to ignore. (>50% of lines
+/// in some files)
+#[cfg(test)]
+#[cfg(tarpaulin)]
+mod tests {
+	// Uses
+	use crate::{
+		emv::{
+			ccd::{
+				CryptogramVersion,
+				FormatCode,
+				GenAc1ApplicationCryptogramType,
+				GenAc2ApplicationCryptogramType,
+			},
+			AccountType,
+			AuthorisationResponseCode,
+			CvMethod,
+			CvmCondition,
+			CvmResult,
+			PinEntryCapability,
+			PosEntryMode,
+			TagClass,
+			TerminalType,
+			TransactionType,
+		},
+		non_emv::{
+			AllowedServices,
+			AuthorisationProcessing,
+			Convention,
+			Instruction,
+			Interchange,
+			PinRequirements,
+			StatusWordCategory,
+			Technology,
+		},
+	};
+
+	#[test]
+	fn cover_all_enum_variants() {
+		AccountType::cover_all_enum_variants();
+		AccountType::try_from(0xFF);
+		AllowedServices::cover_all_enum_variants();
+		AllowedServices::try_from(0xFF);
+		AuthorisationProcessing::cover_all_enum_variants();
+		AuthorisationProcessing::try_from(0xFF);
+		AuthorisationResponseCode::cover_all_enum_variants();
+		AuthorisationResponseCode::try_from("");
+		Convention::cover_all_enum_variants();
+		Convention::try_from(0xFF);
+		CryptogramVersion::cover_all_enum_variants();
+		CryptogramVersion::try_from(0xFF);
+		CvmCondition::cover_all_enum_variants();
+		CvmCondition::try_from(0xFF);
+		CvMethod::cover_all_enum_variants();
+		CvMethod::try_from(0xFF);
+		CvmResult::cover_all_enum_variants();
+		CvmResult::try_from(0xFF);
+		FormatCode::cover_all_enum_variants();
+		FormatCode::try_from(0xFF);
+		GenAc1ApplicationCryptogramType::cover_all_enum_variants();
+		GenAc1ApplicationCryptogramType::try_from(0xFF);
+		GenAc2ApplicationCryptogramType::cover_all_enum_variants();
+		GenAc2ApplicationCryptogramType::try_from(0xFF);
+		Instruction::cover_all_enum_variants();
+		Instruction::try_from(0xFF);
+		Interchange::cover_all_enum_variants();
+		Interchange::try_from(0xFF);
+		PinRequirements::cover_all_enum_variants();
+		PinRequirements::try_from(0xFF);
+		PinEntryCapability::cover_all_enum_variants();
+		PinEntryCapability::try_from(0xFF);
+		PosEntryMode::cover_all_enum_variants();
+		PosEntryMode::try_from(0xFF);
+		StatusWordCategory::cover_all_enum_variants();
+		StatusWordCategory::try_from(0xFF);
+		TagClass::cover_all_enum_variants();
+		TagClass::try_from(0xFF);
+		Technology::cover_all_enum_variants();
+		Technology::try_from(0xFF);
+		TerminalType::cover_all_enum_variants();
+		TerminalType::try_from(0xFF);
+		TransactionType::cover_all_enum_variants();
+		TransactionType::try_from(0xFF);
+	}
+}
@@ -18,6 +18,7 @@ macro_rules! enum_no_repr_fallible {
 	    }
 	) => {
 		$(#[$outer])*
+		#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
         $visibility enum $name {
             $(
                 #[doc = concat!($string, " - ", stringify!($pattern))]
@@ -78,6 +79,7 @@ macro_rules! enum_no_repr_infallible {
 	    }
 	) => {
 		$(#[$outer])*
+		#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
         $visibility enum $name {
             $(
                 #[doc = concat!($string, " - ", stringify!($pattern))]
@@ -134,6 +136,7 @@ macro_rules! enum_repr_fallible {
 	) => {
 		#[repr($typ)]
 		$(#[$outer])*
+		#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
         $visibility enum $name {
             $(
                 #[doc = concat!($string, " - ", stringify!($value))]
@@ -200,6 +203,7 @@ macro_rules! enum_repr_infallible {
 	) => {
 		#[repr($typ)]
 		$(#[$outer])*
+		#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
         $visibility enum $name {
             $(
                 #[doc = concat!($string, " - ", stringify!($value))]
@@ -264,6 +268,7 @@ macro_rules! bitflag_value {
 	    }
 	) => {
 		$(#[$outer])*
+		#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
         $visibility struct $name {
             $($(
                 $(#[doc = $doc])?
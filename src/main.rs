@@ -1,4 +1,4 @@
-//! A utility for parsing EMV-related data.
+//! The command-line utility for parsing EMV-related data.
 
 // Linting Rules
 #![warn(
@@ -40,259 +40,852 @@ extern crate core;
 // Modules
 mod cli;
 mod config;
-mod emv;
-mod error;
-mod macros;
-mod non_emv;
-mod output_colours;
-mod testing_macros;
-mod util;
 
 // Uses
-use std::fmt::Debug;
-
-use termcolor::{StandardStream, WriteColor};
+use std::{
+	cell::Cell,
+	fs::{read, read_to_string},
+	io::{read_to_string as read_reader_to_string, stdin, Result as IoResult, Write},
+	process::ExitCode,
+};
 
-use crate::{
-	cli::build_cli,
-	config::{apply_cli_arguments, colour_choice::ColourChoice, Config},
+use clap::{ArgMatches, Id};
+use pemv::{
 	emv::{
-		auto_tlv::parse as parse_auto_tlv,
-		ber_tlv::parse as parse_ber_tlv,
-		ccd::{CardVerificationResults, IssuerApplicationData},
-		ingenico_tlv::parse as parse_ingenico_tlv,
+		auto_tlv::{detect as detect_tlv_format, detect_strict as detect_tlv_format_strict},
+		ber_tlv::{
+			encode as encode_ber_tlv,
+			parse_tag_value_list,
+			parse_with_spans as parse_ber_tlv_with_spans,
+			print_spans as print_tag_spans,
+		},
+		ccd::{CardVerificationResults, CommonCoreIdentifier, IssuerApplicationData},
+		dgi::parse as parse_dgi,
+		diff::compare as compare_tlv_blocks,
+		set_custom_tags,
 		CardholderVerificationMethodList,
 		CardholderVerificationMethodResults,
-		ProcessedEmvBlock,
+		DisplaySettings,
+		Finding,
+		MaskingMode as LibMaskingMode,
+		MaskingSettings,
+		Severity,
+		SeverityCounts,
 		TagBasicInfo,
+		TagList,
 		TerminalVerificationResults,
 		TransactionStatusInformation,
 	},
-	non_emv::ServiceCode,
-	output_colours::header_colour_spec,
-	util::{parse_hex_str, parse_str_to_u16},
+	non_emv::{
+		AnswerToReset,
+		Apdu,
+		LuhnCheck,
+		LuhnCompletion,
+		ResponseApdu,
+		StatusWord,
+		Track1Data,
+		Track2Data,
+	},
+	output_colours::{header_colour_spec, print_severity_legend, severity_colour_spec},
+	parse_auto_tlv,
+	parse_compact_tlv,
+	parse_ingenico_tlv,
+	util::{
+		bytes_to_str,
+		parse_base64_str,
+		parse_hex_str,
+		parse_hex_str_exact,
+		parse_hex_str_strict,
+		parse_str_to_u16,
+		print_indentation,
+		set_indentation_unit,
+	},
+	DisplayBreakdown,
+	ParseError,
+	ProcessedEmvBlock,
+	ServiceCode,
 };
+use serde_json::{json, Value as JsonValue};
+use termcolor::{StandardStream, WriteColor};
 
-// Constants
-pub const BITS_PER_BYTE: u8 = 8;
+use crate::{
+	cli::build_cli,
+	config::{
+		apply_cli_arguments,
+		output_format::OutputFormat,
+		tag_dictionary::load_tag_dictionary,
+		Config,
+	},
+};
 
-// Traits
-/// A simple trait for displaying a comprehensive breakdown of the value.
-///
-/// Separate from [`Display`] because it represents a more significant operation
-/// than simply printing a small value, and because it can handle coloured
-/// output.
-///
-/// [`Display`]: core::fmt::Display
-#[cfg(not(tarpaulin_include))]
-pub trait DisplayBreakdown: Debug {
-	/// Displays a pretty breakdown of the value and every part's meaning.
-	///
-	/// The indentation should be applied to every line. It's used to allow the
-	/// display of nested values.
-	fn display_breakdown(
-		&self,
-		stdout: &mut StandardStream,
-		indentation: u8,
-		show_severity_colours: bool,
-	);
-}
+// Constants
+/// The prompt shown before each value is read in the REPL.
+const REPL_PROMPT: &str = "> ";
+/// The input file path that represents stdin.
+const STDIN_PATH: &str = "-";
 
 // Entry Point
 #[cfg(not(tarpaulin_include))]
-fn main() {
+fn main() -> ExitCode {
 	let mut cli_definition = build_cli();
-	let matches = cli_definition.clone().get_matches();
+	// Usage errors are handled here instead of by `clap` so that they get their
+	// own exit code
+	let matches = match cli_definition.clone().try_get_matches() {
+		Ok(matches) => matches,
+		Err(error) => {
+			error.print().ok();
+			return if error.use_stderr() {
+				ExitStatus::InvalidUsage
+			} else {
+				ExitStatus::Success
+			}
+			.into();
+		}
+	};
 
-	let config_figment = apply_cli_arguments(Config::figment(), &matches);
+	// Invalid values in the configuration file or environment variables are
+	// usage errors, the same as invalid CLI arguments
+	let config = match Config::try_from(apply_cli_arguments(Config::figment(), &matches)) {
+		Ok(config) => config,
+		Err(error) => {
+			eprintln!("Invalid configuration: {error}");
+			return ExitStatus::InvalidUsage.into();
+		}
+	};
 
-	let colour_choice = config_figment
-		.extract_inner::<ColourChoice>(Config::CLI_COLOUR)
-		.unwrap()
-		.change_based_on_tty()
-		.into();
-	let masking_characters = config_figment
-		.extract_inner::<Vec<char>>(Config::MASKING_CHARACTERS)
-		.unwrap();
-	let sort_parsed_tags = config_figment
-		.extract_inner::<bool>(Config::SORT_PARSED_TAGS)
-		.unwrap();
+	let colour_choice = config.cli_colour.change_based_on_tty().into();
+	// Bytes are checked for masking as the characters with the same value, so the
+	// masking bytes can go alongside the masking characters
+	let mut masking_characters = config.masking_characters;
+	masking_characters.extend(config.masking_bytes.into_iter().map(char::from));
+	let masking_mode = config.masking_mode.into();
+	let mask_single_byte = config.mask_single_byte;
+	let sort_parsed_tags = config.sort_parsed_tags;
+	let show_raw_values = config.show_raw_values;
+	let show_severity_colours = config.show_severity_colours;
+	let show_severity_summary = config.show_severity_summary;
+	let output_format = config.output_format;
+	set_indentation_unit(config.indentation.unit());
+
+	let tag_dictionaries = config.tag_dictionaries;
+	let mut custom_tags = Vec::new();
+	for path in &tag_dictionaries {
+		match load_tag_dictionary(path) {
+			Ok(dictionary) => custom_tags.extend(dictionary),
+			Err(error) => {
+				eprintln!("Unable to load the tag dictionary `{path}`: {error}");
+				return ExitStatus::IoFailure.into();
+			}
+		}
+	}
+	if let Err(error) = set_custom_tags(custom_tags) {
+		eprintln!("Unable to load the tag dictionaries: a `parse_as` tag isn't known ({error})");
+		return ExitStatus::InvalidUsage.into();
+	}
 
 	let mut stdout = StandardStream::stdout(colour_choice);
 
-	let parse_error = {
+	// The REPL parses TLV data by default, since that's what it's most useful for
+	let repl = matches.get_flag("repl");
+	let operation_id = match matches.get_one::<Id>("operations") {
+		Some(operation_id) => operation_id.as_str(),
+		None if repl => "auto-tlv",
+		None => {
+			cli_definition.print_help().expect("unable to print help");
+			return ExitStatus::Success.into();
+		}
+	};
+
+	// These operations don't take a value, so they're handled before reading one
+	match operation_id {
+		"list-tags" => {
+			output_value(
+				&TagList::all(),
+				&mut stdout,
+				output_format,
+				show_severity_colours,
+			);
+			return ExitStatus::Success.into();
+		}
+		"legend" => {
+			print_severity_legend(&mut stdout, 0, show_severity_colours);
+			return ExitStatus::Success.into();
+		}
+		_ => {}
+	}
+
+	let only_tags = match matches
+		.get_many::<String>("only")
+		.map(|tag_strs| {
+			tag_strs
+				.map(|tag_str| parse_hex_str_strict(tag_str.trim()))
+				.collect::<Result<Vec<_>, _>>()
+		})
+		.transpose()
+	{
+		Ok(only_tags) => only_tags,
+		Err(error) => {
+			eprintln!("Unable to parse the tags to display: {error}");
+			return ExitStatus::InvalidUsage.into();
+		}
+	};
+
+	let operation_settings = OperationSettings {
+		masking_characters,
+		masking_mode,
+		mask_single_byte,
+		sort_parsed_tags,
+		show_raw_values,
+		show_offsets: matches.get_flag("offsets"),
+		show_path: matches.get_flag("show-path"),
+		highlight_bytes: matches.get_flag("highlight-bytes"),
+		show_severity_colours,
+		show_severity_summary,
+		only_tags,
+		validate: matches.get_flag("validate"),
+		strict: matches.get_flag("strict"),
+		quiet: matches.get_flag("quiet"),
+		summary: matches.get_flag("summary"),
+		base64: matches.get_flag("base64"),
+		output_format,
+	};
+
+	if repl {
+		return run_repl(operation_id, &mut stdout, &operation_settings).into();
+	}
+
+	// Binary input is parsed as-is, without going through hex
+	if let Some(path) = matches.get_one::<String>("input-file-binary") {
+		let bytes = match read(path) {
+			Ok(bytes) => bytes,
+			Err(error) => {
+				eprintln!("Unable to read the input file `{path}`: {error}");
+				return ExitStatus::IoFailure.into();
+			}
+		};
+		return match run_ber_tlv_binary(bytes.as_slice(), &mut stdout, &operation_settings) {
+			Ok(status) => status,
+			Err(error) => {
+				eprintln!("{error}");
+				ExitStatus::ParseFailure
+			}
+		}
+		.into();
+	}
+
+	// If the operation was provided without a value, read the value from the input
+	// file instead
+	let operation_values = match read_missing_operation_value(&matches) {
+		Ok(Some(contents)) => vec![contents],
+		Ok(None) => matches
+			.get_many::<String>(operation_id)
+			.expect("the operation has a value if one didn't need to be read")
+			.cloned()
+			.collect::<Vec<_>>(),
+		Err(error) => {
+			match matches.get_one::<String>("input-file") {
+				Some(path) if path != STDIN_PATH => {
+					eprintln!("Unable to read the input file `{path}`: {error}");
+				}
+				_ => eprintln!("Unable to read the value from stdin: {error}"),
+			}
+			return ExitStatus::IoFailure.into();
+		}
+	};
+
+	// Comparisons need both of their values at once, so they're handled separately
+	if operation_id == "diff" {
+		return match run_diff(
+			operation_values[0].as_str(),
+			operation_values[1].as_str(),
+			&mut stdout,
+			&operation_settings,
+		) {
+			Ok(()) => ExitStatus::Success,
+			Err(error) => {
+				eprintln!("{error}");
+				ExitStatus::ParseFailure
+			}
+		}
+		.into();
+	}
+
+	// With `--batch`, each non-empty line is treated as an independent value
+	let batch = matches.get_flag("batch");
+	if !batch && operation_values.len() == 1 {
+		return match run_operation(
+			operation_id,
+			operation_values[0].as_str(),
+			&mut stdout,
+			&operation_settings,
+		) {
+			Ok(status) => status,
+			Err(error) => {
+				eprintln!("{error}");
+				ExitStatus::ParseFailure
+			}
+		}
+		.into();
+	}
+	let entries = if batch {
+		operation_values
+			.iter()
+			.flat_map(|value| value.lines())
+			.enumerate()
+			.map(|(line_index, line)| (line_index + 1, line.trim()))
+			.filter(|(_, line)| !line.is_empty())
+			.collect::<Vec<_>>()
+	} else {
+		operation_values
+			.iter()
+			.enumerate()
+			.map(|(value_index, value)| (value_index + 1, value.trim()))
+			.collect::<Vec<_>>()
+	};
+
+	// Errors are collected and reported at the end, so that one bad entry doesn't
+	// stop the remaining entries from being processed
+	let mut errors = Vec::new();
+	let mut status = ExitStatus::Success;
+	for (entry_index, (entry_number, value)) in entries.iter().enumerate() {
+		if output_format == OutputFormat::Human {
+			if entry_index > 0 {
+				println!();
+			}
+			stdout.set_color(&header_colour_spec()).ok();
+			println!("=== Entry {entry_number} ===");
+			stdout.reset().ok();
+		}
+
+		match run_operation(operation_id, value, &mut stdout, &operation_settings) {
+			Ok(entry_status) => status = status.max(entry_status),
+			Err(error) => errors.push((entry_number, error)),
+		}
+	}
+	if !errors.is_empty() {
+		status = ExitStatus::ParseFailure;
+		eprintln!();
+		eprintln!(
+			"{} of {} entries couldn't be parsed:",
+			errors.len(),
+			entries.len()
+		);
+		for (entry_number, error) in errors {
+			eprintln!("Entry {entry_number}: {error}");
+		}
+	}
+
+	status.into()
+}
+
+/// How the program finished, which determines its exit code so that it can be
+/// used from scripts.
+///
+/// The variants are ordered by precedence, so that when multiple values are
+/// parsed the most severe outcome is used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum ExitStatus {
+	/// Everything was parsed successfully.
+	Success,
+	/// Everything was parsed, but `--validate` found at least one error.
+	ValidationErrors,
+	/// A value couldn't be parsed.
+	ParseFailure,
+	/// A file or stdin couldn't be read.
+	IoFailure,
+	/// The command-line arguments or configuration are invalid.
+	InvalidUsage,
+}
+
+impl From<ExitStatus> for ExitCode {
+	fn from(status: ExitStatus) -> Self {
+		match status {
+			ExitStatus::Success => ExitCode::SUCCESS,
+			ExitStatus::ParseFailure => ExitCode::FAILURE,
+			ExitStatus::ValidationErrors => ExitCode::from(2),
+			ExitStatus::IoFailure => ExitCode::from(3),
+			ExitStatus::InvalidUsage => ExitCode::from(4),
+		}
+	}
+}
+
+/// The settings that affect how an operation is run.
+struct OperationSettings {
+	masking_characters:    Vec<char>,
+	masking_mode:          LibMaskingMode,
+	/// Whether a 1-byte binary value that matches a masking character is
+	/// considered masked.
+	mask_single_byte:      bool,
+	sort_parsed_tags:      bool,
+	show_raw_values:       bool,
+	/// Whether to show the byte offsets in the raw value of each tag.
+	show_offsets:          bool,
+	/// Whether to show the path of tags that each tag is nested inside.
+	show_path:             bool,
+	/// Whether to highlight the bytes of each tag in BER-TLV data.
+	highlight_bytes:       bool,
+	show_severity_colours: bool,
+	/// Whether to count the warnings and errors after the full output of TLV
+	/// data.
+	show_severity_summary: bool,
+	only_tags:             Option<Vec<Vec<u8>>>,
+	validate:              bool,
+	/// Whether TLV data has to be strictly compliant.
+	strict:                bool,
+	/// Whether to only display the notable bits of TLV data.
+	quiet:                 bool,
+	/// Whether to summarise the transaction channel of TLV data.
+	summary:               bool,
+	/// Whether BER-TLV data is base64-encoded instead of hex-encoded.
+	base64:                bool,
+	output_format:         OutputFormat,
+}
+
+impl OperationSettings {
+	/// The settings that decide whether data is treated as masked.
+	fn masking(&self) -> MaskingSettings<'_> {
+		MaskingSettings {
+			characters:       self.masking_characters.as_slice(),
+			mode:             self.masking_mode,
+			mask_single_byte: self.mask_single_byte,
+		}
+	}
+}
+
+/// Parses the value for the chosen operation and writes the result to stdout.
+#[cfg(not(tarpaulin_include))]
+fn run_operation(
+	operation_id: &str,
+	value: &str,
+	stdout: &mut StandardStream,
+	settings: &OperationSettings,
+) -> Result<ExitStatus, ParseError> {
+	let output_format = settings.output_format;
+	let show_severity_colours = settings.show_severity_colours;
+	let masking = settings.masking();
+	let found_validation_errors = Cell::new(false);
+	let post_process = |block: ProcessedEmvBlock| {
+		let processed = post_process_block(block, settings);
+		if has_validation_errors(processed.findings.as_deref()) {
+			found_validation_errors.set(true);
+		}
+		processed
+	};
+
+	let result = match operation_id {
 		// EMV Tags
-		if let Some(tvr_str) = matches.get_one::<String>("tvr") {
-			TerminalVerificationResults::try_from(parse_hex_str(tvr_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(iad_str) = matches.get_one::<String>("ccd-iad") {
-			IssuerApplicationData::try_from(parse_hex_str(iad_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(cvr_str) = matches.get_one::<String>("ccd-cvr") {
-			CardVerificationResults::try_from(parse_hex_str(cvr_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(tsi_str) = matches.get_one::<String>("tsi") {
-			TransactionStatusInformation::try_from(parse_hex_str(tsi_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(cvm_results_str) = matches.get_one::<String>("cvm-results") {
-			CardholderVerificationMethodResults::try_from(parse_hex_str(cvm_results_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(cvm_list_str) = matches.get_one::<String>("cvm-list") {
-			CardholderVerificationMethodList::try_from(parse_hex_str(cvm_list_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
+		"tvr" => TerminalVerificationResults::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"ccd-iad" => IssuerApplicationData::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"ccd-cvr" => CardVerificationResults::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"ccd-cci" => CommonCoreIdentifier::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"tsi" => TransactionStatusInformation::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"cvm-results" => {
+			CardholderVerificationMethodResults::try_from(parse_hex_str(value).as_slice())
+				.map(|v| output_value(&v, stdout, output_format, show_severity_colours))
 		}
+		"cvm-list" => CardholderVerificationMethodList::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
 		// EMV Utilities
-		else if let Some(tag_str) = matches.get_one::<String>("identify") {
-			TagBasicInfo::try_from(parse_hex_str(tag_str).as_slice())
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(tlv_str) = matches.get_one::<String>("auto-tlv") {
-			parse_auto_tlv(tlv_str, masking_characters.as_slice())
-				.and_then(|(format, v)| {
-					let result = ProcessedEmvBlock::try_from(v);
-					if result.is_ok() {
-						stdout.set_color(&header_colour_spec()).ok();
-						print!("TLV Format: ");
-						stdout.reset().ok();
-						println!("{format}");
-						println!();
-					}
-					result
-				})
-				.map(|mut v| {
-					if sort_parsed_tags {
-						v.sort_nodes();
-					}
-					v
-				})
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
-		} else if let Some(ber_tlv_str) = matches.get_one::<String>("ber-tlv") {
-			parse_ber_tlv(
-				parse_hex_str(ber_tlv_str).as_slice(),
-				masking_characters.as_slice(),
-			)
-			.and_then(ProcessedEmvBlock::try_from)
-			.map(|mut v| {
-				if sort_parsed_tags {
-					v.sort_nodes();
+		"find-tag" => {
+			output_value(
+				&TagList::search(value),
+				stdout,
+				output_format,
+				show_severity_colours,
+			);
+			Ok(())
+		}
+		"identify" => TagBasicInfo::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"encode" => parse_tag_value_list(value).map(|tags| {
+			let encoded = bytes_to_str(encode_ber_tlv(tags.as_slice()).as_slice());
+			match output_format {
+				OutputFormat::Human => println!("{encoded}"),
+				OutputFormat::Json => println!("{}", JsonValue::String(encoded)),
+			}
+		}),
+		"auto-tlv" => if settings.strict {
+			detect_tlv_format_strict(value, masking)
+		} else {
+			detect_tlv_format(value, masking)
+		}
+		.and_then(|detection| {
+			let result = ProcessedEmvBlock::try_from(detection.block);
+			if result.is_ok() && output_format == OutputFormat::Human {
+				stdout.set_color(&header_colour_spec()).ok();
+				print!("TLV Format: ");
+				stdout.reset().ok();
+				println!("{}", detection.format);
+				if !detection.also_matches.is_empty() {
+					stdout
+						.set_color(&severity_colour_spec(
+							Severity::Warning,
+							show_severity_colours,
+						))
+						.ok();
+					println!(
+						"The data could also be parsed as: {}",
+						detection
+							.also_matches
+							.iter()
+							.map(ToString::to_string)
+							.collect::<Vec<_>>()
+							.join(", ")
+					);
+					stdout.reset().ok();
 				}
-				v
-			})
-			.map(|v| v.display_breakdown(&mut stdout, 0, true))
-			.err()
-		} else if let Some(ingenico_tlv_str) = matches.get_one::<String>("ingenico-tlv") {
-			parse_ingenico_tlv(ingenico_tlv_str, masking_characters.as_slice())
-				.and_then(ProcessedEmvBlock::try_from)
-				.map(|mut v| {
-					if sort_parsed_tags {
-						v.sort_nodes();
-					}
-					v
-				})
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
+				println!();
+			}
+			result
+		})
+		.map(post_process)
+		.map(|processed| output_block(&processed, stdout, settings)),
+		"ber-tlv" => if settings.base64 {
+			parse_base64_str(value)
+		} else {
+			parse_hex_str_exact(value)
 		}
+		.and_then(|bytes| process_ber_tlv(bytes.as_slice(), stdout, settings))
+		.map(post_process)
+		.map(|processed| output_block(&processed, stdout, settings)),
+		"compact-tlv" => parse_compact_tlv(parse_hex_str(value).as_slice())
+			.and_then(ProcessedEmvBlock::try_from)
+			.map(post_process)
+			.map(|processed| output_block(&processed, stdout, settings)),
+		"ingenico-tlv" => parse_ingenico_tlv(value, masking)
+			.and_then(ProcessedEmvBlock::try_from)
+			.map(post_process)
+			.map(|processed| output_block(&processed, stdout, settings)),
+		"dgi" => parse_dgi(parse_hex_str(value).as_slice(), masking)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
 		// Non-EMV
-		else if let Some(service_code_str) = matches.get_one::<String>("service-code") {
-			parse_str_to_u16(service_code_str)
-				.and_then(ServiceCode::try_from)
-				.map(|v| v.display_breakdown(&mut stdout, 0, true))
-				.err()
+		"luhn" => LuhnCheck::try_from(value)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"luhn-complete" => LuhnCompletion::try_from(value)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"service-code" => parse_str_to_u16(value)
+			.and_then(ServiceCode::try_from)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"track1" => Track1Data::try_from(value)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"track2" => Track2Data::try_from(value)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"atr" => AnswerToReset::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"apdu" => Apdu::parse(parse_hex_str(value).as_slice(), masking)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"apdu-response" => ResponseApdu::parse(parse_hex_str(value).as_slice(), masking)
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		"status-word" => StatusWord::try_from(parse_hex_str(value).as_slice())
+			.map(|v| output_value(&v, stdout, output_format, show_severity_colours)),
+		_ => unreachable!("every operation in the group should be handled"),
+	};
+
+	result.map(|()| {
+		if found_validation_errors.get() {
+			ExitStatus::ValidationErrors
+		} else {
+			ExitStatus::Success
 		}
-		// Default behaviour when no options are provided
-		else {
-			cli_definition.print_help().expect("unable to print help");
-			return;
+	})
+}
+
+/// Parses raw BER-TLV bytes and writes the result to stdout.
+///
+/// This is the same as the `--ber-tlv` operation, but for data that isn't
+/// hex-encoded.
+#[cfg(not(tarpaulin_include))]
+fn run_ber_tlv_binary(
+	bytes: &[u8],
+	stdout: &mut StandardStream,
+	settings: &OperationSettings,
+) -> Result<ExitStatus, ParseError> {
+	let processed = post_process_block(process_ber_tlv(bytes, stdout, settings)?, settings);
+	output_block(&processed, stdout, settings);
+
+	Ok(if has_validation_errors(processed.findings.as_deref()) {
+		ExitStatus::ValidationErrors
+	} else {
+		ExitStatus::Success
+	})
+}
+
+/// Parses BER-TLV bytes and processes them into a block, honouring
+/// `--strict`, which also stops padding between tags from being skipped.
+///
+/// If `--highlight-bytes` was used, the bytes of each tag are written to stdout
+/// first.
+fn process_ber_tlv(
+	bytes: &[u8],
+	stdout: &mut StandardStream,
+	settings: &OperationSettings,
+) -> Result<ProcessedEmvBlock, ParseError> {
+	let (block, spans) =
+		parse_ber_tlv_with_spans(bytes, settings.masking(), !settings.strict, settings.strict)?;
+	if settings.highlight_bytes && settings.output_format == OutputFormat::Human {
+		print_tag_spans(stdout, bytes, spans.as_slice());
+		println!();
+	}
+
+	ProcessedEmvBlock::try_from(block)
+}
+
+/// A parsed TLV block after the settings that affect it have been applied.
+struct PostProcessedBlock {
+	block:           ProcessedEmvBlock,
+	/// The cross-validation findings, if `--validate` was used.
+	findings:        Option<Vec<Finding>>,
+	/// The transaction channel summary, if `--summary` was used and there was
+	/// something to summarise.
+	channel_summary: Option<String>,
+}
+
+/// Applies the settings that affect a parsed TLV block.
+fn post_process_block(
+	mut block: ProcessedEmvBlock,
+	settings: &OperationSettings,
+) -> PostProcessedBlock {
+	// Validation and the summary happen first so that they aren't affected by
+	// `--only`
+	let findings = settings.validate.then(|| block.cross_validate());
+	let channel_summary = if settings.summary {
+		block.summarise_channel()
+	} else {
+		None
+	};
+	if let Some(only_tags) = &settings.only_tags {
+		block.retain_tags(only_tags.as_slice());
+	}
+	if settings.sort_parsed_tags {
+		block.sort_nodes();
+	}
+	PostProcessedBlock {
+		block,
+		findings,
+		channel_summary,
+	}
+}
+
+/// Whether any of the cross-validation findings are errors.
+fn has_validation_errors(findings: Option<&[Finding]>) -> bool {
+	findings
+		.into_iter()
+		.flatten()
+		.any(|finding| finding.severity == Severity::Error)
+}
+
+/// Parses two blocks of TLV data and writes the differences between them to
+/// stdout.
+#[cfg(not(tarpaulin_include))]
+fn run_diff(
+	old_value: &str,
+	new_value: &str,
+	stdout: &mut StandardStream,
+	settings: &OperationSettings,
+) -> Result<(), ParseError> {
+	let masking = settings.masking();
+	let (_, old_block) = parse_auto_tlv(old_value, masking)?;
+	let (_, new_block) = parse_auto_tlv(new_value, masking)?;
+
+	output_value(
+		&compare_tlv_blocks(&old_block, &new_block),
+		stdout,
+		settings.output_format,
+		settings.show_severity_colours,
+	);
+
+	Ok(())
+}
+
+/// Reads values from stdin one line at a time, parsing each one with the
+/// chosen operation until the end of the input.
+///
+/// Errors are shown as they happen instead of stopping the loop.
+#[cfg(not(tarpaulin_include))]
+fn run_repl(
+	operation_id: &str,
+	stdout: &mut StandardStream,
+	settings: &OperationSettings,
+) -> ExitStatus {
+	let show_prompt = |stdout: &mut StandardStream| {
+		if settings.output_format == OutputFormat::Human {
+			print!("{REPL_PROMPT}");
+			stdout.flush().ok();
 		}
 	};
 
-	if let Some(error) = parse_error {
-		eprintln!("{error}");
+	show_prompt(stdout);
+	for line in stdin().lines() {
+		let line = match line {
+			Ok(line) => line,
+			Err(error) => {
+				eprintln!("Unable to read the value from stdin: {error}");
+				return ExitStatus::IoFailure;
+			}
+		};
+		let value = line.trim();
+		if !value.is_empty() {
+			if let Err(error) = run_operation(operation_id, value, stdout, settings) {
+				eprintln!("{error}");
+			}
+			if settings.output_format == OutputFormat::Human {
+				println!();
+			}
+		}
+		show_prompt(stdout);
 	}
+	if settings.output_format == OutputFormat::Human {
+		println!();
+	}
+
+	ExitStatus::Success
 }
 
-// Unit Tests
-/// This is because Tarpaulin currently recognises every enum variant line
-/// as uncovered because the [`Display`] impl was never called in testing.
-///
-/// It also marks each fallible enum's error function as uncovered, so that's
-/// also handled here.
+/// Reads the value for the chosen operation from the input file (or stdin) if
+/// it was provided on the command line without a value.
 ///
-/// `#[cfg(not(tarpaulin_include))]` doesn't work here unfortunately, and
-/// the number of 'uncovered' lines is too large to ignore. (>50% of lines
-/// in some files)
-#[cfg(test)]
-#[cfg(tarpaulin)]
-mod tests {
-	// Uses
-	use crate::{
-		emv::{
-			ccd::{
-				CryptogramVersion,
-				FormatCode,
-				GenAc1ApplicationCryptogramType,
-				GenAc2ApplicationCryptogramType,
-			},
-			AuthorisationResponseCode,
-			CvMethod,
-			CvmCondition,
-			CvmResult,
-			PosEntryMode,
-			TagClass,
-			TerminalType,
-			TransactionType,
-		},
-		non_emv::{
-			AllowedServices,
-			AuthorisationProcessing,
-			Interchange,
-			PinRequirements,
-			Technology,
-		},
+/// Returns `None` if the operation already has a value, or if no operation was
+/// provided.
+#[cfg(not(tarpaulin_include))]
+fn read_missing_operation_value(matches: &ArgMatches) -> IoResult<Option<String>> {
+	let Some(operation_id) = matches.get_one::<Id>("operations") else {
+		return Ok(None);
+	};
+	if matches.get_one::<String>(operation_id.as_str()).is_some() {
+		return Ok(None);
+	}
+
+	let path = matches
+		.get_one::<String>("input-file")
+		.map_or(STDIN_PATH, String::as_str);
+	let contents = if path == STDIN_PATH {
+		read_reader_to_string(stdin())?
+	} else {
+		read_to_string(path)?
 	};
 
-	#[test]
-	fn cover_all_enum_variants() {
-		AllowedServices::cover_all_enum_variants();
-		AllowedServices::try_from(0xFF);
-		AuthorisationProcessing::cover_all_enum_variants();
-		AuthorisationProcessing::try_from(0xFF);
-		AuthorisationResponseCode::cover_all_enum_variants();
-		AuthorisationResponseCode::try_from("");
-		CryptogramVersion::cover_all_enum_variants();
-		CryptogramVersion::try_from(0xFF);
-		CvmCondition::cover_all_enum_variants();
-		CvmCondition::try_from(0xFF);
-		CvMethod::cover_all_enum_variants();
-		CvMethod::try_from(0xFF);
-		CvmResult::cover_all_enum_variants();
-		CvmResult::try_from(0xFF);
-		FormatCode::cover_all_enum_variants();
-		FormatCode::try_from(0xFF);
-		GenAc1ApplicationCryptogramType::cover_all_enum_variants();
-		GenAc1ApplicationCryptogramType::try_from(0xFF);
-		GenAc2ApplicationCryptogramType::cover_all_enum_variants();
-		GenAc2ApplicationCryptogramType::try_from(0xFF);
-		Interchange::cover_all_enum_variants();
-		Interchange::try_from(0xFF);
-		PinRequirements::cover_all_enum_variants();
-		PinRequirements::try_from(0xFF);
-		PosEntryMode::cover_all_enum_variants();
-		PosEntryMode::try_from(0xFF);
-		TagClass::cover_all_enum_variants();
-		TagClass::try_from(0xFF);
-		Technology::cover_all_enum_variants();
-		Technology::try_from(0xFF);
-		TerminalType::cover_all_enum_variants();
-		TerminalType::try_from(0xFF);
-		TransactionType::cover_all_enum_variants();
-		TransactionType::try_from(0xFF);
+	// Outside of batch mode, the value may be wrapped across several lines
+	if matches.get_flag("batch") {
+		Ok(Some(contents.trim().to_owned()))
+	} else {
+		Ok(Some(contents.lines().map(str::trim).collect()))
+	}
+}
+
+/// Writes a parsed TLV block to stdout in the requested output format, along
+/// with the cross-validation findings and channel summary if there are any.
+#[cfg(not(tarpaulin_include))]
+fn output_block(
+	processed: &PostProcessedBlock,
+	stdout: &mut StandardStream,
+	settings: &OperationSettings,
+) {
+	let block = &processed.block;
+	let findings = processed.findings.as_deref();
+	match settings.output_format {
+		OutputFormat::Human => {
+			if settings.summary {
+				stdout.set_color(&header_colour_spec()).ok();
+				print!("Channel:");
+				stdout.reset().ok();
+				println!(
+					" {}",
+					processed.channel_summary.as_deref().unwrap_or("Unknown")
+				);
+				println!();
+			}
+
+			if settings.quiet {
+				block.display_notable_bits(stdout, 0, settings.show_severity_colours);
+				let counts = count_severities(processed);
+				if counts != SeverityCounts::default() {
+					println!();
+				}
+				println!("{counts}");
+			} else {
+				block.display_breakdown_showing_raw(
+					stdout,
+					0,
+					settings.show_severity_colours,
+					DisplaySettings {
+						show_raw_values: settings.show_raw_values,
+						show_offsets:    settings.show_offsets,
+						ancestors:       settings.show_path.then_some(""),
+					},
+				);
+				if settings.show_severity_summary {
+					println!();
+					stdout.set_color(&header_colour_spec()).ok();
+					print!("Summary:");
+					stdout.reset().ok();
+					println!(" {}", count_severities(processed));
+				}
+			}
+
+			let Some(findings) = findings else {
+				return;
+			};
+			println!();
+			stdout.set_color(&header_colour_spec()).ok();
+			println!("Findings:");
+			stdout.reset().ok();
+			if findings.is_empty() {
+				print_indentation(stdout, 1);
+				println!("No inconsistencies found");
+			}
+			for finding in findings {
+				finding.display_breakdown(stdout, 1, settings.show_severity_colours);
+			}
+		}
+		OutputFormat::Json => {
+			if findings.is_none() && !settings.summary {
+				println!("{:#}", block.to_json_value());
+				return;
+			}
+
+			let mut value = json!({ "tags": block.to_json_value() });
+			if let Some(findings) = findings {
+				value["findings"] = findings
+					.iter()
+					.map(DisplayBreakdown::to_json_value)
+					.collect();
+			}
+			if settings.summary {
+				value["channel"] = json!(processed.channel_summary);
+			}
+			println!("{value:#}");
+		}
+	}
+}
+
+/// Counts the notable bits of every parsed tag, along with the
+/// cross-validation findings if there are any.
+fn count_severities(processed: &PostProcessedBlock) -> SeverityCounts {
+	let mut counts = processed.block.count_notable_bits();
+	for finding in processed.findings.iter().flatten() {
+		counts.add(finding.severity);
+	}
+
+	counts
+}
+
+/// Writes a parsed value to stdout in the requested output format.
+#[cfg(not(tarpaulin_include))]
+fn output_value<V>(
+	value: &V,
+	stdout: &mut StandardStream,
+	output_format: OutputFormat,
+	show_severity_colours: bool,
+) where
+	V: DisplayBreakdown,
+{
+	match output_format {
+		OutputFormat::Human => value.display_breakdown(stdout, 0, show_severity_colours),
+		OutputFormat::Json => println!("{:#}", value.to_json_value()),
 	}
 }
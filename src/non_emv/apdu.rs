@@ -0,0 +1,521 @@
+//! Application Protocol Data Units (APDUs), which are the commands sent to a
+//! card and the responses it sends back.
+//!
+//! Information for this can be found in [ISO/IEC 7816-4](https://www.iso.org/standard/77180.html),
+//! under section `5`, and the EMV-specific commands are in EMV Book 3, under
+//! section `6.5`. Only short (not extended) length fields are supported.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use super::StatusWord;
+use crate::{
+	emv::{
+		ber_tlv::parse as parse_ber_tlv,
+		MaskingSettings,
+		ProcessedEmvBlock,
+		ShortFileIdentifier,
+	},
+	enum_repr_fallible,
+	error::ParseError,
+	util::bytes_to_str,
+	DisplayBreakdown,
+};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::header_colour_spec,
+	util::{print_bytes, print_indentation},
+};
+
+// Constants
+/// The number of bytes in a command header (`CLA INS P1 P2`).
+const COMMAND_HEADER_BYTES: usize = 4;
+/// The number of bytes in a status word (`SW1 SW2`).
+const STATUS_WORD_BYTES: usize = 2;
+
+// Struct Implementation
+/// Either a command or a response APDU.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Apdu {
+	Command(CommandApdu),
+	Response(ResponseApdu),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct CommandApdu {
+	/// The class byte (CLA).
+	pub class:           u8,
+	/// The instruction byte (INS).
+	pub instruction:     u8,
+	pub p1:              u8,
+	pub p2:              u8,
+	pub data:            Vec<u8>,
+	/// The maximum number of bytes expected in the response (Le), where `0x00`
+	/// means 256.
+	pub expected_length: Option<u8>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ResponseApdu {
+	pub data:        Vec<u8>,
+	pub status_word: StatusWord,
+	/// The response data parsed as BER-TLV, if it's in that format.
+	pub tlv_data:    Option<ProcessedEmvBlock>,
+}
+
+enum_repr_fallible! {
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Instruction: u8, ParseError, { |_| ParseError::Unrecognised } {
+	CardBlock                     = 0x16 => "CARD BLOCK",
+	ApplicationUnblock            = 0x18 => "APPLICATION UNBLOCK",
+	ApplicationBlock              = 0x1E => "APPLICATION BLOCK",
+	Verify                        = 0x20 => "VERIFY",
+	PinChangeUnblock              = 0x24 => "PIN CHANGE/UNBLOCK",
+	ExternalAuthenticate          = 0x82 => "EXTERNAL AUTHENTICATE",
+	GetChallenge                  = 0x84 => "GET CHALLENGE",
+	InternalAuthenticate          = 0x88 => "INTERNAL AUTHENTICATE",
+	Select                        = 0xA4 => "SELECT",
+	GetProcessingOptions          = 0xA8 => "GET PROCESSING OPTIONS",
+	GenerateApplicationCryptogram = 0xAE => "GENERATE AC",
+	ReadRecord                    = 0xB2 => "READ RECORD",
+	GetResponse                   = 0xC0 => "GET RESPONSE",
+	GetData                       = 0xCA => "GET DATA",
+	PutData                       = 0xDA => "PUT DATA",
+}
+}
+
+impl Apdu {
+	/// Parses an APDU, working out whether it's a command or a response.
+	///
+	/// Commands with a recognised instruction are preferred, then responses
+	/// with a valid status word, and finally any structurally-valid command.
+	pub fn parse(bytes: &[u8], masking: MaskingSettings) -> Result<Self, ParseError> {
+		if bytes.is_empty() {
+			return Err(ParseError::EmptyInput);
+		}
+
+		let command = CommandApdu::try_from(bytes);
+		if let Ok(command) = &command {
+			if command.known_instruction().is_some() {
+				return Ok(Self::Command(command.clone()));
+			}
+		}
+
+		match ResponseApdu::parse(bytes, masking) {
+			Ok(response) => Ok(Self::Response(response)),
+			Err(error) => command.map(Self::Command).map_err(|_| error),
+		}
+	}
+}
+
+impl CommandApdu {
+	/// Gets the instruction, if it's a recognised one.
+	pub fn known_instruction(&self) -> Option<Instruction> {
+		Instruction::try_from(self.instruction).ok()
+	}
+
+	/// Describes what the `P1` and `P2` parameters mean for the instruction,
+	/// for the instructions where they're meaningful.
+	pub fn parameter_description(&self) -> Option<String> {
+		match self.known_instruction()? {
+			Instruction::Select => Some(format!(
+				"{}, {}",
+				if self.p1 == 0x04 {
+					"Select by name"
+				} else {
+					"Select by identifier"
+				},
+				if self.p2 == 0x02 {
+					"next occurrence"
+				} else {
+					"first or only occurrence"
+				}
+			)),
+			Instruction::ReadRecord if self.p2 & 0b0000_0111 == 0b0000_0100 => {
+				ShortFileIdentifier::from_reference_byte(self.p2)
+					.ok()
+					.map(|sfi| format!("Record {}, SFI {}", self.p1, sfi.sfi))
+			}
+			Instruction::GenerateApplicationCryptogram => Some(format!(
+				"{} requested{}",
+				match self.p1 >> 6 {
+					0b00 => "AAC",
+					0b01 => "TC",
+					0b10 => "ARQC",
+					_ => "RFU",
+				},
+				if self.p1 & 0b0001_0000 > 0 {
+					", CDA signature requested"
+				} else {
+					""
+				}
+			)),
+			Instruction::GetData => Some(format!("Tag {:02X}{:02X}", self.p1, self.p2)),
+			Instruction::Verify => match self.p2 {
+				0x80 => Some("Plaintext PIN".to_owned()),
+				0x88 => Some("Enciphered PIN".to_owned()),
+				_ => None,
+			},
+			Instruction::CardBlock
+			| Instruction::ApplicationUnblock
+			| Instruction::ApplicationBlock
+			| Instruction::PinChangeUnblock
+			| Instruction::ExternalAuthenticate
+			| Instruction::GetChallenge
+			| Instruction::InternalAuthenticate
+			| Instruction::GetProcessingOptions
+			| Instruction::ReadRecord
+			| Instruction::GetResponse
+			| Instruction::PutData => None,
+		}
+	}
+}
+
+impl TryFrom<&[u8]> for CommandApdu {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		if raw_bytes.len() < COMMAND_HEADER_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Greater,
+				expected: COMMAND_HEADER_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		// CLA 0xFF is reserved, and INS values 0x6X and 0x9X are invalid
+		let class = raw_bytes[0];
+		let instruction = raw_bytes[1];
+		if class == 0xFF || matches!(instruction >> 4, 0x6 | 0x9) {
+			return Err(ParseError::NonCompliant);
+		}
+
+		// The body can be empty, just Le, just Lc and the data, or all three
+		let (data, expected_length) = match &raw_bytes[COMMAND_HEADER_BYTES..] {
+			[] => (Vec::new(), None),
+			[le] => (Vec::new(), Some(*le)),
+			[lc, body @ ..] if *lc > 0 && body.len() == usize::from(*lc) => (body.to_vec(), None),
+			[lc, body @ .., le] if *lc > 0 && body.len() == usize::from(*lc) => {
+				(body.to_vec(), Some(*le))
+			}
+			_ => return Err(ParseError::NonCompliant),
+		};
+
+		Ok(Self {
+			class,
+			instruction,
+			p1: raw_bytes[2],
+			p2: raw_bytes[3],
+			data,
+			expected_length,
+		})
+	}
+}
+
+impl ResponseApdu {
+	/// Parses a response APDU, with the data being parsed as BER-TLV if
+	/// possible.
+	pub fn parse(bytes: &[u8], masking: MaskingSettings) -> Result<Self, ParseError> {
+		if bytes.len() < STATUS_WORD_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Greater,
+				expected: STATUS_WORD_BYTES,
+				found:    bytes.len(),
+			});
+		}
+
+		let (data, status_word_bytes) = bytes.split_at(bytes.len() - STATUS_WORD_BYTES);
+		let status_word = StatusWord::try_from(status_word_bytes)?;
+		let tlv_data = if data.is_empty() {
+			None
+		} else {
+			parse_ber_tlv(data, masking, true)
+				.and_then(ProcessedEmvBlock::try_from)
+				.ok()
+		};
+
+		Ok(Self {
+			data: data.to_vec(),
+			status_word,
+			tlv_data,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for Apdu {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		match self {
+			Self::Command(command) => {
+				command.display_breakdown(stdout, indentation, show_severity_colours);
+			}
+			Self::Response(response) => {
+				response.display_breakdown(stdout, indentation, show_severity_colours);
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		match self {
+			Self::Command(command) => command.to_json_value(),
+			Self::Response(response) => response.to_json_value(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for CommandApdu {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		let header_colour_spec = header_colour_spec();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(stdout, "Command APDU").ok();
+		stdout.reset().ok();
+
+		// Print the header
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Class (CLA):").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {:#04X}", self.class).ok();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Instruction (INS):").ok();
+		stdout.reset().ok();
+		match self.known_instruction() {
+			Some(instruction) => {
+				writeln!(stdout, " {:#04X} - {instruction}", self.instruction).ok()
+			}
+			None => writeln!(stdout, " {:#04X} - Unrecognised", self.instruction).ok(),
+		};
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Parameters (P1 P2):").ok();
+		stdout.reset().ok();
+		write!(stdout, " {:#04X} {:#04X}", self.p1, self.p2).ok();
+		match self.parameter_description() {
+			Some(description) => writeln!(stdout, " - {description}").ok(),
+			None => writeln!(stdout).ok(),
+		};
+
+		// Print the body
+		if !self.data.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Data (Lc = {}):", self.data.len()).ok();
+			stdout.reset().ok();
+			print_bytes(stdout, self.data.as_slice(), 16, indentation + 1);
+		}
+		if let Some(expected_length) = self.expected_length {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "Expected Length (Le):").ok();
+			stdout.reset().ok();
+			if expected_length == 0x00 {
+				writeln!(stdout, " {expected_length:#04X} - Up to 256 bytes").ok();
+			} else {
+				writeln!(
+					stdout,
+					" {expected_length:#04X} - Up to {expected_length} bytes"
+				)
+				.ok();
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"type": "command",
+			"class": format!("{:02X}", self.class),
+			"instruction": format!("{:02X}", self.instruction),
+			"instruction_name": self.known_instruction().map(|instruction| instruction.to_string()),
+			"p1": format!("{:02X}", self.p1),
+			"p2": format!("{:02X}", self.p2),
+			"parameter_description": self.parameter_description(),
+			"data": bytes_to_str(self.data.as_slice()),
+			"expected_length": self.expected_length,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for ResponseApdu {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let header_colour_spec = header_colour_spec();
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(stdout, "Response APDU").ok();
+		stdout.reset().ok();
+
+		// Print the status word
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(stdout, "Status Word (SW1 SW2):").ok();
+		stdout.reset().ok();
+		self.status_word
+			.display_breakdown(stdout, indentation + 1, show_severity_colours);
+
+		// Print the data
+		if !self.data.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Data ({} bytes):", self.data.len()).ok();
+			stdout.reset().ok();
+			print_bytes(stdout, self.data.as_slice(), 16, indentation + 1);
+		}
+		if let Some(tlv_data) = &self.tlv_data {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			writeln!(stdout, "Parsed Data:").ok();
+			stdout.reset().ok();
+			tlv_data.display_breakdown(stdout, indentation + 1, show_severity_colours);
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"type": "response",
+			"status_word": self.status_word.to_json_value(),
+			"data": bytes_to_str(self.data.as_slice()),
+			"tlv_data": self.tlv_data.as_ref().map(DisplayBreakdown::to_json_value),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{Apdu, CommandApdu, Instruction, ResponseApdu};
+	use crate::{emv::MaskingSettings, error::ParseError};
+
+	// Tests
+	#[test]
+	fn parse_select_command() {
+		let expected = Ok(Apdu::Command(CommandApdu {
+			class:           0x00,
+			instruction:     0xA4,
+			p1:              0x04,
+			p2:              0x00,
+			data:            vec![0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10],
+			expected_length: Some(0x00),
+		}));
+		let result = Apdu::parse(
+			[
+				0x00, 0xA4, 0x04, 0x00, 0x07, 0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10, 0x00,
+			]
+			.as_slice(),
+			MaskingSettings {
+				characters: &[],
+				..MaskingSettings::default()
+			},
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_read_record_command() {
+		let expected = Ok((
+			Some(Instruction::ReadRecord),
+			Some("Record 1, SFI 2".to_owned()),
+		));
+		let result = CommandApdu::try_from([0x00, 0xB2, 0x01, 0x14, 0x00].as_slice())
+			.map(|command| (command.known_instruction(), command.parameter_description()));
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_response_with_tlv_data() {
+		let expected = Ok((vec![0x9F, 0x36, 0x02, 0x00, 0x01], true, 0x90));
+		let result = Apdu::parse(
+			[0x9F, 0x36, 0x02, 0x00, 0x01, 0x90, 0x00].as_slice(),
+			MaskingSettings {
+				characters: &[],
+				..MaskingSettings::default()
+			},
+		)
+		.map(|apdu| match apdu {
+			Apdu::Response(response) => (
+				response.data,
+				response.tlv_data.is_some(),
+				response.status_word.sw1,
+			),
+			Apdu::Command(_) => panic!("the testing value should be a response"),
+		});
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_successful_response() {
+		let expected = Ok((true, "Success".to_owned()));
+		let result = ResponseApdu::parse(
+			[0x9F, 0x36, 0x02, 0x00, 0x01, 0x90, 0x00].as_slice(),
+			MaskingSettings {
+				characters: &[],
+				..MaskingSettings::default()
+			},
+		)
+		.map(|response| {
+			(
+				response.tlv_data.is_some(),
+				response.status_word.description(),
+			)
+		});
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_error_status_only() {
+		let expected = Ok((vec![], None, "File not found".to_owned()));
+		let result = ResponseApdu::parse(
+			[0x6A, 0x82].as_slice(),
+			MaskingSettings {
+				characters: &[],
+				..MaskingSettings::default()
+			},
+		)
+		.map(|response| {
+			(
+				response.data,
+				response.tlv_data,
+				response.status_word.description(),
+			)
+		});
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_command_wrong_lc() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = CommandApdu::try_from([0x00, 0xA4, 0x04, 0x00, 0x07, 0xA0, 0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,486 @@
+//! The Answer To Reset (ATR), which is the first thing a smartcard sends after
+//! being powered up. It describes the protocols the card supports and how to
+//! communicate with it.
+//!
+//! Information for this can be found in [ISO/IEC 7816-3](https://www.iso.org/standard/38770.html),
+//! under section `8`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, header_colour_spec, severity_colour_spec},
+	util::{print_bytes, print_indentation},
+};
+use crate::{enum_repr_fallible, error::ParseError, util::bytes_to_str, DisplayBreakdown};
+
+// Constants
+const TA_PRESENT: u8 = 0b0001_0000;
+const TB_PRESENT: u8 = 0b0010_0000;
+const TC_PRESENT: u8 = 0b0100_0000;
+const TD_PRESENT: u8 = 0b1000_0000;
+const PROTOCOL_MASK: u8 = 0b0000_1111;
+/// T=15 isn't a real transmission protocol - it only indicates global
+/// interface bytes.
+const GLOBAL_PROTOCOL: u8 = 15;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct AnswerToReset {
+	/// The convention indicated by the initial character (TS).
+	pub convention:            Convention,
+	/// The format byte (T0).
+	pub format_byte:           u8,
+	pub interface_byte_groups: Vec<InterfaceByteGroup>,
+	pub historical_bytes:      Vec<u8>,
+	/// The check byte (TCK), which is only present if a protocol other than
+	/// T=0 is indicated.
+	pub check_byte:            Option<CheckByte>,
+}
+
+enum_repr_fallible! {
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Convention: u8, ParseError, { |_| ParseError::NonCompliant } {
+	Direct  = 0x3B => "Direct",
+	Inverse = 0x3F => "Inverse",
+}
+}
+
+/// A single group of interface bytes (`TAi`, `TBi`, `TCi`, and `TDi`).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct InterfaceByteGroup {
+	/// The group number (`i`), starting from 1.
+	pub number:   u8,
+	/// The protocol that the group applies to, as indicated by the previous
+	/// group's `TD` byte. `None` for the first group, which is global.
+	pub protocol: Option<u8>,
+	pub ta:       Option<u8>,
+	pub tb:       Option<u8>,
+	pub tc:       Option<u8>,
+	pub td:       Option<u8>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct CheckByte {
+	pub value:    u8,
+	/// The value the check byte should have, based on the rest of the ATR.
+	pub expected: u8,
+}
+
+impl CheckByte {
+	pub fn is_valid(&self) -> bool {
+		self.value == self.expected
+	}
+}
+
+impl AnswerToReset {
+	/// The transmission protocols offered by the card, in the order they're
+	/// indicated.
+	///
+	/// If no protocol is explicitly indicated, T=0 is implied.
+	pub fn protocols(&self) -> Vec<u8> {
+		let mut protocols = Vec::new();
+		for protocol in self
+			.interface_byte_groups
+			.iter()
+			.filter_map(|group| group.td)
+			.map(|td| td & PROTOCOL_MASK)
+			.filter(|protocol| *protocol != GLOBAL_PROTOCOL)
+		{
+			if !protocols.contains(&protocol) {
+				protocols.push(protocol);
+			}
+		}
+		if protocols.is_empty() {
+			protocols.push(0);
+		}
+
+		protocols
+	}
+}
+
+impl TryFrom<&[u8]> for AnswerToReset {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const MIN_BYTES: usize = 2;
+
+		if raw_bytes.len() < MIN_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Greater,
+				expected: MIN_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		let convention = Convention::try_from(raw_bytes[0])?;
+		let format_byte = raw_bytes[1];
+		let historical_byte_count = usize::from(format_byte & PROTOCOL_MASK);
+
+		// Each indicator byte (T0 or TDi) declares which interface bytes follow it
+		let mut index = MIN_BYTES;
+		let mut indicator = format_byte;
+		let mut protocol = None;
+		let mut check_byte_required = false;
+		let mut interface_byte_groups = Vec::new();
+		for number in 1.. {
+			let mut next_byte = |present_bit: u8| -> Result<Option<u8>, ParseError> {
+				if indicator & present_bit == 0 {
+					return Ok(None);
+				}
+				let byte = *raw_bytes.get(index).ok_or(ParseError::ByteCountIncorrect {
+					r#type:   Ordering::Greater,
+					expected: index + 1,
+					found:    raw_bytes.len(),
+				})?;
+				index += 1;
+				Ok(Some(byte))
+			};
+
+			let group = InterfaceByteGroup {
+				number,
+				protocol,
+				ta: next_byte(TA_PRESENT)?,
+				tb: next_byte(TB_PRESENT)?,
+				tc: next_byte(TC_PRESENT)?,
+				td: next_byte(TD_PRESENT)?,
+			};
+			let td = group.td;
+			interface_byte_groups.push(group);
+
+			let Some(td) = td else {
+				break;
+			};
+			let indicated_protocol = td & PROTOCOL_MASK;
+			if indicated_protocol != 0 {
+				check_byte_required = true;
+			}
+			indicator = td;
+			protocol = Some(indicated_protocol);
+		}
+
+		// The historical bytes and check byte should make up the remainder exactly
+		let expected_len = index + historical_byte_count + usize::from(check_byte_required);
+		if raw_bytes.len() != expected_len {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   if raw_bytes.len() < expected_len {
+					Ordering::Greater
+				} else {
+					Ordering::Equal
+				},
+				expected: expected_len,
+				found:    raw_bytes.len(),
+			});
+		}
+		let historical_bytes = raw_bytes[index..index + historical_byte_count].to_vec();
+
+		// The check byte is set so that all bytes from T0 to TCK XOR to 0
+		let check_byte = check_byte_required.then(|| CheckByte {
+			value:    raw_bytes[expected_len - 1],
+			expected: raw_bytes[1..expected_len - 1]
+				.iter()
+				.fold(0x00, |acc, byte| acc ^ byte),
+		});
+
+		Ok(Self {
+			convention,
+			format_byte,
+			interface_byte_groups,
+			historical_bytes,
+			check_byte,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for AnswerToReset {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let header_colour_spec = header_colour_spec();
+		let bold_colour_spec = bold_colour_spec();
+
+		// Print the convention
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Convention (TS):").ok();
+		stdout.reset().ok();
+		writeln!(
+			stdout,
+			" {:#04X} - {}",
+			self.convention as u8, self.convention
+		)
+		.ok();
+
+		// Print the protocols
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		write!(stdout, "Protocols Offered:").ok();
+		stdout.reset().ok();
+		writeln!(
+			stdout,
+			" {}",
+			self.protocols()
+				.iter()
+				.map(|protocol| format!("T={protocol}"))
+				.collect::<Vec<_>>()
+				.join(", ")
+		)
+		.ok();
+
+		// Print the interface bytes
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(stdout, "Interface Bytes:").ok();
+		stdout.reset().ok();
+		for group in &self.interface_byte_groups {
+			print_indentation(stdout, indentation + 1);
+			stdout.set_color(&bold_colour_spec).ok();
+			write!(stdout, "Group {}", group.number).ok();
+			stdout.reset().ok();
+			match group.protocol {
+				None | Some(GLOBAL_PROTOCOL) => writeln!(stdout, " (Global)").ok(),
+				Some(protocol) => writeln!(stdout, " (T={protocol})").ok(),
+			};
+
+			let bytes = [
+				("TA", group.ta),
+				("TB", group.tb),
+				("TC", group.tc),
+				("TD", group.td),
+			];
+			if bytes.iter().all(|(_, byte)| byte.is_none()) {
+				print_indentation(stdout, indentation + 2);
+				writeln!(stdout, "None").ok();
+			}
+			for (name, byte) in bytes {
+				let Some(byte) = byte else {
+					continue;
+				};
+				print_indentation(stdout, indentation + 2);
+				write!(stdout, "{name}{}: {byte:#04X}", group.number).ok();
+				if name == "TD" {
+					write!(stdout, " - Next protocol: T={}", byte & PROTOCOL_MASK).ok();
+				}
+				writeln!(stdout).ok();
+			}
+		}
+
+		// Print the historical bytes
+		print_indentation(stdout, indentation);
+		stdout.set_color(&header_colour_spec).ok();
+		writeln!(
+			stdout,
+			"Historical Bytes ({}):",
+			self.historical_bytes.len()
+		)
+		.ok();
+		stdout.reset().ok();
+		if self.historical_bytes.is_empty() {
+			print_indentation(stdout, indentation + 1);
+			writeln!(stdout, "None").ok();
+		} else {
+			print_bytes(
+				stdout,
+				self.historical_bytes.as_slice(),
+				16,
+				indentation + 1,
+			);
+		}
+
+		// Print the check byte
+		if let Some(check_byte) = self.check_byte {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&header_colour_spec).ok();
+			write!(stdout, "Check Byte (TCK):").ok();
+			stdout.reset().ok();
+			write!(stdout, " {:#04X}", check_byte.value).ok();
+			if check_byte.is_valid() {
+				writeln!(stdout, " - Valid").ok();
+			} else {
+				stdout
+					.set_color(&severity_colour_spec(
+						Severity::Error,
+						show_severity_colours,
+					))
+					.ok();
+				writeln!(stdout, " - Invalid, should be {:#04X}", check_byte.expected).ok();
+				stdout.reset().ok();
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"convention": self.convention.to_string(),
+			"format_byte": format!("{:02X}", self.format_byte),
+			"protocols": self.protocols(),
+			"interface_byte_groups": self
+				.interface_byte_groups
+				.iter()
+				.map(|group| {
+					let byte_str = |byte: Option<u8>| byte.map(|byte| format!("{byte:02X}"));
+					json!({
+						"number": group.number,
+						"protocol": group.protocol,
+						"ta": byte_str(group.ta),
+						"tb": byte_str(group.tb),
+						"tc": byte_str(group.tc),
+						"td": byte_str(group.td),
+					})
+				})
+				.collect::<Vec<_>>(),
+			"historical_bytes": bytes_to_str(self.historical_bytes.as_slice()),
+			"check_byte": self.check_byte.map(|check_byte| json!({
+				"value": format!("{:02X}", check_byte.value),
+				"expected": format!("{:02X}", check_byte.expected),
+				"valid": check_byte.is_valid(),
+			})),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::{AnswerToReset, CheckByte, Convention, InterfaceByteGroup};
+	use crate::error::ParseError;
+
+	// Tests
+	#[test]
+	fn parse_t0_only() {
+		let expected = Ok(AnswerToReset {
+			convention:            Convention::Direct,
+			format_byte:           0x6E,
+			interface_byte_groups: vec![InterfaceByteGroup {
+				number:   1,
+				protocol: None,
+				ta:       None,
+				tb:       Some(0x00),
+				tc:       Some(0x00),
+				td:       None,
+			}],
+			historical_bytes:      vec![
+				0x80, 0x31, 0x80, 0x66, 0xB0, 0x84, 0x12, 0x01, 0x6E, 0x01, 0x83, 0x00, 0x90, 0x00,
+			],
+			check_byte:            None,
+		});
+		let result = AnswerToReset::try_from(
+			[
+				0x3B, 0x6E, 0x00, 0x00, 0x80, 0x31, 0x80, 0x66, 0xB0, 0x84, 0x12, 0x01, 0x6E, 0x01,
+				0x83, 0x00, 0x90, 0x00,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_with_check_byte() {
+		let expected = Ok(AnswerToReset {
+			convention:            Convention::Direct,
+			format_byte:           0x8F,
+			interface_byte_groups: vec![
+				InterfaceByteGroup {
+					number:   1,
+					protocol: None,
+					ta:       None,
+					tb:       None,
+					tc:       None,
+					td:       Some(0x80),
+				},
+				InterfaceByteGroup {
+					number:   2,
+					protocol: Some(0),
+					ta:       None,
+					tb:       None,
+					tc:       None,
+					td:       Some(0x01),
+				},
+				InterfaceByteGroup {
+					number:   3,
+					protocol: Some(1),
+					ta:       None,
+					tb:       None,
+					tc:       None,
+					td:       None,
+				},
+			],
+			historical_bytes:      vec![
+				0x80, 0x4F, 0x0C, 0xA0, 0x00, 0x00, 0x03, 0x06, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+				0x00,
+			],
+			check_byte:            Some(CheckByte {
+				value:    0x6A,
+				expected: 0x6A,
+			}),
+		});
+		let result = AnswerToReset::try_from(
+			[
+				0x3B, 0x8F, 0x80, 0x01, 0x80, 0x4F, 0x0C, 0xA0, 0x00, 0x00, 0x03, 0x06, 0x03, 0x00,
+				0x01, 0x00, 0x00, 0x00, 0x00, 0x6A,
+			]
+			.as_slice(),
+		);
+
+		assert_eq!(expected, result);
+		assert_eq!(
+			vec![0, 1],
+			result.expect("the result was just checked").protocols()
+		);
+	}
+
+	#[test]
+	fn parse_bad_check_byte() {
+		let expected = Some(false);
+		let result = AnswerToReset::try_from(
+			[
+				0x3B, 0x8F, 0x80, 0x01, 0x80, 0x4F, 0x0C, 0xA0, 0x00, 0x00, 0x03, 0x06, 0x03, 0x00,
+				0x01, 0x00, 0x00, 0x00, 0x00, 0x6B,
+			]
+			.as_slice(),
+		)
+		.expect("the ATR is otherwise valid")
+		.check_byte
+		.map(|check_byte| check_byte.is_valid());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_invalid_convention() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = AnswerToReset::try_from([0x3A, 0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_truncated() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Greater,
+			expected: 6,
+			found:    4,
+		});
+		let result = AnswerToReset::try_from([0x3B, 0x62, 0x00, 0x01].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
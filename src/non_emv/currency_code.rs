@@ -0,0 +1,241 @@
+//! The ISO 4217 numeric currency code. In EMV, it's typically from tags
+//! `0x5F2A` (Transaction Currency Code) and `0x9F42` (Application Currency
+//! Code).
+//!
+//! Information for this can be found in [ISO 4217](https://www.iso.org/iso-4217-currency-codes.html).
+//!
+//! This is incomplete - only the more common currencies are included.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, util::bcd_to_decimal_string, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct CurrencyCode {
+	pub code:       u16,
+	/// The 3-letter alphabetic code, like `USD`.
+	pub alpha_code: &'static str,
+	/// The symbol the currency is commonly written with, if there's one that
+	/// isn't ambiguous on its own.
+	pub symbol:     Option<&'static str>,
+	/// The number of digits after the decimal point in the minor unit.
+	pub exponent:   u8,
+}
+
+impl CurrencyCode {
+	/// Formats an amount in minor units with the decimal point applied, along
+	/// with the currency symbol and code.
+	///
+	/// The exponent is passed in separately since EMV provides it in its own
+	/// tags, which should take priority over the one from the table.
+	pub fn format_amount(&self, amount: u64, exponent: u8) -> String {
+		format!(
+			"{}{} {}",
+			self.symbol.unwrap_or_default(),
+			format_minor_units(amount, exponent),
+			self.alpha_code
+		)
+	}
+}
+
+/// Formats an amount in minor units with the decimal point applied.
+pub fn format_minor_units(amount: u64, exponent: u8) -> String {
+	let digits = format!("{amount:0>width$}", width = usize::from(exponent) + 1);
+	if exponent == 0 {
+		return digits;
+	}
+
+	let (major, minor) = digits.split_at(digits.len() - usize::from(exponent));
+
+	format!("{major}.{minor}")
+}
+
+impl TryFrom<u16> for CurrencyCode {
+	type Error = ParseError;
+
+	fn try_from(code: u16) -> Result<Self, Self::Error> {
+		const MAX_VALUE: u16 = 999;
+
+		if code > MAX_VALUE {
+			return Err(ParseError::NonCompliant);
+		}
+
+		let (alpha_code, symbol, exponent) =
+			currency_details(code).ok_or(ParseError::Unrecognised)?;
+
+		Ok(Self {
+			code,
+			alpha_code,
+			symbol,
+			exponent,
+		})
+	}
+}
+
+impl TryFrom<&[u8]> for CurrencyCode {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 2;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		// The value is 3 BCD digits with a leading zero, so any hex digits or
+		// padding are invalid
+		let digits = bcd_to_decimal_string(raw_bytes)?;
+		if digits.len() != NUM_BYTES * 2 {
+			return Err(ParseError::InvalidBytes);
+		}
+
+		digits
+			.parse::<u16>()
+			.map_err(|_| ParseError::InvalidBytes)
+			.and_then(Self::try_from)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for CurrencyCode {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		write!(stdout, "{:0>3}", self.code).ok();
+		stdout.reset().ok();
+		write!(stdout, " - {}", self.alpha_code).ok();
+		if let Some(symbol) = self.symbol {
+			write!(stdout, " ({symbol})").ok();
+		}
+		writeln!(stdout).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"code": format!("{:0>3}", self.code),
+			"alpha_code": self.alpha_code,
+			"symbol": self.symbol,
+			"exponent": self.exponent,
+		})
+	}
+}
+
+/// Gets the alphabetic code, symbol, and exponent of a currency.
+#[cfg(not(tarpaulin_include))]
+fn currency_details(code: u16) -> Option<(&'static str, Option<&'static str>, u8)> {
+	match code {
+		32 => Some(("ARS", Some("$"), 2)),
+		36 => Some(("AUD", Some("$"), 2)),
+		48 => Some(("BHD", None, 3)),
+		124 => Some(("CAD", Some("$"), 2)),
+		152 => Some(("CLP", Some("$"), 0)),
+		156 => Some(("CNY", Some("\u{a5}"), 2)),
+		170 => Some(("COP", Some("$"), 2)),
+		203 => Some(("CZK", Some("K\u{10d}"), 2)),
+		208 => Some(("DKK", Some("kr"), 2)),
+		344 => Some(("HKD", Some("$"), 2)),
+		348 => Some(("HUF", Some("Ft"), 2)),
+		356 => Some(("INR", Some("\u{20b9}"), 2)),
+		360 => Some(("IDR", Some("Rp"), 2)),
+		376 => Some(("ILS", Some("\u{20aa}"), 2)),
+		392 => Some(("JPY", Some("\u{a5}"), 0)),
+		410 => Some(("KRW", Some("\u{20a9}"), 0)),
+		414 => Some(("KWD", None, 3)),
+		458 => Some(("MYR", Some("RM"), 2)),
+		484 => Some(("MXN", Some("$"), 2)),
+		512 => Some(("OMR", None, 3)),
+		554 => Some(("NZD", Some("$"), 2)),
+		578 => Some(("NOK", Some("kr"), 2)),
+		608 => Some(("PHP", Some("\u{20b1}"), 2)),
+		634 => Some(("QAR", None, 2)),
+		643 => Some(("RUB", Some("\u{20bd}"), 2)),
+		682 => Some(("SAR", None, 2)),
+		702 => Some(("SGD", Some("$"), 2)),
+		704 => Some(("VND", Some("\u{20ab}"), 0)),
+		710 => Some(("ZAR", Some("R"), 2)),
+		752 => Some(("SEK", Some("kr"), 2)),
+		756 => Some(("CHF", None, 2)),
+		764 => Some(("THB", Some("\u{e3f}"), 2)),
+		784 => Some(("AED", None, 2)),
+		826 => Some(("GBP", Some("\u{a3}"), 2)),
+		840 => Some(("USD", Some("$"), 2)),
+		901 => Some(("TWD", Some("$"), 2)),
+		949 => Some(("TRY", Some("\u{20ba}"), 2)),
+		978 => Some(("EUR", Some("\u{20ac}"), 2)),
+		985 => Some(("PLN", Some("z\u{142}"), 2)),
+		986 => Some(("BRL", Some("R$"), 2)),
+		_ => None,
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{format_minor_units, CurrencyCode};
+	use crate::error::ParseError;
+
+	// Tests
+	#[test]
+	fn parse_known() {
+		let expected = Ok(CurrencyCode {
+			code:       840,
+			alpha_code: "USD",
+			symbol:     Some("$"),
+			exponent:   2,
+		});
+		let result = CurrencyCode::try_from([0x08, 0x40].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_unknown() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = CurrencyCode::try_from([0x09, 0x99].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_non_bcd() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = CurrencyCode::try_from([0x08, 0x4A].as_slice());
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_padded() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = CurrencyCode::try_from([0x08, 0x4F].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn format_amounts() {
+		let expected = vec!["123.45", "0.05", "1.000", "500"];
+		let result = vec![
+			format_minor_units(12345, 2),
+			format_minor_units(5, 2),
+			format_minor_units(1000, 3),
+			format_minor_units(500, 0),
+		];
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,210 @@
+//! The Luhn (mod 10) check, used to validate numbers like PANs.
+//!
+//! Information for this can be found in [ISO/IEC 7812](https://www.iso.org/standard/70484.html),
+//! under `Annex B`.
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+use crate::{error::ParseError, util::luhn_check_digit, DisplayBreakdown};
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LuhnCheck {
+	pub digits:               String,
+	pub expected_check_digit: u8,
+	pub valid:                bool,
+}
+
+impl TryFrom<&str> for LuhnCheck {
+	type Error = ParseError;
+
+	fn try_from(number: &str) -> Result<Self, Self::Error> {
+		// Whitespace is ignored so that numbers can be pasted in their usual grouped
+		// form
+		let digits = number
+			.chars()
+			.filter(|c| !c.is_ascii_whitespace())
+			.collect::<String>();
+		if digits.len() < 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+			return Err(ParseError::InvalidNumber);
+		}
+
+		let (payload, check_digit) = digits.split_at(digits.len() - 1);
+		let expected_check_digit = luhn_check_digit(payload)?;
+		let valid = check_digit.parse::<u8>() == Ok(expected_check_digit);
+
+		Ok(Self {
+			digits,
+			expected_check_digit,
+			valid,
+		})
+	}
+}
+
+/// A number that's missing its Luhn check digit, along with the check digit it
+/// should have.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LuhnCompletion {
+	pub payload:     String,
+	pub check_digit: u8,
+}
+
+impl LuhnCompletion {
+	/// The number with the check digit appended.
+	pub fn completed(&self) -> String {
+		format!("{}{}", self.payload, self.check_digit)
+	}
+}
+
+impl TryFrom<&str> for LuhnCompletion {
+	type Error = ParseError;
+
+	fn try_from(number: &str) -> Result<Self, Self::Error> {
+		// Whitespace is ignored so that numbers can be pasted in their usual grouped
+		// form
+		let payload = number
+			.chars()
+			.filter(|c| !c.is_ascii_whitespace())
+			.collect::<String>();
+
+		Ok(Self {
+			check_digit: luhn_check_digit(payload.as_str())?,
+			payload,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for LuhnCheck {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "{}", self.digits).ok();
+		stdout.reset().ok();
+
+		print_indentation(stdout, indentation);
+		if self.valid {
+			writeln!(stdout, "Valid - the check digit is correct").ok();
+		} else {
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Error,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(
+				stdout,
+				"Invalid - the check digit should be {}",
+				self.expected_check_digit
+			)
+			.ok();
+			stdout.reset().ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"digits": self.digits,
+			"expected_check_digit": self.expected_check_digit,
+			"valid": self.valid,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for LuhnCompletion {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		write!(stdout, "{}", self.payload).ok();
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "{}", self.check_digit).ok();
+		stdout.reset().ok();
+
+		print_indentation(stdout, indentation);
+		writeln!(stdout, "The check digit is {}", self.check_digit).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"payload": self.payload,
+			"check_digit": self.check_digit,
+			"completed": self.completed(),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{LuhnCheck, LuhnCompletion};
+	use crate::error::ParseError;
+
+	// Tests
+	#[test]
+	fn check_valid() {
+		let expected = Ok(LuhnCheck {
+			digits:               "4111111111111111".to_owned(),
+			expected_check_digit: 1,
+			valid:                true,
+		});
+		let result = LuhnCheck::try_from("4111 1111 1111 1111");
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn check_invalid() {
+		let expected = Ok(LuhnCheck {
+			digits:               "4111111111111112".to_owned(),
+			expected_check_digit: 1,
+			valid:                false,
+		});
+		let result = LuhnCheck::try_from("4111111111111112");
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn check_non_digit() {
+		let expected = Err(ParseError::InvalidNumber);
+		let result = LuhnCheck::try_from("4111-1111-1111-1111");
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn complete() {
+		let expected = Ok("4111111111111111".to_owned());
+		let result =
+			LuhnCompletion::try_from("4111 1111 1111 111").map(|completion| completion.completed());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn complete_empty() {
+		let expected = Err(ParseError::InvalidNumber);
+		let result = LuhnCompletion::try_from(" ");
+
+		assert_eq!(expected, result);
+	}
+}
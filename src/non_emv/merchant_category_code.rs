@@ -0,0 +1,267 @@
+//! The Merchant Category Code (MCC), which describes the type of business a
+//! merchant conducts. In EMV, it's typically from tag `0x9F15`.
+//!
+//! Information for this can be found in [ISO 18245](https://www.iso.org/standard/79450.html).
+//!
+//! This is incomplete - only the more common codes are included, since the
+//! full list is very long and payment systems define many of their own.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, util::bcd_to_decimal_string, DisplayBreakdown};
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct MerchantCategoryCode {
+	pub code:        u16,
+	pub description: &'static str,
+}
+
+impl TryFrom<u16> for MerchantCategoryCode {
+	type Error = ParseError;
+
+	fn try_from(code: u16) -> Result<Self, Self::Error> {
+		const MAX_VALUE: u16 = 9999;
+
+		if code > MAX_VALUE {
+			return Err(ParseError::NonCompliant);
+		}
+
+		let description = mcc_description(code).ok_or(ParseError::Unrecognised)?;
+
+		Ok(Self { code, description })
+	}
+}
+
+impl TryFrom<&[u8]> for MerchantCategoryCode {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 2;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		// The value is 4 BCD digits, so any hex digits or padding are invalid
+		let digits = bcd_to_decimal_string(raw_bytes)?;
+		if digits.len() != NUM_BYTES * 2 {
+			return Err(ParseError::InvalidBytes);
+		}
+
+		digits
+			.parse::<u16>()
+			.map_err(|_| ParseError::InvalidBytes)
+			.and_then(Self::try_from)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for MerchantCategoryCode {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		write!(stdout, "{:0>4}", self.code).ok();
+		stdout.reset().ok();
+		writeln!(stdout, " - {}", self.description).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"code": format!("{:0>4}", self.code),
+			"description": self.description,
+		})
+	}
+}
+
+/// Gets the description of a Merchant Category Code.
+#[cfg(not(tarpaulin_include))]
+fn mcc_description(code: u16) -> Option<&'static str> {
+	match code {
+		742 => Some("Veterinary Services"),
+		763 => Some("Agricultural Cooperatives"),
+		780 => Some("Landscaping and Horticultural Services"),
+		1520 => Some("General Contractors - Residential and Commercial"),
+		1711 => Some("Heating, Plumbing, and Air Conditioning Contractors"),
+		1731 => Some("Electrical Contractors"),
+		1799 => Some("Special Trade Contractors (Not Elsewhere Classified)"),
+		3000..=3350 => Some("Airlines, Air Carriers"),
+		3351..=3500 => Some("Car Rental Agencies"),
+		3501..=3999 => Some("Lodging - Hotels, Motels, Resorts"),
+		4011 => Some("Railroads"),
+		4111 => Some("Local and Suburban Commuter Passenger Transportation, Including Ferries"),
+		4112 => Some("Passenger Railways"),
+		4121 => Some("Taxicabs and Limousines"),
+		4131 => Some("Bus Lines"),
+		4214 => Some("Motor Freight Carriers and Trucking"),
+		4215 => Some("Courier Services"),
+		4411 => Some("Steamship and Cruise Lines"),
+		4511 => Some("Airlines and Air Carriers"),
+		4722 => Some("Travel Agencies and Tour Operators"),
+		4784 => Some("Tolls and Bridge Fees"),
+		4789 => Some("Transportation Services (Not Elsewhere Classified)"),
+		4812 => Some("Telecommunication Equipment and Telephone Sales"),
+		4814 => Some("Telecommunication Services"),
+		4816 => Some("Computer Network and Information Services"),
+		4899 => Some("Cable, Satellite, and Other Pay Television and Radio Services"),
+		4900 => Some("Utilities - Electric, Gas, Water, and Sanitary"),
+		5013 => Some("Motor Vehicle Supplies and New Parts"),
+		5045 => Some("Computers, Computer Peripheral Equipment, and Software"),
+		5111 => Some("Stationery, Office Supplies, and Printing and Writing Paper"),
+		5200 => Some("Home Supply Warehouse Stores"),
+		5211 => Some("Lumber and Building Materials Stores"),
+		5251 => Some("Hardware Stores"),
+		5300 => Some("Wholesale Clubs"),
+		5309 => Some("Duty Free Stores"),
+		5310 => Some("Discount Stores"),
+		5311 => Some("Department Stores"),
+		5331 => Some("Variety Stores"),
+		5399 => Some("Miscellaneous General Merchandise"),
+		5411 => Some("Grocery Stores, Supermarkets"),
+		5422 => Some("Freezer and Locker Meat Provisioners"),
+		5441 => Some("Candy, Nut, and Confectionery Stores"),
+		5451 => Some("Dairy Products Stores"),
+		5462 => Some("Bakeries"),
+		5499 => Some("Miscellaneous Food Stores - Convenience Stores and Specialty Markets"),
+		5511 => Some("Car and Truck Dealers (New and Used)"),
+		5521 => Some("Car and Truck Dealers (Used Only)"),
+		5532 => Some("Automotive Tire Stores"),
+		5533 => Some("Automotive Parts and Accessories Stores"),
+		5541 => Some("Service Stations"),
+		5542 => Some("Automated Fuel Dispensers"),
+		5611 => Some("Men's and Boys' Clothing and Accessories Stores"),
+		5621 => Some("Women's Ready-To-Wear Stores"),
+		5651 => Some("Family Clothing Stores"),
+		5655 => Some("Sports and Riding Apparel Stores"),
+		5661 => Some("Shoe Stores"),
+		5691 => Some("Men's and Women's Clothing Stores"),
+		5699 => Some("Miscellaneous Apparel and Accessory Shops"),
+		5712 => Some("Furniture, Home Furnishings, and Equipment Stores"),
+		5722 => Some("Household Appliance Stores"),
+		5732 => Some("Electronics Stores"),
+		5734 => Some("Computer Software Stores"),
+		5735 => Some("Record Stores"),
+		5812 => Some("Eating Places, Restaurants"),
+		5813 => Some("Drinking Places - Bars, Taverns, Nightclubs"),
+		5814 => Some("Fast Food Restaurants"),
+		5815 => Some("Digital Goods - Media"),
+		5816 => Some("Digital Goods - Games"),
+		5817 => Some("Digital Goods - Applications"),
+		5818 => Some("Digital Goods - Large Digital Goods Merchant"),
+		5912 => Some("Drug Stores and Pharmacies"),
+		5921 => Some("Package Stores - Beer, Wine, and Liquor"),
+		5941 => Some("Sporting Goods Stores"),
+		5942 => Some("Book Stores"),
+		5943 => Some("Stationery, Office, and School Supply Stores"),
+		5944 => Some("Jewelry, Watch, Clock, and Silverware Stores"),
+		5945 => Some("Hobby, Toy, and Game Shops"),
+		5947 => Some("Gift, Card, Novelty, and Souvenir Shops"),
+		5964 => Some("Direct Marketing - Catalog Merchant"),
+		5967 => Some("Direct Marketing - Inbound Teleservices Merchant"),
+		5977 => Some("Cosmetic Stores"),
+		5983 => Some("Fuel Dealers"),
+		5992 => Some("Florists"),
+		5993 => Some("Cigar Stores and Stands"),
+		5994 => Some("News Dealers and Newsstands"),
+		5995 => Some("Pet Shops, Pet Food, and Supplies"),
+		5999 => Some("Miscellaneous and Specialty Retail Stores"),
+		6010 => Some("Financial Institutions - Manual Cash Disbursements"),
+		6011 => Some("Financial Institutions - Automated Cash Disbursements"),
+		6012 => Some("Financial Institutions - Merchandise, Services, and Debt Repayment"),
+		6051 => Some("Non-Financial Institutions - Foreign Currency, Money Orders, and Quasi-Cash"),
+		6211 => Some("Security Brokers and Dealers"),
+		6300 => Some("Insurance Sales, Underwriting, and Premiums"),
+		6513 => Some("Real Estate Agents and Managers - Rentals"),
+		6540 => Some("Non-Financial Institutions - Stored Value Card Purchase and Load"),
+		7011 => Some("Lodging - Hotels, Motels, Resorts (Not Elsewhere Classified)"),
+		7210 => Some("Laundry, Cleaning, and Garment Services"),
+		7230 => Some("Beauty and Barber Shops"),
+		7298 => Some("Health and Beauty Spas"),
+		7311 => Some("Advertising Services"),
+		7372 => Some("Computer Programming, Data Processing, and Integrated Systems Design"),
+		7399 => Some("Business Services (Not Elsewhere Classified)"),
+		7512 => Some("Automobile Rental Agency"),
+		7523 => Some("Parking Lots and Garages"),
+		7538 => Some("Automotive Service Shops (Non-Dealer)"),
+		7542 => Some("Car Washes"),
+		7832 => Some("Motion Picture Theatres"),
+		7841 => Some("Video Tape Rental Stores"),
+		7922 => Some("Theatrical Producers and Ticket Agencies"),
+		7941 => Some("Commercial Sports, Professional Sports Clubs, and Sports Promoters"),
+		7991 => Some("Tourist Attractions and Exhibits"),
+		7995 => Some("Betting, Including Lottery Tickets, Casino Gaming Chips, and Wagers"),
+		7996 => Some("Amusement Parks, Circuses, and Carnivals"),
+		7997 => Some("Membership Clubs - Sports, Recreation, Athletic"),
+		7999 => Some("Recreation Services (Not Elsewhere Classified)"),
+		8011 => Some("Doctors and Physicians (Not Elsewhere Classified)"),
+		8021 => Some("Dentists and Orthodontists"),
+		8043 => Some("Opticians, Optical Goods, and Eyeglasses"),
+		8062 => Some("Hospitals"),
+		8099 => Some("Medical Services and Health Practitioners (Not Elsewhere Classified)"),
+		8111 => Some("Legal Services and Attorneys"),
+		8211 => Some("Elementary and Secondary Schools"),
+		8220 => Some("Colleges, Universities, Professional Schools, and Junior Colleges"),
+		8299 => Some("Schools and Educational Services (Not Elsewhere Classified)"),
+		8398 => Some("Charitable and Social Service Organizations"),
+		8651 => Some("Political Organizations"),
+		8699 => Some("Membership Organizations (Not Elsewhere Classified)"),
+		8999 => Some("Professional Services (Not Elsewhere Classified)"),
+		9211 => Some("Court Costs, Including Alimony and Child Support"),
+		9222 => Some("Fines"),
+		9311 => Some("Tax Payments"),
+		9399 => Some("Government Services (Not Elsewhere Classified)"),
+		9402 => Some("Postal Services - Government Only"),
+		_ => None,
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::MerchantCategoryCode;
+	use crate::{error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::MerchantCategoryCode, 2);
+
+	#[test]
+	fn parse_known() {
+		let expected = Ok(MerchantCategoryCode {
+			code:        5411,
+			description: "Grocery Stores, Supermarkets",
+		});
+		let result = MerchantCategoryCode::try_from([0x54, 0x11].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_unknown() {
+		let expected = Err(ParseError::Unrecognised);
+		let result = MerchantCategoryCode::try_from([0x00, 0x01].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_non_bcd() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = MerchantCategoryCode::try_from([0x54, 0x1A].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
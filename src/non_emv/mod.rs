@@ -1,7 +1,27 @@
 //! All non-EMV-related parsers.
 
 // Modules
+mod apdu;
+mod atr;
+mod currency_code;
+mod luhn_check;
+mod merchant_category_code;
+mod primary_account_number;
 mod service_code;
+mod status_word;
+mod track_1_data;
+mod track_2_data;
 
 // Public Exports
-pub use self::service_code::*;
+pub use self::{
+	apdu::*,
+	atr::*,
+	currency_code::*,
+	luhn_check::*,
+	merchant_category_code::*,
+	primary_account_number::*,
+	service_code::*,
+	status_word::*,
+	track_1_data::*,
+	track_2_data::*,
+};
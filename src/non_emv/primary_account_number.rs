@@ -0,0 +1,272 @@
+//! Everything for handling Primary Account Numbers (PANs).
+//!
+//! Information for this can be found in [ISO/IEC 7812](https://www.iso.org/standard/70484.html).
+//!
+//! When stored in EMV tags like `0x5A`, the PAN is encoded as compressed
+//! numeric data, padded with a trailing `F` nibble if there are an odd number
+//! of digits.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{
+	emv::{Finding, Severity},
+	error::ParseError,
+	util::{bcd_to_decimal_string, luhn_is_valid},
+	DisplayBreakdown,
+};
+#[cfg(feature = "display")]
+use crate::{
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+
+// Constants
+/// The maximum number of digits in a PAN, according to ISO/IEC 7812.
+const MAX_DIGITS: usize = 19;
+/// The number of digits to display in each group when formatting the PAN.
+const DIGITS_PER_GROUP: usize = 4;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct PrimaryAccountNumber {
+	digits:     String,
+	luhn_valid: bool,
+}
+
+impl PrimaryAccountNumber {
+	/// The PAN digits, with no formatting applied.
+	pub fn digits(&self) -> &str {
+		self.digits.as_str()
+	}
+
+	/// Whether the PAN passes the Luhn check.
+	pub fn luhn_valid(&self) -> bool {
+		self.luhn_valid
+	}
+
+	/// Formats the PAN digits in groups of four, separated by spaces.
+	pub fn formatted(&self) -> String {
+		let mut result =
+			String::with_capacity(self.digits.len() + self.digits.len() / DIGITS_PER_GROUP);
+		for (i, digit) in self.digits.chars().enumerate() {
+			if i > 0 && i % DIGITS_PER_GROUP == 0 {
+				result.push(' ');
+			}
+			result.push(digit);
+		}
+		result
+	}
+}
+
+impl TryFrom<&str> for PrimaryAccountNumber {
+	type Error = ParseError;
+
+	fn try_from(digits: &str) -> Result<Self, Self::Error> {
+		if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+			return Err(ParseError::InvalidNumber);
+		}
+		if digits.len() > MAX_DIGITS {
+			return Err(ParseError::NonCompliant);
+		}
+
+		Ok(Self {
+			digits:     digits.to_owned(),
+			luhn_valid: luhn_is_valid(digits) == Ok(true),
+		})
+	}
+}
+
+impl TryFrom<&[u8]> for PrimaryAccountNumber {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const MAX_BYTES: usize = MAX_DIGITS.div_ceil(2);
+
+		if raw_bytes.len() > MAX_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Less,
+				expected: MAX_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Self::try_from(bcd_to_decimal_string(raw_bytes)?.as_str())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for PrimaryAccountNumber {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		// Print the formatted PAN
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		writeln!(stdout, "{}", self.formatted()).ok();
+		stdout.reset().ok();
+
+		// Print the Luhn check result
+		print_indentation(stdout, indentation);
+		if self.luhn_valid {
+			writeln!(stdout, "Luhn check passed").ok();
+		} else {
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Error,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(
+				stdout,
+				"Luhn check failed - the PAN may have been transcribed incorrectly"
+			)
+			.ok();
+			stdout.reset().ok();
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"digits": self.digits,
+			"luhn_valid": self.luhn_valid,
+		})
+	}
+
+	fn get_notable_bits(&self) -> Vec<Finding> {
+		if self.luhn_valid {
+			return Vec::new();
+		}
+
+		vec![Finding {
+			message:  "Luhn check failed - the PAN may have been transcribed incorrectly"
+				.to_owned(),
+			severity: Severity::Error,
+		}]
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::cmp::Ordering;
+
+	use super::PrimaryAccountNumber;
+	use crate::{emv::Severity, error::ParseError, DisplayBreakdown};
+
+	// Tests
+	#[test]
+	fn parse_valid_even_length() {
+		let expected = Ok(PrimaryAccountNumber {
+			digits:     "4111111111111111".to_owned(),
+			luhn_valid: true,
+		});
+		let result = PrimaryAccountNumber::try_from(
+			[0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_valid_with_padding() {
+		let expected = Ok(PrimaryAccountNumber {
+			digits:     "374245455400126".to_owned(),
+			luhn_valid: true,
+		});
+		let result = PrimaryAccountNumber::try_from(
+			[0x37, 0x42, 0x45, 0x45, 0x54, 0x00, 0x12, 0x6F].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_luhn_failure() {
+		let expected = Ok(PrimaryAccountNumber {
+			digits:     "4111111111111112".to_owned(),
+			luhn_valid: false,
+		});
+		let result = PrimaryAccountNumber::try_from(
+			[0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x12].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_luhn_failure_with_padding() {
+		let expected = Ok(PrimaryAccountNumber {
+			digits:     "374245455400127".to_owned(),
+			luhn_valid: false,
+		});
+		let result = PrimaryAccountNumber::try_from(
+			[0x37, 0x42, 0x45, 0x45, 0x54, 0x00, 0x12, 0x7F].as_slice(),
+		);
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_padding_not_at_end() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = PrimaryAccountNumber::try_from([0x41, 0xF1, 0x11, 0x11].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_non_decimal_nibble() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = PrimaryAccountNumber::try_from([0x41, 0x1A, 0x11, 0x11].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_too_many_bytes() {
+		let expected = Err(ParseError::ByteCountIncorrect {
+			r#type:   Ordering::Less,
+			expected: 10,
+			found:    11,
+		});
+		let result = PrimaryAccountNumber::try_from([0x11; 11].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn format_in_groups_of_four() {
+		let expected = "3742 4545 5400 126";
+		let result = PrimaryAccountNumber::try_from("374245455400126")
+			.unwrap()
+			.formatted();
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_failure_is_notable() {
+		let expected = Ok(vec![Severity::Error]);
+		let result = PrimaryAccountNumber::try_from(
+			[0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x12].as_slice(),
+		)
+		.map(|pan| {
+			pan.get_notable_bits()
+				.into_iter()
+				.map(|notable_bit| notable_bit.severity)
+				.collect::<Vec<_>>()
+		});
+
+		assert_eq!(expected, result);
+	}
+}
@@ -5,19 +5,22 @@
 // Uses
 use std::cmp::Ordering;
 
-use termcolor::{StandardStream, WriteColor};
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
 
 use crate::{
 	enum_no_repr_infallible,
 	error::ParseError,
-	output_colours::bold_colour_spec,
-	parse_str_to_u16,
-	util::{bytes_to_str, print_indentation},
+	util::{bytes_to_str, parse_str_to_u16},
 	DisplayBreakdown,
 };
+#[cfg(feature = "display")]
+use crate::{output_colours::bold_colour_spec, util::print_indentation};
 
 // Struct Implementation
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ServiceCode {
 	number:                   u16,
 	interchange:              Interchange,
@@ -77,6 +80,13 @@ pub enum PinRequirements: u8 {
 }
 }
 
+impl ServiceCode {
+	/// The card technology indicated by the first digit.
+	pub fn technology(&self) -> Technology {
+		self.technology
+	}
+}
+
 impl TryFrom<u16> for ServiceCode {
 	type Error = ParseError;
 
@@ -120,13 +130,14 @@ impl TryFrom<&[u8]> for ServiceCode {
 
 #[cfg(not(tarpaulin_include))]
 impl DisplayBreakdown for ServiceCode {
-	fn display_breakdown(&self, stdout: &mut StandardStream, indentation: u8, _: bool) {
+	#[cfg(feature = "display")]
+	fn display_breakdown(&self, stdout: &mut dyn WriteColor, indentation: u8, _: bool) {
 		let bold_colour_spec = bold_colour_spec();
 
 		// Print the numeric representation
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		stdout.set_color(&bold_colour_spec).ok();
-		println!("{:0>3}", self.number);
+		writeln!(stdout, "{:0>3}", self.number).ok();
 		stdout.reset().ok();
 
 		// Because the structure of the service code is much more rigidly-defined, the
@@ -136,40 +147,51 @@ impl DisplayBreakdown for ServiceCode {
 		// entry is a kind of category title, and alignment is more important.
 
 		// Allowed Services
-		print_indentation(indentation);
-		print!("\u{2502}\u{2502}\u{251c} ");
+		print_indentation(stdout, indentation);
+		write!(stdout, "\u{2502}\u{2502}\u{251c} ").ok();
 		stdout.set_color(&bold_colour_spec).ok();
-		print!("Allowed Services:");
+		write!(stdout, "Allowed Services:").ok();
 		stdout.reset().ok();
-		println!("         {}", self.allowed_services);
+		writeln!(stdout, "         {}", self.allowed_services).ok();
 		// PIN Requirements
-		print_indentation(indentation);
-		print!("\u{2502}\u{2502}\u{2514} ");
+		print_indentation(stdout, indentation);
+		write!(stdout, "\u{2502}\u{2502}\u{2514} ").ok();
 		stdout.set_color(&bold_colour_spec).ok();
-		print!("PIN Requirements:");
+		write!(stdout, "PIN Requirements:").ok();
 		stdout.reset().ok();
-		println!("         {}", self.pin_requirements);
+		writeln!(stdout, "         {}", self.pin_requirements).ok();
 		// Authorisation Processing
-		print_indentation(indentation);
-		print!("\u{2502}\u{2514}\u{2500} ");
+		print_indentation(stdout, indentation);
+		write!(stdout, "\u{2502}\u{2514}\u{2500} ").ok();
 		stdout.set_color(&bold_colour_spec).ok();
-		print!("Authorisation Processing:");
+		write!(stdout, "Authorisation Processing:").ok();
 		stdout.reset().ok();
-		println!(" {}", self.authorisation_processing);
+		writeln!(stdout, " {}", self.authorisation_processing).ok();
 		// Interchange
-		print_indentation(indentation);
-		print!("\u{251c}\u{2500}\u{2500} ");
+		print_indentation(stdout, indentation);
+		write!(stdout, "\u{251c}\u{2500}\u{2500} ").ok();
 		stdout.set_color(&bold_colour_spec).ok();
-		print!("Interchange:");
+		write!(stdout, "Interchange:").ok();
 		stdout.reset().ok();
-		println!("              {}", self.interchange);
+		writeln!(stdout, "              {}", self.interchange).ok();
 		// Technology
-		print_indentation(indentation);
-		print!("\u{2514}\u{2500}\u{2500} ");
+		print_indentation(stdout, indentation);
+		write!(stdout, "\u{2514}\u{2500}\u{2500} ").ok();
 		stdout.set_color(&bold_colour_spec).ok();
-		print!("Technology:");
+		write!(stdout, "Technology:").ok();
 		stdout.reset().ok();
-		println!("               {}", self.technology);
+		writeln!(stdout, "               {}", self.technology).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"number": self.number,
+			"interchange": self.interchange.to_string(),
+			"technology": self.technology.to_string(),
+			"authorisation_processing": self.authorisation_processing.to_string(),
+			"allowed_services": self.allowed_services.to_string(),
+			"pin_requirements": self.pin_requirements.to_string(),
+		})
 	}
 }
 
@@ -187,9 +209,22 @@ mod tests {
 		ServiceCode,
 		Technology,
 	};
-	use crate::error::ParseError;
+	use crate::{display_breakdown_matches, error::ParseError};
 
 	// Tests
+	display_breakdown_matches!(
+		display_breakdown,
+		ServiceCode::try_from(201).expect("the service code is valid"),
+		concat!(
+			"201\n",
+			"││├ Allowed Services:         No restrictions\n",
+			"││└ PIN Requirements:         None\n",
+			"│└─ Authorisation Processing: Normal\n",
+			"├── Interchange:              International\n",
+			"└── Technology:               Integrated circuit card (ICC)\n",
+		)
+	);
+
 	/// This value is the service code of my personal Interac debit card.
 	#[test]
 	fn parse_interac_debit() {
@@ -0,0 +1,232 @@
+//! ISO 7816 status words (`SW1 SW2`), which are returned at the end of every
+//! response APDU to indicate the result of the command.
+//!
+//! Information for this can be found in [ISO/IEC 7816-4](https://www.iso.org/standard/77180.html),
+//! under section `5.6`, and in EMV Book 3, under section `6.3.5`.
+
+// Uses
+use std::cmp::Ordering;
+
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+use crate::{enum_no_repr_fallible, error::ParseError, DisplayBreakdown};
+
+// Struct Implementation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct StatusWord {
+	pub sw1:      u8,
+	pub sw2:      u8,
+	pub category: StatusWordCategory,
+}
+
+enum_no_repr_fallible! {
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StatusWordCategory: u8, ParseError, { |_| ParseError::NonCompliant } {
+	NormalProcessing = 0x90 | 0x61                => "Normal processing",
+	Warning          = 0x62 | 0x63                => "Warning",
+	ExecutionError   = 0x64..=0x66                => "Execution error",
+	CheckingError    = 0x67..=0x6F                => "Checking error",
+	Proprietary      = 0x91..=0x9F                => "Proprietary",
+}
+}
+
+impl StatusWord {
+	/// Gets a description of the status word.
+	///
+	/// Well-known values have specific descriptions, and everything else falls
+	/// back to a description of the `SW1` value.
+	#[cfg(not(tarpaulin_include))]
+	pub fn description(&self) -> String {
+		match (self.sw1, self.sw2) {
+			// Normal processing
+			(0x90, 0x00) => "Success".to_owned(),
+			(0x61, remaining) => format!("Success, {remaining} more bytes of data available"),
+			// Warnings
+			(0x62, 0x81) => "Part of the returned data may be corrupted".to_owned(),
+			(0x62, 0x82) => "End of file or record reached before reading Le bytes".to_owned(),
+			(0x62, 0x83) => "Selected file invalidated".to_owned(),
+			(0x62, 0x84) => "FCI not formatted according to ISO 7816-4".to_owned(),
+			(0x62, _) => "Warning, state of non-volatile memory unchanged".to_owned(),
+			(0x63, 0x00) => "Authentication failed".to_owned(),
+			(0x63, counter @ 0xC0..=0xCF) => {
+				format!("Verification failed, {} tries remaining", counter & 0x0F)
+			}
+			(0x63, _) => "Warning, state of non-volatile memory changed".to_owned(),
+			// Execution errors
+			(0x64, _) => "Execution error, state of non-volatile memory unchanged".to_owned(),
+			(0x65, 0x81) => "Memory failure".to_owned(),
+			(0x65, _) => "Execution error, state of non-volatile memory changed".to_owned(),
+			(0x66, _) => "Security-related issue".to_owned(),
+			// Checking errors
+			(0x67, 0x00) => "Wrong length".to_owned(),
+			(0x68, 0x81) => "Logical channel not supported".to_owned(),
+			(0x68, 0x82) => "Secure messaging not supported".to_owned(),
+			(0x68, _) => "Functions in CLA not supported".to_owned(),
+			(0x69, 0x81) => "Command incompatible with file structure".to_owned(),
+			(0x69, 0x82) => "Security status not satisfied".to_owned(),
+			(0x69, 0x83) => "Authentication method blocked".to_owned(),
+			(0x69, 0x84) => "Referenced data invalidated".to_owned(),
+			(0x69, 0x85) => "Conditions of use not satisfied".to_owned(),
+			(0x69, 0x86) => "Command not allowed (no current EF)".to_owned(),
+			(0x69, 0x87) => "Expected secure messaging data objects missing".to_owned(),
+			(0x69, 0x88) => "Incorrect secure messaging data objects".to_owned(),
+			(0x69, _) => "Command not allowed".to_owned(),
+			(0x6A, 0x80) => "Incorrect parameters in the data field".to_owned(),
+			(0x6A, 0x81) => "Function not supported".to_owned(),
+			(0x6A, 0x82) => "File not found".to_owned(),
+			(0x6A, 0x83) => "Record not found".to_owned(),
+			(0x6A, 0x84) => "Not enough memory space in the file".to_owned(),
+			(0x6A, 0x86) => "Incorrect parameters P1-P2".to_owned(),
+			(0x6A, 0x88) => "Referenced data not found".to_owned(),
+			(0x6A | 0x6B, _) => "Wrong parameters P1-P2".to_owned(),
+			(0x6C, exact_length) => format!("Wrong Le field, the exact length is {exact_length}"),
+			(0x6D, _) => "Instruction code not supported or invalid".to_owned(),
+			(0x6E, _) => "Class not supported".to_owned(),
+			(0x6F, _) => "No precise diagnosis".to_owned(),
+			// Everything else
+			_ => self.category.to_string(),
+		}
+	}
+}
+
+impl TryFrom<&[u8]> for StatusWord {
+	type Error = ParseError;
+
+	fn try_from(raw_bytes: &[u8]) -> Result<Self, Self::Error> {
+		const NUM_BYTES: usize = 2;
+
+		if raw_bytes.len() != NUM_BYTES {
+			return Err(ParseError::ByteCountIncorrect {
+				r#type:   Ordering::Equal,
+				expected: NUM_BYTES,
+				found:    raw_bytes.len(),
+			});
+		}
+
+		Ok(Self {
+			sw1:      raw_bytes[0],
+			sw2:      raw_bytes[1],
+			category: StatusWordCategory::try_from(raw_bytes[0])?,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for StatusWord {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let severity = match self.category {
+			StatusWordCategory::NormalProcessing | StatusWordCategory::Proprietary => {
+				Severity::Normal
+			}
+			StatusWordCategory::Warning => Severity::Warning,
+			StatusWordCategory::ExecutionError | StatusWordCategory::CheckingError => {
+				Severity::Error
+			}
+		};
+
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec()).ok();
+		write!(stdout, "{:02X}{:02X}", self.sw1, self.sw2).ok();
+		stdout.reset().ok();
+		write!(stdout, " - ").ok();
+		stdout
+			.set_color(&severity_colour_spec(severity, show_severity_colours))
+			.ok();
+		writeln!(stdout, "{}", self.description()).ok();
+		stdout.reset().ok();
+
+		print_indentation(stdout, indentation);
+		writeln!(stdout, "Category: {}", self.category).ok();
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"sw1": format!("{:02X}", self.sw1),
+			"sw2": format!("{:02X}", self.sw2),
+			"category": self.category.to_string(),
+			"description": self.description(),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{StatusWord, StatusWordCategory};
+	use crate::{error::ParseError, wrong_byte_count};
+
+	// Tests
+	wrong_byte_count!(super::StatusWord, 2);
+
+	#[test]
+	fn parse_success() {
+		let expected = Ok(StatusWord {
+			sw1:      0x90,
+			sw2:      0x00,
+			category: StatusWordCategory::NormalProcessing,
+		});
+		let result = StatusWord::try_from([0x90, 0x00].as_slice());
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_file_not_found() {
+		let expected = Ok((
+			StatusWordCategory::CheckingError,
+			"File not found".to_owned(),
+		));
+		let result = StatusWord::try_from([0x6A, 0x82].as_slice())
+			.map(|status_word| (status_word.category, status_word.description()));
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_authentication_method_blocked() {
+		let expected = Ok((
+			StatusWordCategory::CheckingError,
+			"Authentication method blocked".to_owned(),
+		));
+		let result = StatusWord::try_from([0x69, 0x83].as_slice())
+			.map(|status_word| (status_word.category, status_word.description()));
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_more_data_available() {
+		let expected = Ok((
+			StatusWordCategory::NormalProcessing,
+			"Success, 16 more bytes of data available".to_owned(),
+		));
+		let result = StatusWord::try_from([0x61, 0x10].as_slice())
+			.map(|status_word| (status_word.category, status_word.description()));
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn parse_invalid_sw1() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = StatusWord::try_from([0x12, 0x34].as_slice());
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,291 @@
+//! Track 1 data from a card's magnetic stripe, in format code `B`.
+//!
+//! The track looks like `%B<PAN>^<NAME>^<YYMM><SERVICE CODE><DISCRETIONARY>?`,
+//! optionally followed by the LRC character. The sentinels are often stripped
+//! by card readers, so they're optional here.
+//!
+//! Information for this can be found in [ISO/IEC 7813](https://www.iso.org/standard/43317.html).
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+use crate::{
+	error::ParseError,
+	non_emv::{PrimaryAccountNumber, ServiceCode},
+	util::{parse_str_to_u16, split_track_lrc, TrackLrc},
+	DisplayBreakdown,
+};
+
+// Constants
+const START_SENTINEL: char = '%';
+const END_SENTINEL: char = '?';
+const FORMAT_CODE: char = 'B';
+const FIELD_SEPARATOR: char = '^';
+/// The maximum number of characters in the name field.
+const MAX_NAME_CHARS: usize = 26;
+const EXPIRY_DATE_DIGITS: usize = 4;
+const SERVICE_CODE_DIGITS: usize = 3;
+/// The offset of the 6-bit character set used by Track 1 from ASCII.
+const CHARACTER_SET_OFFSET: u8 = 0x20;
+/// The largest value of the 6-bit character set used by Track 1.
+const MAX_CHARACTER_VALUE: u8 = 0x3F;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Track1Data {
+	pub primary_account_number: PrimaryAccountNumber,
+	/// The cardholder name, with trailing padding removed.
+	pub name:                   String,
+	pub expiry_year:            u8,
+	pub expiry_month:           u8,
+	pub service_code:           ServiceCode,
+	pub discretionary_data:     String,
+	/// The LRC character and whether it matches the track data, if it was
+	/// provided.
+	pub lrc:                    Option<TrackLrc>,
+}
+
+impl TryFrom<&str> for Track1Data {
+	type Error = ParseError;
+
+	fn try_from(track: &str) -> Result<Self, Self::Error> {
+		let track = track.trim();
+
+		let (track_body, lrc) = split_track_lrc(
+			track,
+			START_SENTINEL,
+			END_SENTINEL,
+			CHARACTER_SET_OFFSET,
+			MAX_CHARACTER_VALUE,
+		)?;
+		let track_body = track_body
+			.strip_prefix(START_SENTINEL)
+			.unwrap_or(track_body)
+			.strip_prefix(FORMAT_CODE)
+			.ok_or(ParseError::NonCompliant)?;
+
+		let mut fields = track_body.splitn(3, FIELD_SEPARATOR);
+		let (Some(pan_str), Some(name), Some(remainder)) =
+			(fields.next(), fields.next(), fields.next())
+		else {
+			return Err(ParseError::NonCompliant);
+		};
+		if name.len() > MAX_NAME_CHARS {
+			return Err(ParseError::NonCompliant);
+		}
+
+		// The input isn't guaranteed to be ASCII, so the slice may not fall on a
+		// character boundary
+		if !remainder
+			.get(..(EXPIRY_DATE_DIGITS + SERVICE_CODE_DIGITS))
+			.is_some_and(|digits| digits.chars().all(|c| c.is_ascii_digit()))
+		{
+			return Err(ParseError::NonCompliant);
+		}
+		let (expiry_str, remainder) = remainder.split_at(EXPIRY_DATE_DIGITS);
+		let (service_code_str, discretionary_data) = remainder.split_at(SERVICE_CODE_DIGITS);
+
+		Ok(Self {
+			primary_account_number: PrimaryAccountNumber::try_from(pan_str)?,
+			name: name.trim_end().to_owned(),
+			expiry_year: expiry_str[0..2]
+				.parse()
+				.map_err(|_| ParseError::InvalidNumber)?,
+			expiry_month: expiry_str[2..4]
+				.parse()
+				.map_err(|_| ParseError::InvalidNumber)?,
+			service_code: parse_str_to_u16(service_code_str).and_then(ServiceCode::try_from)?,
+			discretionary_data: discretionary_data.to_owned(),
+			lrc,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for Track1Data {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		let bold_colour_spec = bold_colour_spec();
+
+		// PAN
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		writeln!(stdout, "PAN:").ok();
+		stdout.reset().ok();
+		self.primary_account_number.display_breakdown(
+			stdout,
+			indentation + 1,
+			show_severity_colours,
+		);
+
+		// Name
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		write!(stdout, "Name:").ok();
+		stdout.reset().ok();
+		writeln!(stdout, " {}", self.name).ok();
+
+		// Expiry Date
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		write!(stdout, "Expiry Date (YY/MM):").ok();
+		stdout.reset().ok();
+		write!(
+			stdout,
+			" {:0>2}/{:0>2}",
+			self.expiry_year, self.expiry_month
+		)
+		.ok();
+		if (1..=12).contains(&self.expiry_month) {
+			writeln!(stdout).ok();
+		} else {
+			stdout
+				.set_color(&severity_colour_spec(
+					Severity::Warning,
+					show_severity_colours,
+				))
+				.ok();
+			writeln!(stdout, " (invalid month)").ok();
+			stdout.reset().ok();
+		}
+
+		// Service Code
+		print_indentation(stdout, indentation);
+		stdout.set_color(&bold_colour_spec).ok();
+		writeln!(stdout, "Service Code:").ok();
+		stdout.reset().ok();
+		self.service_code
+			.display_breakdown(stdout, indentation + 1, show_severity_colours);
+
+		// Discretionary Data
+		if !self.discretionary_data.is_empty() {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&bold_colour_spec).ok();
+			write!(stdout, "Discretionary Data:").ok();
+			stdout.reset().ok();
+			writeln!(stdout, " {}", self.discretionary_data).ok();
+		}
+
+		// LRC
+		if let Some((lrc_char, lrc_valid)) = self.lrc {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&bold_colour_spec).ok();
+			write!(stdout, "LRC:").ok();
+			stdout.reset().ok();
+			write!(stdout, " {lrc_char}").ok();
+			if lrc_valid {
+				writeln!(stdout).ok();
+			} else {
+				stdout
+					.set_color(&severity_colour_spec(
+						Severity::Error,
+						show_severity_colours,
+					))
+					.ok();
+				writeln!(stdout, " (doesn't match the track data)").ok();
+				stdout.reset().ok();
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		json!({
+			"primary_account_number": self.primary_account_number.to_json_value(),
+			"name": self.name,
+			"expiry_year": self.expiry_year,
+			"expiry_month": self.expiry_month,
+			"service_code": self.service_code.to_json_value(),
+			"discretionary_data": self.discretionary_data,
+			"lrc": self.lrc.map(|(lrc_char, lrc_valid)| json!({
+				"character": lrc_char.to_string(),
+				"valid": lrc_valid,
+			})),
+		})
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::Track1Data;
+	use crate::{
+		error::ParseError,
+		non_emv::{PrimaryAccountNumber, ServiceCode},
+	};
+
+	// Tests
+	#[test]
+	fn parse_full_track() {
+		let expected = Ok(Track1Data {
+			primary_account_number: PrimaryAccountNumber::try_from("4111111111111111").unwrap(),
+			name:                   "DOE/JOHN".to_owned(),
+			expiry_year:            25,
+			expiry_month:           12,
+			service_code:           ServiceCode::try_from(201).unwrap(),
+			discretionary_data:     "000000000".to_owned(),
+			lrc:                    Some(('8', true)),
+		});
+		let result = Track1Data::try_from("%B4111111111111111^DOE/JOHN^2512201000000000?8");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_without_sentinels() {
+		let expected = Ok(Track1Data {
+			primary_account_number: PrimaryAccountNumber::try_from("4111111111111111").unwrap(),
+			name:                   "DOE/JOHN".to_owned(),
+			expiry_year:            25,
+			expiry_month:           12,
+			service_code:           ServiceCode::try_from(201).unwrap(),
+			discretionary_data:     String::new(),
+			lrc:                    None,
+		});
+		let result = Track1Data::try_from("B4111111111111111^DOE/JOHN    ^2512201");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_incorrect_lrc() {
+		let expected = Ok(Some(('9', false)));
+		let result = Track1Data::try_from("%B4111111111111111^DOE/JOHN^2512201000000000?9")
+			.map(|track| track.lrc);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_wrong_format_code() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = Track1Data::try_from("%A4111111111111111^DOE/JOHN^2512201000000000?");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_missing_field_separator() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = Track1Data::try_from("%B4111111111111111^2512201000000000?");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_multibyte_character_in_expiry_date() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = Track1Data::try_from("%B4111111111111111^DOE/JOHN^251220\u{e9}");
+
+		assert_eq!(expected, result);
+	}
+}
@@ -0,0 +1,174 @@
+//! Track 2 data from a card's magnetic stripe, as it's read from the card.
+//!
+//! The track looks like `;<PAN>=<YYMM><SERVICE CODE><DISCRETIONARY>?`,
+//! optionally followed by the LRC character. The sentinels are often stripped
+//! by card readers, so they're optional here.
+//!
+//! This is the same data as the EMV Track 2 Equivalent Data from tag `0x57`,
+//! but in its ASCII form instead of BCD.
+//!
+//! Information for this can be found in [ISO/IEC 7813](https://www.iso.org/standard/43317.html).
+
+// Uses
+use serde_json::{json, Value as JsonValue};
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+#[cfg(feature = "display")]
+use crate::{
+	emv::Severity,
+	output_colours::{bold_colour_spec, severity_colour_spec},
+	util::print_indentation,
+};
+use crate::{
+	emv::Track2EquivalentData,
+	error::ParseError,
+	util::{split_track_lrc, TrackLrc},
+	DisplayBreakdown,
+};
+
+// Constants
+const START_SENTINEL: char = ';';
+const END_SENTINEL: char = '?';
+const FIELD_SEPARATOR: char = '=';
+/// The offset of the 4-bit character set used by Track 2 from ASCII.
+const CHARACTER_SET_OFFSET: u8 = 0x30;
+/// The largest value of the 4-bit character set used by Track 2.
+const MAX_CHARACTER_VALUE: u8 = 0x0F;
+
+// Struct Implementation
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Track2Data {
+	pub track: Track2EquivalentData,
+	/// The LRC character and whether it matches the track data, if it was
+	/// provided.
+	pub lrc:   Option<TrackLrc>,
+}
+
+impl TryFrom<&str> for Track2Data {
+	type Error = ParseError;
+
+	fn try_from(track: &str) -> Result<Self, Self::Error> {
+		let track = track.trim();
+
+		let (track_body, lrc) = split_track_lrc(
+			track,
+			START_SENTINEL,
+			END_SENTINEL,
+			CHARACTER_SET_OFFSET,
+			MAX_CHARACTER_VALUE,
+		)?;
+		let track_body = track_body
+			.strip_prefix(START_SENTINEL)
+			.unwrap_or(track_body);
+
+		Ok(Self {
+			track: Track2EquivalentData::from_track_str(track_body, FIELD_SEPARATOR)?,
+			lrc,
+		})
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl DisplayBreakdown for Track2Data {
+	#[cfg(feature = "display")]
+	fn display_breakdown(
+		&self,
+		stdout: &mut dyn WriteColor,
+		indentation: u8,
+		show_severity_colours: bool,
+	) {
+		self.track
+			.display_breakdown(stdout, indentation, show_severity_colours);
+
+		// LRC
+		if let Some((lrc_char, lrc_valid)) = self.lrc {
+			print_indentation(stdout, indentation);
+			stdout.set_color(&bold_colour_spec()).ok();
+			write!(stdout, "LRC:").ok();
+			stdout.reset().ok();
+			write!(stdout, " {lrc_char}").ok();
+			if lrc_valid {
+				writeln!(stdout).ok();
+			} else {
+				stdout
+					.set_color(&severity_colour_spec(
+						Severity::Error,
+						show_severity_colours,
+					))
+					.ok();
+				writeln!(stdout, " (doesn't match the track data)").ok();
+				stdout.reset().ok();
+			}
+		}
+	}
+
+	fn to_json_value(&self) -> JsonValue {
+		let mut value = self.track.to_json_value();
+		value["lrc"] = json!(self.lrc.map(|(lrc_char, lrc_valid)| json!({
+			"character": lrc_char.to_string(),
+			"valid": lrc_valid,
+		})));
+		value
+	}
+}
+
+// Unit Tests
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::Track2Data;
+	use crate::{
+		emv::Track2EquivalentData,
+		error::ParseError,
+		non_emv::{PrimaryAccountNumber, ServiceCode},
+	};
+
+	// Tests
+	#[test]
+	fn parse_full_track() {
+		let expected = Ok(Track2Data {
+			track: Track2EquivalentData {
+				primary_account_number: PrimaryAccountNumber::try_from("4111111111111111").unwrap(),
+				expiry_year:            25,
+				expiry_month:           12,
+				service_code:           ServiceCode::try_from(201).unwrap(),
+				discretionary_data:     "0000000".to_owned(),
+			},
+			lrc:   Some((';', true)),
+		});
+		let result = Track2Data::try_from(";4111111111111111=25122010000000?;");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_without_sentinels() {
+		let expected = Ok(None);
+		let result = Track2Data::try_from("4111111111111111=2512201").map(|track| track.lrc);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_lrc_without_start_sentinel() {
+		let expected = Ok(None);
+		let result = Track2Data::try_from("4111111111111111=2512201?;").map(|track| track.lrc);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_incorrect_lrc() {
+		let expected = Ok(Some(('7', false)));
+		let result =
+			Track2Data::try_from(";4111111111111111=25122010000000?7").map(|track| track.lrc);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_bcd_separator() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = Track2Data::try_from(";4111111111111111D25122010000000?");
+
+		assert_eq!(expected, result);
+	}
+}
@@ -2,7 +2,9 @@
 //! again elsewhere.
 
 // Uses
-use termcolor::{Color, ColorSpec};
+use termcolor::{Color, ColorSpec, WriteColor};
+
+use crate::{emv::Severity, util::print_indentation};
 
 /// Bold, without any specified colour.
 #[cfg(not(tarpaulin_include))]
@@ -19,3 +21,95 @@ pub fn header_colour_spec() -> ColorSpec {
 	c.set_bold(true).set_fg(Some(Color::Cyan));
 	c
 }
+
+/// The colour for a value of a given severity. If `show_severity_colours` is
+/// `false`, no colour is specified.
+#[cfg(not(tarpaulin_include))]
+pub fn severity_colour_spec(severity: Severity, show_severity_colours: bool) -> ColorSpec {
+	let mut c = ColorSpec::new();
+	if show_severity_colours {
+		c.set_fg(match severity {
+			Severity::Normal => None,
+			Severity::Warning => Some(Color::Yellow),
+			Severity::Error => Some(Color::Red),
+		});
+	}
+	c
+}
+
+/// Prints a legend of the colour used for each severity, with each entry
+/// written in its own colour.
+#[cfg(not(tarpaulin_include))]
+pub fn print_severity_legend(
+	stdout: &mut dyn WriteColor,
+	indentation: u8,
+	show_severity_colours: bool,
+) {
+	print_indentation(stdout, indentation);
+	stdout.set_color(&header_colour_spec()).ok();
+	writeln!(stdout, "Severity Colours:").ok();
+	stdout.reset().ok();
+	for (severity, colour_name) in [
+		(Severity::Normal, "no colour"),
+		(Severity::Warning, "yellow"),
+		(Severity::Error, "red"),
+	] {
+		print_indentation(stdout, indentation + 1);
+		stdout
+			.set_color(&severity_colour_spec(severity, show_severity_colours))
+			.ok();
+		writeln!(stdout, "{severity} ({colour_name})").ok();
+		stdout.reset().ok();
+	}
+}
+
+/// The colour for the bytes that make up a tag ID, when highlighting the parts
+/// of a tag.
+#[cfg(not(tarpaulin_include))]
+pub fn tag_bytes_colour_spec() -> ColorSpec {
+	let mut c = ColorSpec::new();
+	c.set_bold(true).set_fg(Some(Color::Cyan));
+	c
+}
+
+/// The colour for the bytes that make up a tag's length, when highlighting the
+/// parts of a tag.
+#[cfg(not(tarpaulin_include))]
+pub fn length_bytes_colour_spec() -> ColorSpec {
+	let mut c = ColorSpec::new();
+	c.set_fg(Some(Color::Magenta));
+	c
+}
+
+/// The colour for a tag that was added in a TLV diff. If `show_colours` is
+/// `false`, no colour is specified.
+#[cfg(not(tarpaulin_include))]
+pub fn added_colour_spec(show_colours: bool) -> ColorSpec {
+	let mut c = ColorSpec::new();
+	if show_colours {
+		c.set_fg(Some(Color::Green));
+	}
+	c
+}
+
+/// The colour for a tag that was removed in a TLV diff. If `show_colours` is
+/// `false`, no colour is specified.
+#[cfg(not(tarpaulin_include))]
+pub fn removed_colour_spec(show_colours: bool) -> ColorSpec {
+	let mut c = ColorSpec::new();
+	if show_colours {
+		c.set_fg(Some(Color::Red));
+	}
+	c
+}
+
+/// The colour for a tag that was changed in a TLV diff. If `show_colours` is
+/// `false`, no colour is specified.
+#[cfg(not(tarpaulin_include))]
+pub fn changed_colour_spec(show_colours: bool) -> ColorSpec {
+	let mut c = ColorSpec::new();
+	if show_colours {
+		c.set_fg(Some(Color::Yellow));
+	}
+	c
+}
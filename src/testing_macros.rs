@@ -169,3 +169,21 @@ macro_rules! enum_byte_slice_result_matches_true_value_result {
 		}
 	};
 }
+
+#[macro_export]
+macro_rules! display_breakdown_matches {
+	($test_name:ident, $value:expr, $expected:expr) => {
+		#[doc = concat!("Ensures the breakdown of `", stringify!($value), "` is displayed as expected, with colours disabled.")]
+		#[cfg(feature = "display")]
+		#[test]
+		fn $test_name() {
+			let mut buffer = termcolor::Buffer::no_color();
+			$crate::DisplayBreakdown::display_breakdown(&$value, &mut buffer, 0, false);
+
+			let expected = $expected;
+			let result = String::from_utf8_lossy(buffer.as_slice());
+
+			assert_eq!(expected, result);
+		}
+	};
+}
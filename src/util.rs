@@ -1,22 +1,61 @@
 //! Utility functions for internal use by other components of the crate.
 
 // Uses
-use std::iter::successors;
+use std::{iter::successors, sync::OnceLock};
 
-use crate::error::ParseError;
+#[cfg(feature = "display")]
+use termcolor::WriteColor;
+
+use crate::{error::ParseError, BITS_PER_BYTE};
 
 /// Parses a string into a [`u16`].
 pub fn parse_str_to_u16(s: &str) -> Result<u16, ParseError> {
-	s.trim().parse().map_err(|_| ParseError::InvalidNumber)
+	let s = s.trim();
+	if s.is_empty() {
+		return Err(ParseError::EmptyInput);
+	}
+
+	s.parse().map_err(|_| ParseError::InvalidNumber)
 }
 
 /// Parses a hex string into a vector of bytes.
 ///
+/// This is lenient, and any non-hex characters are ignored. This means all of
+/// the following forms are accepted, as they're commonly found in code and
+/// specification documents:
+/// - `9F02 06`
+/// - `0x9F, 0x02, 0x06` (`0x` and `0X` prefixes are stripped before anything
+///   else, so the `0` isn't read as part of the data)
+/// - `h'9F0206'`
+///
 /// Original function written by Jake Goulding.
 ///
 /// <https://codereview.stackexchange.com/a/201699>
 pub fn parse_hex_str(hex_asm: &str) -> Vec<u8> {
-	let mut hex_bytes = hex_asm
+	hex_str_nibbles(hex_asm)
+		.chunks_exact(2)
+		.map(|pair| pair[0] << 4 | pair[1])
+		.collect()
+}
+/// Does the exact same thing as [`parse_hex_str`], but it throws an error if
+/// there's an odd number of hex digits, instead of dropping the last one.
+///
+/// This catches mistyped bytes that would otherwise quietly change the data.
+pub fn parse_hex_str_exact(hex_asm: &str) -> Result<Vec<u8>, ParseError> {
+	let nibbles = hex_str_nibbles(hex_asm);
+	let pairs = nibbles.chunks_exact(2);
+	if !pairs.remainder().is_empty() {
+		return Err(ParseError::InvalidBytes);
+	}
+
+	Ok(pairs.map(|pair| pair[0] << 4 | pair[1]).collect())
+}
+/// Extracts the value of every hex digit in the string, for
+/// [`parse_hex_str`] and [`parse_hex_str_exact`].
+fn hex_str_nibbles(hex_asm: &str) -> Vec<u8> {
+	hex_asm
+		.replace("0x", "")
+		.replace("0X", "")
 		.as_bytes()
 		.iter()
 		.filter_map(|b| match b {
@@ -25,13 +64,7 @@ pub fn parse_hex_str(hex_asm: &str) -> Vec<u8> {
 			b'A'..=b'F' => Some(b - b'A' + 10),
 			_ => None,
 		})
-		.fuse();
-
-	let mut bytes = Vec::new();
-	while let (Some(h), Some(l)) = (hex_bytes.next(), hex_bytes.next()) {
-		bytes.push(h << 4 | l);
-	}
-	bytes
+		.collect()
 }
 /// Does the exact same thing as [`parse_hex_str`], but it throws an error if
 /// there are any non-hex ASCII characters in the string.
@@ -45,38 +78,128 @@ pub fn parse_hex_str_strict(hex_asm: &str) -> Result<Vec<u8>, ParseError> {
 	}
 }
 
+/// Decodes packed BCD into its decimal digits.
+///
+/// A single `F` nibble is allowed as padding at the very end, like in a PAN
+/// with an odd number of digits, and is left out of the result.
+pub fn bcd_to_decimal_string(bytes: &[u8]) -> Result<String, ParseError> {
+	decode_bcd(bytes, None)
+}
+/// The same as [`bcd_to_decimal_string`], but `D` nibbles are decoded as
+/// `separator`, like the field separator in Track 2 data.
+pub fn bcd_with_separator_to_string(bytes: &[u8], separator: char) -> Result<String, ParseError> {
+	decode_bcd(bytes, Some(separator))
+}
+/// The shared implementation of the BCD decoders.
+fn decode_bcd(bytes: &[u8], separator: Option<char>) -> Result<String, ParseError> {
+	const SEPARATOR_NIBBLE: u8 = 0xD;
+	const PADDING_NIBBLE: u8 = 0xF;
+
+	let mut digits = String::with_capacity(bytes.len() * 2);
+	let nibble_count = bytes.len() * 2;
+	for (i, nibble) in bytes
+		.iter()
+		.flat_map(|byte| [byte >> 4, byte & 0b0000_1111])
+		.enumerate()
+	{
+		match (nibble, separator) {
+			(0..=9, _) => digits.push((b'0' + nibble) as char),
+			(SEPARATOR_NIBBLE, Some(separator)) => digits.push(separator),
+			// Padding is only allowed as the very last nibble
+			(PADDING_NIBBLE, _) if i == nibble_count - 1 => {}
+			_ => return Err(ParseError::InvalidBytes),
+		}
+	}
+
+	Ok(digits)
+}
+/// Checks whether the bytes are valid packed BCD, allowing for the same
+/// padding as [`bcd_to_decimal_string`].
+pub fn is_valid_bcd(bytes: &[u8]) -> bool {
+	bcd_to_decimal_string(bytes).is_ok()
+}
+/// Decodes base64 text into bytes, for data that was captured base64-encoded.
+///
+/// Both the standard and URL-safe alphabets are accepted, the padding is
+/// optional, and whitespace is ignored.
+pub fn parse_base64_str(base64: &str) -> Result<Vec<u8>, ParseError> {
+	const BITS_PER_CHAR: u8 = 6;
+
+	let mut bytes = Vec::with_capacity(base64.len() * 3 / 4);
+	let mut buffer = 0u32;
+	let mut buffered_bits = 0u8;
+	let mut padding_found = false;
+	for c in base64.bytes().filter(|c| !c.is_ascii_whitespace()) {
+		let value = match c {
+			b'A'..=b'Z' => c - b'A',
+			b'a'..=b'z' => c - b'a' + 26,
+			b'0'..=b'9' => c - b'0' + 52,
+			b'+' | b'-' => 62,
+			b'/' | b'_' => 63,
+			b'=' => {
+				padding_found = true;
+				continue;
+			}
+			_ => return Err(ParseError::InvalidBytes),
+		};
+		// Padding is only allowed at the very end
+		if padding_found {
+			return Err(ParseError::InvalidBytes);
+		}
+
+		buffer = buffer << BITS_PER_CHAR | u32::from(value);
+		buffered_bits += BITS_PER_CHAR;
+		if buffered_bits >= BITS_PER_BYTE {
+			buffered_bits -= BITS_PER_BYTE;
+			bytes.push((buffer >> buffered_bits) as u8);
+			buffer &= (1 << buffered_bits) - 1;
+		}
+	}
+	// A single character on its own can't make up a byte, so the data must have
+	// been cut off
+	if buffered_bits >= BITS_PER_CHAR {
+		return Err(ParseError::InvalidBytes);
+	}
+
+	Ok(bytes)
+}
+
 /// The number of bytes per 32 bits.
 pub const BYTES_PER_32_BITS: usize = 4;
 /// Converts a raw byte slice to [`u32`].
 ///
-/// Panics if the slice is too long.
-pub fn byte_slice_to_u32(bytes: &[u8]) -> u32 {
+/// Returns [`ParseError::Unsupported`] if the slice is too long to fit.
+pub fn byte_slice_to_u32(bytes: &[u8]) -> Result<u32, ParseError> {
 	let provided_bytes_length = bytes.len();
-	assert!(provided_bytes_length <= BYTES_PER_32_BITS);
+	if provided_bytes_length > BYTES_PER_32_BITS {
+		return Err(ParseError::Unsupported);
+	}
 
 	let mut all_bytes = [0u8; BYTES_PER_32_BITS];
 	for i in 0..provided_bytes_length {
 		all_bytes[(BYTES_PER_32_BITS - provided_bytes_length) + i] = bytes[i];
 	}
 
-	u32::from_be_bytes(all_bytes)
+	Ok(u32::from_be_bytes(all_bytes))
 }
 
 /// The number of bytes per 64 bits.
 pub const BYTES_PER_64_BITS: usize = 8;
 /// Converts a raw byte slice to [`u64`].
 ///
-/// Panics if the slice is too long.
-pub fn byte_slice_to_u64(bytes: &[u8]) -> u64 {
+/// Returns [`ParseError::Unsupported`] if the slice is too long to fit.
+pub fn byte_slice_to_u64(bytes: &[u8]) -> Result<u64, ParseError> {
 	let provided_bytes_length = bytes.len();
-	assert!(provided_bytes_length <= BYTES_PER_64_BITS);
+	if provided_bytes_length > BYTES_PER_64_BITS {
+		return Err(ParseError::Unsupported);
+	}
 
 	let mut all_bytes = [0u8; BYTES_PER_64_BITS];
 	for i in 0..provided_bytes_length {
 		all_bytes[(BYTES_PER_64_BITS - provided_bytes_length) + i] = bytes[i];
 	}
 
-	u64::from_be_bytes(all_bytes)
+	Ok(u64::from_be_bytes(all_bytes))
 }
 
 /// Converts bytes to a string.
@@ -97,6 +220,24 @@ pub fn bytes_to_str(bytes: &[u8]) -> String {
 	result
 }
 
+/// Converts bytes to a string of printable ASCII, replacing any other bytes
+/// with `replacement`.
+///
+/// The offsets of the replaced bytes are returned alongside the string.
+pub fn bytes_to_printable_ascii(bytes: &[u8], replacement: char) -> (String, Vec<usize>) {
+	let mut result = String::with_capacity(bytes.len());
+	let mut non_printable_offsets = Vec::new();
+	for (offset, &byte) in bytes.iter().enumerate() {
+		if matches!(byte, 0x20..=0x7E) {
+			result.push(byte as char);
+		} else {
+			result.push(replacement);
+			non_printable_offsets.push(offset);
+		}
+	}
+	(result, non_printable_offsets)
+}
+
 /// Trims the leading `0x00` bytes, never removing the last byte. (even if it's
 /// also `0x00`)
 pub fn trim_leading_0_bytes(bytes: &mut Vec<u8>) {
@@ -117,59 +258,203 @@ pub fn num_dec_digits(value: u32) -> usize {
 	successors(Some(value), |&n| (n >= 10).then_some(n / 10)).count()
 }
 
+/// Computes the Luhn (mod 10) check digit for a string of decimal digits that
+/// doesn't yet have one.
+pub fn luhn_check_digit(payload: &str) -> Result<u8, ParseError> {
+	if payload.is_empty() {
+		return Err(ParseError::InvalidNumber);
+	}
+
+	let mut sum = 0u32;
+	for (i, c) in payload.chars().rev().enumerate() {
+		let mut digit = c.to_digit(10).ok_or(ParseError::InvalidNumber)?;
+		// The check digit will be appended to the right, so the rightmost payload
+		// digit is the first one to be doubled
+		if i % 2 == 0 {
+			digit *= 2;
+			if digit > 9 {
+				digit -= 9;
+			}
+		}
+		sum += digit;
+	}
+
+	Ok(((10 - (sum % 10)) % 10) as u8)
+}
+
+/// Checks whether a string of decimal digits passes the Luhn (mod 10) check,
+/// with the check digit being the last digit.
+pub fn luhn_is_valid(digits: &str) -> Result<bool, ParseError> {
+	let Some(check_digit) = digits.chars().last() else {
+		return Err(ParseError::InvalidNumber);
+	};
+	let check_digit = check_digit.to_digit(10).ok_or(ParseError::InvalidNumber)?;
+
+	Ok(luhn_check_digit(&digits[..digits.len() - 1])? == check_digit as u8)
+}
+
+/// Calculates the LRC character of magnetic stripe track data, from the start
+/// sentinel up to and including the end sentinel.
+///
+/// Each character is reduced to its value in the track's character set, which
+/// starts at `character_set_offset` in ASCII, and the values are XOR'd
+/// together. Returns `None` if a character isn't in the character set.
+pub fn calculate_track_lrc(
+	track: &str,
+	character_set_offset: u8,
+	max_character_value: u8,
+) -> Option<char> {
+	track
+		.chars()
+		.try_fold(0u8, |lrc, c| {
+			u8::try_from(c)
+				.ok()
+				.and_then(|byte| byte.checked_sub(character_set_offset))
+				.filter(|value| *value <= max_character_value)
+				.map(|value| lrc ^ value)
+		})
+		.map(|lrc| char::from(lrc + character_set_offset))
+}
+/// The LRC character of magnetic stripe track data, along with whether it
+/// matches the track data.
+pub type TrackLrc = (char, bool);
+/// Splits magnetic stripe track data at its end sentinel, returning the data
+/// before it along with the LRC character after it (if there is one) and
+/// whether the LRC matches the track data.
+///
+/// The LRC covers both sentinels, so it's only checked if the track starts
+/// with `start_sentinel`. Otherwise, it's left out.
+pub fn split_track_lrc(
+	track: &str,
+	start_sentinel: char,
+	end_sentinel: char,
+	character_set_offset: u8,
+	max_character_value: u8,
+) -> Result<(&str, Option<TrackLrc>), ParseError> {
+	let Some((body, lrc_str)) = track.split_once(end_sentinel) else {
+		return Ok((track, None));
+	};
+
+	let mut lrc_chars = lrc_str.chars();
+	let lrc = match (lrc_chars.next(), lrc_chars.next()) {
+		(None, _) => None,
+		(Some(lrc_char), None) => track.starts_with(start_sentinel).then(|| {
+			let covered = &track[..(body.len() + end_sentinel.len_utf8())];
+			(
+				lrc_char,
+				calculate_track_lrc(covered, character_set_offset, max_character_value)
+					== Some(lrc_char),
+			)
+		}),
+		// Only the LRC can come after the end sentinel
+		_ => return Err(ParseError::NonCompliant),
+	};
+
+	Ok((body, lrc))
+}
+
+/// The text printed for each level of indentation, if it's been changed from
+/// the default.
+static INDENTATION_UNIT: OnceLock<String> = OnceLock::new();
+/// The text printed for each level of indentation by default.
+pub const DEFAULT_INDENTATION_UNIT: &str = "\t";
+
+/// Sets the text printed for each level of indentation by
+/// [`print_indentation`], like a number of spaces.
+///
+/// This can only be set once, and should be done at startup before anything is
+/// displayed. Returns `false` if it was already set.
+pub fn set_indentation_unit(unit: String) -> bool {
+	INDENTATION_UNIT.set(unit).is_ok()
+}
+
 /// Prints the specified amount of indentation on the current line.
+#[cfg(feature = "display")]
 #[cfg(not(tarpaulin_include))]
-pub fn print_indentation(indentation: u8) {
+pub fn print_indentation(stdout: &mut dyn WriteColor, indentation: u8) {
+	let unit = INDENTATION_UNIT
+		.get()
+		.map_or(DEFAULT_INDENTATION_UNIT, String::as_str);
 	for _ in 0..indentation {
-		print!("\t");
+		write!(stdout, "{unit}").ok();
 	}
 }
 
 /// Pretty-prints bytes as hex.
+#[cfg(feature = "display")]
 #[cfg(not(tarpaulin_include))]
-pub fn print_bytes(bytes: &[u8], bytes_per_line: usize, indentation: u8) {
+pub fn print_bytes(
+	stdout: &mut dyn WriteColor,
+	bytes: &[u8],
+	bytes_per_line: usize,
+	indentation: u8,
+) {
 	for line in bytes.chunks(bytes_per_line) {
 		// Print the hex
-		print_indentation(indentation);
+		print_indentation(stdout, indentation);
 		let mut first = true;
 		for byte in line {
 			if first {
 				first = false;
 			} else {
-				print!(" ");
+				write!(stdout, " ").ok();
 			}
-			print!("{byte:0>2X}");
+			write!(stdout, "{byte:0>2X}").ok();
 		}
 
 		// End the line
-		println!();
+		writeln!(stdout).ok();
 	}
 }
 
 /// Pretty-prints bytes as hex with an ASCII readout next to the hex on each
 /// line.
+///
+/// If `show_offsets` is `true`, each line starts with the offset of its first
+/// byte, like in a hex editor.
+#[cfg(feature = "display")]
 #[cfg(not(tarpaulin_include))]
-pub fn print_bytes_pretty(bytes: &[u8], bytes_per_line: usize, indentation: u8) {
-	for line in bytes.chunks(bytes_per_line) {
+pub fn print_bytes_pretty(
+	stdout: &mut dyn WriteColor,
+	bytes: &[u8],
+	bytes_per_line: usize,
+	indentation: u8,
+	show_offsets: bool,
+) {
+	// The offsets are padded to the same width, which is at least 4 digits
+	let offset_digits = format!("{:X}", bytes.len().saturating_sub(1)).len().max(4);
+
+	for (line_index, line) in bytes.chunks(bytes_per_line).enumerate() {
+		// Print the offset
+		print_indentation(stdout, indentation);
+		if show_offsets {
+			write!(
+				stdout,
+				"{:0>width$X}  ",
+				line_index * bytes_per_line,
+				width = offset_digits
+			)
+			.ok();
+		}
+
 		// Print the hex
-		print_indentation(indentation);
 		let mut first = true;
 		for byte in line {
 			if first {
 				first = false;
 			} else {
-				print!(" ");
+				write!(stdout, " ").ok();
 			}
-			print!("{byte:0>2X}");
+			write!(stdout, "{byte:0>2X}").ok();
 		}
 
 		// Add padding to the end if this is the last line
 		for _ in 0..(bytes_per_line - line.len()) {
-			print!("   ");
+			write!(stdout, "   ").ok();
 		}
 
 		// Add padding between the hex and ASCII sections
-		print!("  ");
+		write!(stdout, "  ").ok();
 
 		// Print the ASCII readout, replacing unprintable characters
 		for &byte in line {
@@ -177,11 +462,11 @@ pub fn print_bytes_pretty(bytes: &[u8], bytes_per_line: usize, indentation: u8)
 				0x20..=0x7E => byte as char,
 				_ => '.',
 			};
-			print!("{printable_char}");
+			write!(stdout, "{printable_char}").ok();
 		}
 
 		// End the line
-		println!();
+		writeln!(stdout).ok();
 	}
 }
 
@@ -189,10 +474,11 @@ pub fn print_bytes_pretty(bytes: &[u8], bytes_per_line: usize, indentation: u8)
 ///
 /// This does not add a line ending afterwards, and all bytes are printed on one
 /// line.
+#[cfg(feature = "display")]
 #[cfg(not(tarpaulin_include))]
-pub fn print_bytes_small(bytes: &[u8]) {
+pub fn print_bytes_small(stdout: &mut dyn WriteColor, bytes: &[u8]) {
 	for byte in bytes {
-		print!("{byte:0>2X}");
+		write!(stdout, "{byte:0>2X}").ok();
 	}
 }
 
@@ -201,13 +487,23 @@ pub fn print_bytes_small(bytes: &[u8]) {
 mod tests {
 	// Uses
 	use super::{
+		bcd_to_decimal_string,
+		bcd_with_separator_to_string,
 		byte_slice_to_u32,
 		byte_slice_to_u64,
+		bytes_to_printable_ascii,
 		bytes_to_str,
+		calculate_track_lrc,
+		is_valid_bcd,
+		luhn_check_digit,
+		luhn_is_valid,
 		num_dec_digits,
+		parse_base64_str,
 		parse_hex_str,
+		parse_hex_str_exact,
 		parse_hex_str_strict,
 		parse_str_to_u16,
+		split_track_lrc,
 		trim_leading_0_bytes,
 	};
 	use crate::error::ParseError;
@@ -227,6 +523,13 @@ mod tests {
 
 		assert_eq!(expected, result);
 	}
+	#[test]
+	fn parse_str_to_u16_empty() {
+		let expected = Err(ParseError::EmptyInput);
+		let result = parse_str_to_u16("  ");
+
+		assert_eq!(expected, result);
+	}
 
 	#[test]
 	fn parse_hex_str_uppercase_no_spaces() {
@@ -264,6 +567,34 @@ mod tests {
 		assert_eq!(expected, result);
 	}
 	#[test]
+	fn parse_hex_str_odd_length() {
+		let expected = vec![0xDEu8, 0xAD];
+		let result = parse_hex_str("DEADB");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_hex_str_exact_even_length() {
+		let expected = Ok(vec![0x9Fu8, 0x02, 0x06]);
+		let result = parse_hex_str_exact("0x9F, 0x02, 0x06");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_hex_str_exact_odd_length() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = parse_hex_str_exact("9F 02 6");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_hex_str_exact_single_digit() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = parse_hex_str_exact("A");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
 	fn parse_hex_str_mixed_non_ascii() {
 		let expected = vec![0x0Au8, 0x6E, 0x42];
 		let result = parse_hex_str("  . 0a 6E  \u{2764}\u{fe0f} 42    t ");
@@ -271,6 +602,34 @@ mod tests {
 		assert_eq!(expected, result);
 	}
 
+	#[test]
+	fn parse_hex_str_prefixes_and_commas() {
+		let expected = vec![0x9Fu8, 0x02, 0x06];
+		let result = parse_hex_str("0x9F, 0X02, 0x06");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_hex_str_prefixes_without_separators() {
+		let expected = vec![0x9Fu8, 0x02, 0x06];
+		let result = parse_hex_str("0x9F0x020x06");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_hex_str_quoted_wrapper() {
+		let expected = vec![0x9Fu8, 0x02, 0x06];
+		let result = parse_hex_str("h'9F0206'");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_hex_str_strict_rejects_prefixes() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = parse_hex_str_strict("0x9F02");
+
+		assert_eq!(expected, result);
+	}
 	#[test]
 	fn parse_hex_str_strict_uppercase_no_spaces() {
 		let expected = Ok(vec![0xDEu8, 0xAD, 0xBE, 0xEF]);
@@ -309,57 +668,62 @@ mod tests {
 
 	#[test]
 	fn byte_slice_to_u32_single_byte() {
-		let expected = 0x25u32;
+		let expected = Ok(0x25u32);
 		let result = byte_slice_to_u32([0x25u8].as_slice());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn byte_slice_to_u32_multi_byte_not_full() {
-		let expected = 0x0012_3456_u32;
+		let expected = Ok(0x0012_3456_u32);
 		let result = byte_slice_to_u32([0x12u8, 0x34, 0x56].as_slice());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn byte_slice_to_u32_multi_byte_full() {
-		let expected = 0x1234_5678_u32;
+		let expected = Ok(0x1234_5678_u32);
 		let result = byte_slice_to_u32([0x12u8, 0x34, 0x56, 0x78].as_slice());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
-	#[should_panic]
 	fn byte_slice_to_u32_too_many_bytes() {
-		byte_slice_to_u32([0x12u8, 0x34, 0x56, 0x78, 0x90].as_slice());
+		let expected = Err(ParseError::Unsupported);
+		let result = byte_slice_to_u32([0x12u8, 0x34, 0x56, 0x78, 0x90].as_slice());
+
+		assert_eq!(expected, result);
 	}
 
 	#[test]
 	fn byte_slice_to_u64_single_byte() {
-		let expected = 0x25u64;
+		let expected = Ok(0x25u64);
 		let result = byte_slice_to_u64([0x25u8].as_slice());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn byte_slice_to_u64_multi_byte_not_full() {
-		let expected = 0x0012_3456_u64;
+		let expected = Ok(0x0012_3456_u64);
 		let result = byte_slice_to_u64([0x12u8, 0x34, 0x56].as_slice());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
 	fn byte_slice_to_u64_multi_byte_full() {
-		let expected = 0x1234_5678_9012_3456_u64;
+		let expected = Ok(0x1234_5678_9012_3456_u64);
 		let result =
 			byte_slice_to_u64([0x12u8, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56].as_slice());
 
 		assert_eq!(expected, result);
 	}
 	#[test]
-	#[should_panic]
 	fn byte_slice_to_u64_too_many_bytes() {
-		byte_slice_to_u64([0x12u8, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78].as_slice());
+		let expected = Err(ParseError::Unsupported);
+		let result =
+			byte_slice_to_u64([0x12u8, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78].as_slice());
+
+		assert_eq!(expected, result);
 	}
 
 	#[test]
@@ -384,6 +748,13 @@ mod tests {
 		assert_eq!(expected, result);
 	}
 
+	#[test]
+	fn bytes_to_printable_ascii_replaces_non_printable() {
+		let expected = ("AB.C.".to_owned(), vec![2, 4]);
+		let result = bytes_to_printable_ascii(b"AB\x07C\xFF".as_slice(), '.');
+
+		assert_eq!(expected, result);
+	}
 	#[test]
 	fn trim_leading_0_bytes_normal_case() {
 		let expected = [0x8A].to_vec();
@@ -432,4 +803,194 @@ mod tests {
 
 		assert_eq!(expected, result);
 	}
+
+	#[test]
+	fn luhn_check_digit_valid() {
+		let expected = Ok(1);
+		let result = luhn_check_digit("411111111111111");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_check_digit_odd_length() {
+		let expected = Ok(4);
+		let result = luhn_check_digit("601100099013942");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_check_digit_non_digit() {
+		let expected = Err(ParseError::InvalidNumber);
+		let result = luhn_check_digit("41111111111111a");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_check_digit_empty() {
+		let expected = Err(ParseError::InvalidNumber);
+		let result = luhn_check_digit("");
+
+		assert_eq!(expected, result);
+	}
+
+	#[test]
+	fn luhn_is_valid_valid() {
+		let expected = Ok(true);
+		let result = luhn_is_valid("374245455400126");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_is_valid_invalid() {
+		let expected = Ok(false);
+		let result = luhn_is_valid("4111111111111112");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_is_valid_non_digit() {
+		let expected = Err(ParseError::InvalidNumber);
+		let result = luhn_is_valid("4111-1111");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn luhn_is_valid_too_short() {
+		let expected = Err(ParseError::InvalidNumber);
+		let result = luhn_is_valid("4");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn bcd_to_decimal_string_valid() {
+		let expected = Ok("12".to_owned());
+		let result = bcd_to_decimal_string(&[0x12]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn bcd_to_decimal_string_trailing_padding() {
+		let expected = Ok("123".to_owned());
+		let result = bcd_to_decimal_string(&[0x12, 0x3F]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn bcd_to_decimal_string_hex_digit() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = bcd_to_decimal_string(&[0x9A]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn bcd_to_decimal_string_padding_not_at_end() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = bcd_to_decimal_string(&[0xF1, 0x23]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn bcd_to_decimal_string_empty() {
+		let expected = Ok(String::new());
+		let result = bcd_to_decimal_string(&[]);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn bcd_with_separator_to_string_valid() {
+		let expected = Ok("12=34".to_owned());
+		let result = bcd_with_separator_to_string(&[0x12, 0xD3, 0x4F], '=');
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn is_valid_bcd_cases() {
+		let expected = vec![true, true, false, false];
+		let result = vec![
+			is_valid_bcd(&[0x12]),
+			is_valid_bcd(&[0x12, 0x3F]),
+			is_valid_bcd(&[0x9A]),
+			is_valid_bcd(&[0xFF]),
+		];
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_base64_str_padded() {
+		let expected = Ok(vec![0x9F, 0x36, 0x02, 0x00, 0x01]);
+		let result = parse_base64_str("nzYCAAE=");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_base64_str_unpadded() {
+		let expected = Ok(vec![0x9F, 0x36]);
+		let result = parse_base64_str("nzY");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_base64_str_url_safe() {
+		let expected = Ok(vec![0xFB, 0xFF]);
+		let result = parse_base64_str("-_8=");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_base64_str_invalid_character() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = parse_base64_str("nzY*");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn parse_base64_str_cut_off() {
+		let expected = Err(ParseError::InvalidBytes);
+		let result = parse_base64_str("nzYCA");
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn calculate_track_lrc_track_1() {
+		let expected = Some('8');
+		let result =
+			calculate_track_lrc("%B4111111111111111^DOE/JOHN^2512201000000000?", 0x20, 0x3F);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn calculate_track_lrc_track_2() {
+		let expected = Some(';');
+		let result = calculate_track_lrc(";4111111111111111=25122010000000?", 0x30, 0x0F);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn calculate_track_lrc_outside_character_set() {
+		let expected = None;
+		let result = calculate_track_lrc("%Bdoe?", 0x20, 0x3F);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn split_track_lrc_valid() {
+		let expected = Ok((";4111111111111111=25122010000000", Some((';', true))));
+		let result = split_track_lrc(";4111111111111111=25122010000000?;", ';', '?', 0x30, 0x0F);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn split_track_lrc_without_start_sentinel() {
+		let expected = Ok(("1234", None));
+		let result = split_track_lrc("1234?5", ';', '?', 0x30, 0x0F);
+
+		assert_eq!(expected, result);
+	}
+	#[test]
+	fn split_track_lrc_too_long() {
+		let expected = Err(ParseError::NonCompliant);
+		let result = split_track_lrc(";1234?56", ';', '?', 0x30, 0x0F);
+
+		assert_eq!(expected, result);
+	}
 }
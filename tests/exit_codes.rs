@@ -0,0 +1,148 @@
+//! Tests for the exit codes of the binary, which scripts rely on.
+
+// The binary is only built with this feature
+#![cfg(feature = "display")]
+
+// Uses
+use std::{env::temp_dir, fs::write, process::Command};
+
+// Tests
+/// Runs the binary with the provided arguments, returning its exit code.
+fn exit_code(args: &[&str]) -> Option<i32> {
+	Command::new(env!("CARGO_BIN_EXE_pemv"))
+		.args(args)
+		.output()
+		.expect("the binary should be able to run")
+		.status
+		.code()
+}
+
+#[test]
+fn success() {
+	let expected = Some(0);
+	let result = exit_code(&["--ber-tlv", "9F36020001"]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn parse_failure() {
+	let expected = Some(1);
+	let result = exit_code(&["--ber-tlv", "9F3602"]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn no_data_after_cleaning() {
+	let expected = vec![Some(1); 4];
+	let result = [
+		["--ber-tlv", "ZZ"],
+		["--ber-tlv", "0x"],
+		["--auto-tlv", "ZZ"],
+		["--compact-tlv", "zz"],
+	]
+	.iter()
+	.map(|args| exit_code(args))
+	.collect::<Vec<_>>();
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn strict_padding() {
+	let expected = (Some(0), Some(1));
+	let result = (
+		exit_code(&["--ber-tlv", "9F36020001 00 00"]),
+		exit_code(&["--strict", "--ber-tlv", "9F36020001 00 00"]),
+	);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn validation_errors() {
+	let expected = Some(2);
+	let result = exit_code(&[
+		"--validate",
+		"--ber-tlv",
+		"570F4111111111111111D25122010000005F2403261231",
+	]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn io_failure() {
+	let expected = Some(3);
+	let result = exit_code(&["--ber-tlv", "--input-file", "this-file-does-not-exist.hex"]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn binary_input() {
+	let path = temp_dir().join("pemv-exit-codes-binary-input.bin");
+	write(&path, [0x9F, 0x36, 0x02, 0x00, 0x01]).expect("the temporary file should be writable");
+
+	let expected = Some(0);
+	let result = exit_code(&[
+		"--ber-tlv",
+		"--input-file-binary",
+		path.to_str()
+			.expect("the temporary path should be valid UTF-8"),
+	]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn binary_input_io_failure() {
+	let expected = Some(3);
+	let result = exit_code(&[
+		"--ber-tlv",
+		"--input-file-binary",
+		"this-file-does-not-exist.bin",
+	]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn invalid_usage() {
+	let expected = Some(4);
+	let result = exit_code(&["--not-a-real-option"]);
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn invalid_configuration() {
+	let expected = Some(4);
+	let result = Command::new(env!("CARGO_BIN_EXE_pemv"))
+		.args(["--ber-tlv", "9F36020001"])
+		.env("PEMV_MASK_SINGLE_BYTE", "banana")
+		.output()
+		.expect("the binary should be able to run")
+		.status
+		.code();
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn indentation_from_environment() {
+	let expected = Some(0);
+	let result = Command::new(env!("CARGO_BIN_EXE_pemv"))
+		.args(["--ber-tlv", "9F36020001"])
+		.env("PEMV_INDENTATION", "2")
+		.output()
+		.expect("the binary should be able to run")
+		.status
+		.code();
+
+	assert_eq!(expected, result);
+}
+#[test]
+fn invalid_indentation_from_environment() {
+	let expected = Some(4);
+	let result = Command::new(env!("CARGO_BIN_EXE_pemv"))
+		.args(["--ber-tlv", "9F36020001"])
+		.env("PEMV_INDENTATION", "banana")
+		.output()
+		.expect("the binary should be able to run")
+		.status
+		.code();
+
+	assert_eq!(expected, result);
+}